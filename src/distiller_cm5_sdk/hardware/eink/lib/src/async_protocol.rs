@@ -0,0 +1,387 @@
+//! Async counterpart to [`crate::protocol`], for embassy-style executors.
+//!
+//! [`EinkProtocol::check_status`](crate::protocol::EinkProtocol::check_status)
+//! busy-waits with a polling loop, blocking the executor for the length of a
+//! refresh. [`AsyncEinkProtocol::check_status`] instead parks the task on
+//! the BUSY pin's falling edge via `embedded-hal-async`'s [`Wait`], bounded
+//! by a configurable timeout so a stuck panel still surfaces
+//! `DisplayError::Timeout` instead of hanging forever.
+
+use embassy_time::{Duration, with_timeout};
+use embedded_hal::digital::OutputPin;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::error::DisplayError;
+use crate::firmware::{Command, CommandSequence, DisplayFirmware};
+use crate::protocol::{DisplayMode, GrayPlane};
+
+/// Async counterpart to [`EinkProtocol`](crate::protocol::EinkProtocol).
+/// Mirrors its methods one-for-one, except that every method is `async` and
+/// [`Self::check_status`] waits on an edge instead of polling.
+///
+/// Like `embedded-hal-async`'s own traits, this is meant for single-threaded
+/// embedded executors (embassy, ...), not for moving futures across threads,
+/// so the lack of an auto `Send` bound on the returned futures is fine.
+#[allow(async_fn_in_trait)]
+pub trait AsyncEinkProtocol {
+    /// Initialize the display hardware (reset + init sequence)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command or pin operation fails
+    async fn init_hardware(&mut self) -> Result<(), DisplayError>;
+    /// Run the partial-update initialization sequence
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command or pin operation fails
+    async fn init_partial(&mut self) -> Result<(), DisplayError>;
+    /// Write a single command byte
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if the SPI write fails
+    async fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError>;
+    /// Write a single data byte
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if the SPI write fails
+    async fn write_data(&mut self, data: u8) -> Result<(), DisplayError>;
+    /// Write a full frame of image data
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `data` doesn't match the
+    /// firmware's `array_size`, or `DisplayError::Spi` if the write fails
+    async fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError>;
+    /// Wait for the BUSY pin's falling edge, bounded by the configured
+    /// timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Timeout` if BUSY doesn't fall within the
+    /// configured timeout
+    async fn check_status(&mut self) -> Result<(), DisplayError>;
+    /// Trigger a display refresh in the given mode
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command or pin operation fails
+    async fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError>;
+    /// Put the controller to sleep
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command or pin operation fails
+    async fn sleep(&mut self) -> Result<(), DisplayError>;
+    /// Get the display specifications
+    fn get_spec(&self) -> &crate::firmware::DisplaySpec;
+    /// Get the write RAM command byte
+    fn get_write_ram_command(&self) -> u8;
+    /// Program the controller's RAM X/Y address window and cursor ahead of a
+    /// windowed partial update.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command or pin operation fails
+    async fn set_ram_window(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError>;
+    /// Write a sub-rectangle's worth of image bytes, for use after
+    /// `set_ram_window` has restricted the active window.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Spi` if the write fails
+    async fn write_region_data(&mut self, data: &[u8]) -> Result<(), DisplayError>;
+    /// Load the firmware's 4-gray waveform LUT into the controller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if the firmware has no 4-gray support
+    async fn write_gray_lut(&mut self) -> Result<(), DisplayError>;
+    /// Write one of the two 1bpp bitplanes a 4-gray update requires.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if the firmware has no 4-gray support
+    async fn write_plane(&mut self, plane: GrayPlane, data: &[u8]) -> Result<(), DisplayError>;
+
+    /// Drive a 4-gray update from a single combined 2bpp framebuffer. See
+    /// [`EinkProtocol::update_display_gray4`](crate::protocol::EinkProtocol::update_display_gray4)
+    /// for the buffer layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `planes` doesn't match the
+    /// firmware's pixel count, or `DisplayError::Config` if the firmware has
+    /// no 4-gray support.
+    async fn update_display_gray4(&mut self, planes: &[u8]) -> Result<(), DisplayError> {
+        let spec = self.get_spec().clone();
+        let pixel_count = (spec.width * spec.height) as usize;
+        let expected = pixel_count.div_ceil(4);
+        if planes.len() != expected {
+            return Err(DisplayError::InvalidDataSize {
+                expected,
+                actual: planes.len(),
+            });
+        }
+
+        let mut old_plane = vec![0u8; spec.array_size()];
+        let mut new_plane = vec![0u8; spec.array_size()];
+
+        for pixel_idx in 0..pixel_count {
+            let src_byte = planes[pixel_idx / 4];
+            let shift = 6 - (pixel_idx % 4) * 2;
+            let value = (src_byte >> shift) & 0b11;
+
+            let byte_idx = pixel_idx / 8;
+            let bit_idx = pixel_idx % 8;
+            if (value >> 1) & 1 == 1 {
+                old_plane[byte_idx] |= 1 << (7 - bit_idx);
+            }
+            if value & 1 == 1 {
+                new_plane[byte_idx] |= 1 << (7 - bit_idx);
+            }
+        }
+
+        self.write_gray_lut().await?;
+        self.write_plane(GrayPlane::Old, &old_plane).await?;
+        self.write_plane(GrayPlane::New, &new_plane).await?;
+        self.update_display(DisplayMode::Gray4).await
+    }
+
+    /// Program the RAM window to `(x, y, w, h)`, stream `data` into it, and
+    /// trigger a partial refresh restricted to that rectangle. See
+    /// [`EinkProtocol::update_region`](crate::protocol::EinkProtocol::update_region).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if programming the window, writing the region,
+    /// or triggering the update fails.
+    async fn update_region(
+        &mut self,
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+        data: &[u8],
+    ) -> Result<(), DisplayError> {
+        self.init_partial().await?;
+        self.set_ram_window(x, y, w, h).await?;
+        let write_ram_cmd = self.get_write_ram_command();
+        self.write_cmd(write_ram_cmd).await?;
+        self.write_region_data(data).await?;
+        self.update_display(DisplayMode::Partial).await
+    }
+}
+
+/// Async e-ink protocol implementation built on `embedded-hal-async`'s
+/// [`SpiDevice`] and [`DelayNs`], plus [`Wait`] for the BUSY pin.
+pub struct GenericAsyncEinkProtocol<DC, RST, BUSY, SPI, DELAY, F>
+where
+    DC: OutputPin,
+    RST: OutputPin,
+    BUSY: Wait,
+    SPI: SpiDevice,
+    DELAY: DelayNs,
+    F: DisplayFirmware,
+{
+    dc: DC,
+    rst: RST,
+    busy: BUSY,
+    spi: SPI,
+    delay: DELAY,
+    firmware: F,
+    busy_timeout: Duration,
+}
+
+impl<DC, RST, BUSY, SPI, DELAY, F> GenericAsyncEinkProtocol<DC, RST, BUSY, SPI, DELAY, F>
+where
+    DC: OutputPin,
+    RST: OutputPin,
+    BUSY: Wait,
+    SPI: SpiDevice,
+    DELAY: DelayNs,
+    F: DisplayFirmware,
+{
+    /// Build an async protocol from already-configured `embedded-hal-async`
+    /// pin/bus handles, a firmware variant, and a bound on how long to wait
+    /// for BUSY to fall before `check_status` reports `DisplayError::Timeout`.
+    pub fn new(
+        dc: DC,
+        rst: RST,
+        busy: BUSY,
+        spi: SPI,
+        delay: DELAY,
+        firmware: F,
+        busy_timeout: Duration,
+    ) -> Self {
+        Self {
+            dc,
+            rst,
+            busy,
+            spi,
+            delay,
+            firmware,
+            busy_timeout,
+        }
+    }
+
+    async fn execute_sequence(&mut self, sequence: CommandSequence) -> Result<(), DisplayError> {
+        for command in sequence.commands {
+            match command {
+                Command::WriteCommand(cmd) => self.write_cmd(cmd).await?,
+                Command::WriteData(data) => self.write_data(data).await?,
+                Command::Delay(ms) => self.delay.delay_ms(ms as u32).await,
+                Command::CheckStatus => self.check_status().await?,
+                Command::Reset => {
+                    self.rst
+                        .set_low()
+                        .map_err(|_| DisplayError::Gpio("Failed to set RST pin".to_string()))?;
+                    self.delay.delay_ms(10).await;
+                    self.rst
+                        .set_high()
+                        .map_err(|_| DisplayError::Gpio("Failed to set RST pin".to_string()))?;
+                    self.delay.delay_ms(10).await;
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<DC, RST, BUSY, SPI, DELAY, F> AsyncEinkProtocol
+    for GenericAsyncEinkProtocol<DC, RST, BUSY, SPI, DELAY, F>
+where
+    DC: OutputPin,
+    RST: OutputPin,
+    BUSY: Wait,
+    SPI: SpiDevice,
+    DELAY: DelayNs,
+    F: DisplayFirmware,
+{
+    async fn init_hardware(&mut self) -> Result<(), DisplayError> {
+        let reset_sequence = self.firmware.get_reset_sequence();
+        self.execute_sequence(reset_sequence).await?;
+
+        let init_sequence = self.firmware.get_init_sequence();
+        self.execute_sequence(init_sequence).await?;
+
+        Ok(())
+    }
+
+    async fn init_partial(&mut self) -> Result<(), DisplayError> {
+        let partial_sequence = self.firmware.get_partial_init_sequence();
+        self.execute_sequence(partial_sequence).await?;
+        Ok(())
+    }
+
+    async fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
+        self.delay.delay_us(10).await;
+        self.dc
+            .set_low()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(&[cmd])
+            .await
+            .map_err(|e| DisplayError::Spi(format!("Failed to write command: {e:?}")))
+    }
+
+    async fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
+        self.delay.delay_us(10).await;
+        self.dc
+            .set_high()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(&[data])
+            .await
+            .map_err(|e| DisplayError::Spi(format!("Failed to write data: {e:?}")))
+    }
+
+    async fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        self.firmware.validate_image_size(data)?;
+
+        self.dc
+            .set_high()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(data)
+            .await
+            .map_err(|e| DisplayError::Spi(format!("Failed to write image data: {e:?}")))
+    }
+
+    async fn check_status(&mut self) -> Result<(), DisplayError> {
+        match with_timeout(self.busy_timeout, self.busy.wait_for_falling_edge()).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(_)) => Err(DisplayError::Gpio("Failed to wait on BUSY pin".to_string())),
+            Err(_) => {
+                log::warn!("Display busy timeout");
+                Err(DisplayError::Timeout)
+            },
+        }
+    }
+
+    async fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
+        let is_partial = matches!(mode, DisplayMode::Partial);
+        let update_sequence = self.firmware.get_update_sequence(is_partial);
+        self.execute_sequence(update_sequence).await?;
+        Ok(())
+    }
+
+    async fn sleep(&mut self) -> Result<(), DisplayError> {
+        let sleep_sequence = self.firmware.get_sleep_sequence();
+        self.execute_sequence(sleep_sequence).await?;
+        Ok(())
+    }
+
+    fn get_spec(&self) -> &crate::firmware::DisplaySpec {
+        self.firmware.get_spec()
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        self.firmware.get_write_ram_command()
+    }
+
+    async fn set_ram_window(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError> {
+        let window_sequence = self.firmware.get_window_sequence(x, y, w, h);
+        self.execute_sequence(window_sequence).await
+    }
+
+    async fn write_region_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        self.dc
+            .set_high()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(data)
+            .await
+            .map_err(|e| DisplayError::Spi(format!("Failed to write region data: {e:?}")))
+    }
+
+    async fn write_gray_lut(&mut self) -> Result<(), DisplayError> {
+        let init_sequence = self.firmware.get_gray4_init_sequence().ok_or_else(|| {
+            DisplayError::Config("Firmware does not support 4-gray mode".to_string())
+        })?;
+        self.execute_sequence(init_sequence).await?;
+
+        let lut_sequence = self.firmware.get_gray4_lut_sequence().ok_or_else(|| {
+            DisplayError::Config("Firmware does not support 4-gray mode".to_string())
+        })?;
+        self.execute_sequence(lut_sequence).await
+    }
+
+    async fn write_plane(&mut self, plane: GrayPlane, data: &[u8]) -> Result<(), DisplayError> {
+        let (old_cmd, new_cmd) = self.firmware.get_gray4_plane_commands().ok_or_else(|| {
+            DisplayError::Config("Firmware does not support 4-gray mode".to_string())
+        })?;
+
+        let cmd = match plane {
+            GrayPlane::Old => old_cmd,
+            GrayPlane::New => new_cmd,
+        };
+
+        self.write_cmd(cmd).await?;
+        self.write_image_data(data).await
+    }
+}
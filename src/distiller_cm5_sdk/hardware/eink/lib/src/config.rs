@@ -1,6 +1,7 @@
 //! Configuration management for e-ink display firmware selection and settings.
 
 use std::{
+    collections::HashMap,
     str::FromStr,
     sync::{Mutex, OnceLock},
 };
@@ -158,6 +159,53 @@ pub fn get_default_spec() -> Result<DisplaySpec, DisplayError> {
     Ok(firmware_type.get_spec())
 }
 
+/// A factory for a firmware variant registered by name with
+/// [`register_firmware`], so adding a new panel means registering one
+/// function instead of adding a `FirmwareType` variant and editing every
+/// `ConfigurableProtocol` match arm.
+pub type FirmwareFactory = fn() -> Box<dyn DisplayFirmware>;
+
+/// Global registry of named firmware factories.
+static FIRMWARE_REGISTRY: OnceLock<Mutex<HashMap<String, FirmwareFactory>>> = OnceLock::new();
+
+fn firmware_registry() -> &'static Mutex<HashMap<String, FirmwareFactory>> {
+    FIRMWARE_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Register a firmware factory under `name` (case-insensitive), so
+/// [`create_firmware_by_name`]/[`crate::protocol::create_protocol_by_name`]
+/// can look it up at runtime.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if the registry lock cannot be acquired
+pub fn register_firmware(name: &str, factory: FirmwareFactory) -> Result<(), DisplayError> {
+    let registry = firmware_registry();
+    let mut guard = registry
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire firmware registry lock: {e}")))?;
+    guard.insert(name.to_lowercase(), factory);
+    Ok(())
+}
+
+/// Create a firmware instance previously registered with
+/// [`register_firmware`].
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if `name` has no registered factory or the
+/// registry lock cannot be acquired
+pub fn create_firmware_by_name(name: &str) -> Result<Box<dyn DisplayFirmware>, DisplayError> {
+    let registry = firmware_registry();
+    let guard = registry
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire firmware registry lock: {e}")))?;
+    let factory = guard.get(&name.to_lowercase()).ok_or_else(|| {
+        DisplayError::Config(format!("No firmware registered under '{name}'"))
+    })?;
+    Ok(factory())
+}
+
 /// Configuration from environment variables
 ///
 /// # Errors
@@ -0,0 +1,371 @@
+//! On-device text console layered over [`crate::display`], rendering a fixed
+//! character grid through the windowed partial update path.
+//!
+//! The console bundles a small built-in 6x8 bitmap font covering digits,
+//! uppercase letters (lowercase is folded to uppercase) and common
+//! punctuation, so headless devices can use the panel as a log/status
+//! console without shipping their own font or layout code. Any character
+//! outside that set renders as a hollow box, matching how real bitmap fonts
+//! signal a missing glyph.
+
+use std::sync::Mutex;
+
+use crate::display;
+use crate::error::DisplayError;
+use crate::firmware::DisplaySpec;
+use crate::protocol::DisplayMode;
+
+/// Glyph width in pixels.
+pub const FONT_WIDTH: u32 = 6;
+/// Glyph height in pixels.
+pub const FONT_HEIGHT: u32 = 8;
+
+/// Per-cell style flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CellStyle {
+    /// Draw the glyph thickened by one pixel to the right.
+    pub bold: bool,
+    /// Draw a solid line under the cell's bottom row.
+    pub underline: bool,
+    /// Swap ink and background for the cell.
+    pub reverse: bool,
+    /// Draw a solid line through the cell's middle row.
+    pub strike: bool,
+}
+
+/// A single character cell in the console grid.
+#[derive(Debug, Clone, Copy)]
+pub struct Cell {
+    /// The cell's character.
+    pub ch: char,
+    /// Style flags applied when rendering this cell.
+    pub style: CellStyle,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            style: CellStyle::default(),
+        }
+    }
+}
+
+/// Styled character-grid console rendered onto a 1-bit e-ink framebuffer.
+pub struct Console {
+    rows: u32,
+    cols: u32,
+    cells: Vec<Cell>,
+    cursor_row: u32,
+    cursor_col: u32,
+}
+
+impl Console {
+    /// Create a console with an explicit grid size.
+    #[must_use]
+    pub fn new(rows: u32, cols: u32) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![Cell::default(); (rows * cols) as usize],
+            cursor_row: 0,
+            cursor_col: 0,
+        }
+    }
+
+    /// Build a console whose grid fills `spec` with the built-in font.
+    #[must_use]
+    pub fn for_spec(spec: &DisplaySpec) -> Self {
+        Self::new(spec.height / FONT_HEIGHT, spec.width / FONT_WIDTH)
+    }
+
+    /// Number of character rows in the grid.
+    #[must_use]
+    pub const fn rows(&self) -> u32 {
+        self.rows
+    }
+
+    /// Number of character columns in the grid.
+    #[must_use]
+    pub const fn cols(&self) -> u32 {
+        self.cols
+    }
+
+    const fn cell_index(&self, row: u32, col: u32) -> usize {
+        (row * self.cols + col) as usize
+    }
+
+    /// Move the cursor to `(row, col)`, clamped to the grid bounds.
+    pub fn set_cursor(&mut self, row: u32, col: u32) {
+        self.cursor_row = row.min(self.rows.saturating_sub(1));
+        self.cursor_col = col.min(self.cols.saturating_sub(1));
+    }
+
+    /// Blank every cell and return the cursor to the top-left corner.
+    pub fn clear(&mut self) {
+        for cell in &mut self.cells {
+            *cell = Cell::default();
+        }
+        self.cursor_row = 0;
+        self.cursor_col = 0;
+    }
+
+    /// Scroll the grid up by one row, discarding row 0 and blanking the new
+    /// last row.
+    pub fn scroll(&mut self) {
+        let cols = self.cols as usize;
+        self.cells.drain(0..cols);
+        self.cells.extend(std::iter::repeat_n(Cell::default(), cols));
+    }
+
+    /// Returns `true` if the grid scrolled, which moves every row.
+    fn newline(&mut self) -> bool {
+        self.cursor_col = 0;
+        if self.cursor_row + 1 >= self.rows {
+            self.scroll();
+            true
+        } else {
+            self.cursor_row += 1;
+            false
+        }
+    }
+
+    /// Print `text` into the grid starting at the cursor, wrapping at the
+    /// last column and scrolling when the cursor passes the last row.
+    ///
+    /// Returns the `(min_row, max_row)` range of rows touched, so the caller
+    /// can refresh just that band instead of the whole panel.
+    pub fn print(&mut self, text: &str) -> (u32, u32) {
+        let mut min_touched_row = self.cursor_row;
+        let mut max_touched_row = self.cursor_row;
+
+        for ch in text.chars() {
+            if ch == '\n' {
+                if self.newline() {
+                    min_touched_row = 0;
+                }
+            } else {
+                if self.cursor_col >= self.cols && self.newline() {
+                    min_touched_row = 0;
+                }
+
+                let idx = self.cell_index(self.cursor_row, self.cursor_col);
+                self.cells[idx] = Cell {
+                    ch,
+                    style: CellStyle::default(),
+                };
+                self.cursor_col += 1;
+            }
+
+            min_touched_row = min_touched_row.min(self.cursor_row);
+            max_touched_row = max_touched_row.max(self.cursor_row);
+        }
+
+        (min_touched_row, max_touched_row)
+    }
+
+    /// Render the full grid into a 1-bit framebuffer sized for `spec`.
+    #[must_use]
+    pub fn render(&self, spec: &DisplaySpec) -> Vec<u8> {
+        let mut buffer = vec![0xFFu8; spec.array_size()];
+        for row in 0..self.rows {
+            self.render_row(&mut buffer, spec, row);
+        }
+        buffer
+    }
+
+    fn render_row(&self, buffer: &mut [u8], spec: &DisplaySpec, row: u32) {
+        for col in 0..self.cols {
+            let cell = self.cells[self.cell_index(row, col)];
+            let glyph = glyph_bitmap(cell.ch);
+            let px0 = col * FONT_WIDTH;
+            let py0 = row * FONT_HEIGHT;
+
+            for gy in 0..FONT_HEIGHT {
+                let row_bits = glyph[gy as usize];
+
+                for gx in 0..FONT_WIDTH {
+                    let mut on = (row_bits >> (FONT_WIDTH - 1 - gx)) & 1 == 1;
+                    if cell.style.bold && gx > 0 {
+                        on |= (row_bits >> (FONT_WIDTH - gx)) & 1 == 1;
+                    }
+                    if cell.style.underline && gy == FONT_HEIGHT - 1 {
+                        on = true;
+                    }
+                    if cell.style.strike && gy == FONT_HEIGHT / 2 {
+                        on = true;
+                    }
+                    if cell.style.reverse {
+                        on = !on;
+                    }
+
+                    let px = px0 + gx;
+                    let py = py0 + gy;
+                    if px >= spec.width || py >= spec.height {
+                        continue;
+                    }
+
+                    let pixel_idx = (py * spec.width + px) as usize;
+                    let byte_idx = pixel_idx / 8;
+                    let bit_idx = pixel_idx % 8;
+                    if on {
+                        buffer[byte_idx] &= !(1 << (7 - bit_idx));
+                    } else {
+                        buffer[byte_idx] |= 1 << (7 - bit_idx);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Look up the 8-row bitmap for a glyph; each row's bottom `FONT_WIDTH` bits
+/// are the pixels for that row, MSB first (leftmost column). Characters
+/// outside the built-in set render as a hollow box.
+#[rustfmt::skip]
+fn glyph_bitmap(ch: char) -> [u8; FONT_HEIGHT as usize] {
+    match ch.to_ascii_uppercase() {
+        ' ' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000],
+        '0' => [0b011110, 0b100011, 0b100101, 0b101001, 0b110001, 0b100001, 0b100001, 0b011110],
+        '1' => [0b001100, 0b011100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b111111],
+        '2' => [0b011110, 0b100001, 0b000001, 0b000010, 0b000100, 0b001000, 0b010000, 0b111111],
+        '3' => [0b111110, 0b000001, 0b000001, 0b011110, 0b000001, 0b000001, 0b100001, 0b011110],
+        '4' => [0b000010, 0b000110, 0b001010, 0b010010, 0b100010, 0b111111, 0b000010, 0b000010],
+        '5' => [0b111111, 0b100000, 0b111110, 0b000001, 0b000001, 0b000001, 0b100001, 0b011110],
+        '6' => [0b011110, 0b100000, 0b100000, 0b111110, 0b100001, 0b100001, 0b100001, 0b011110],
+        '7' => [0b111111, 0b000001, 0b000010, 0b000100, 0b001000, 0b010000, 0b010000, 0b010000],
+        '8' => [0b011110, 0b100001, 0b100001, 0b011110, 0b100001, 0b100001, 0b100001, 0b011110],
+        '9' => [0b011110, 0b100001, 0b100001, 0b100001, 0b011111, 0b000001, 0b000001, 0b011110],
+        'A' => [0b001100, 0b010010, 0b100001, 0b100001, 0b111111, 0b100001, 0b100001, 0b100001],
+        'B' => [0b111110, 0b100001, 0b100001, 0b111110, 0b100001, 0b100001, 0b100001, 0b111110],
+        'C' => [0b011110, 0b100001, 0b100000, 0b100000, 0b100000, 0b100000, 0b100001, 0b011110],
+        'D' => [0b111100, 0b100010, 0b100001, 0b100001, 0b100001, 0b100001, 0b100010, 0b111100],
+        'E' => [0b111111, 0b100000, 0b100000, 0b111110, 0b100000, 0b100000, 0b100000, 0b111111],
+        'F' => [0b111111, 0b100000, 0b100000, 0b111110, 0b100000, 0b100000, 0b100000, 0b100000],
+        'G' => [0b011110, 0b100001, 0b100000, 0b100000, 0b100111, 0b100001, 0b100001, 0b011110],
+        'H' => [0b100001, 0b100001, 0b100001, 0b111111, 0b100001, 0b100001, 0b100001, 0b100001],
+        'I' => [0b011110, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b011110],
+        'J' => [0b000111, 0b000010, 0b000010, 0b000010, 0b000010, 0b100010, 0b100010, 0b011100],
+        'K' => [0b100001, 0b100010, 0b100100, 0b111000, 0b100100, 0b100010, 0b100001, 0b100001],
+        'L' => [0b100000, 0b100000, 0b100000, 0b100000, 0b100000, 0b100000, 0b100000, 0b111111],
+        'M' => [0b100001, 0b110011, 0b101101, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001],
+        'N' => [0b100001, 0b110001, 0b101001, 0b100101, 0b100011, 0b100001, 0b100001, 0b100001],
+        'O' => [0b011110, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b011110],
+        'P' => [0b111110, 0b100001, 0b100001, 0b111110, 0b100000, 0b100000, 0b100000, 0b100000],
+        'Q' => [0b011110, 0b100001, 0b100001, 0b100001, 0b100101, 0b100010, 0b100001, 0b011101],
+        'R' => [0b111110, 0b100001, 0b100001, 0b111110, 0b100100, 0b100010, 0b100001, 0b100001],
+        'S' => [0b011111, 0b100000, 0b100000, 0b011110, 0b000001, 0b000001, 0b000001, 0b111110],
+        'T' => [0b111111, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100],
+        'U' => [0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b011110],
+        'V' => [0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b010010, 0b010010, 0b001100],
+        'W' => [0b100001, 0b100001, 0b100001, 0b100001, 0b101101, 0b110011, 0b100001, 0b100001],
+        'X' => [0b100001, 0b100001, 0b010010, 0b001100, 0b001100, 0b010010, 0b100001, 0b100001],
+        'Y' => [0b100001, 0b100001, 0b010010, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100],
+        'Z' => [0b111111, 0b000010, 0b000100, 0b001000, 0b010000, 0b100000, 0b100000, 0b111111],
+        '.' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b001100, 0b001100],
+        ',' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b001100, 0b001100, 0b011000],
+        ':' => [0b000000, 0b001100, 0b001100, 0b000000, 0b000000, 0b001100, 0b001100, 0b000000],
+        ';' => [0b000000, 0b001100, 0b001100, 0b000000, 0b000000, 0b001100, 0b001100, 0b011000],
+        '-' => [0b000000, 0b000000, 0b000000, 0b111111, 0b000000, 0b000000, 0b000000, 0b000000],
+        '+' => [0b000000, 0b001100, 0b001100, 0b111111, 0b001100, 0b001100, 0b000000, 0b000000],
+        '=' => [0b000000, 0b000000, 0b111111, 0b000000, 0b111111, 0b000000, 0b000000, 0b000000],
+        '!' => [0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b000000, 0b001100, 0b001100],
+        '?' => [0b011110, 0b100001, 0b000010, 0b000100, 0b001000, 0b000000, 0b001000, 0b001000],
+        '/' => [0b000001, 0b000010, 0b000100, 0b001000, 0b010000, 0b100000, 0b000000, 0b000000],
+        '(' => [0b000110, 0b001100, 0b011000, 0b011000, 0b011000, 0b011000, 0b001100, 0b000110],
+        ')' => [0b011000, 0b001100, 0b000110, 0b000110, 0b000110, 0b000110, 0b001100, 0b011000],
+        '_' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b111111],
+        '*' => [0b000000, 0b100101, 0b010010, 0b111111, 0b010010, 0b100101, 0b000000, 0b000000],
+        // Missing glyph: hollow box, matching how real fonts signal "tofu".
+        _ => [0b111111, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b111111],
+    }
+}
+
+struct GlobalConsoleState {
+    console: Option<Console>,
+}
+
+static GLOBAL_CONSOLE: Mutex<GlobalConsoleState> = Mutex::new(GlobalConsoleState { console: None });
+
+/// Initialize the console, sizing its character grid to the active
+/// display's dimensions.
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the display hasn't been
+/// initialized yet.
+pub fn console_init() -> Result<(), DisplayError> {
+    let spec = display::display_get_spec()?;
+    let mut state = GLOBAL_CONSOLE.lock().unwrap();
+    state.console = Some(Console::for_spec(&spec));
+    Ok(())
+}
+
+/// Print `text` at the cursor, wrapping and scrolling as needed, then push
+/// only the touched character rows to the panel as a partial refresh.
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the console or display hasn't
+/// been initialized yet.
+pub fn console_print(text: &str) -> Result<(), DisplayError> {
+    let spec = display::display_get_spec()?;
+    let mut state = GLOBAL_CONSOLE.lock().unwrap();
+    let console = state.console.as_mut().ok_or(DisplayError::NotInitialized)?;
+
+    let (min_row, max_row) = console.print(text);
+    let full = console.render(&spec);
+
+    let row_bytes = (spec.width / 8) as usize;
+    let y = min_row * FONT_HEIGHT;
+    let h = (max_row - min_row + 1) * FONT_HEIGHT;
+    let start = y as usize * row_bytes;
+    let end = (y + h) as usize * row_bytes;
+
+    display::display_image_region(&full[start..end], 0, y, spec.width, h, DisplayMode::Partial)
+}
+
+/// Move the console cursor to `(row, col)`.
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the console hasn't been
+/// initialized yet.
+pub fn console_set_cursor(row: u32, col: u32) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_CONSOLE.lock().unwrap();
+    let console = state.console.as_mut().ok_or(DisplayError::NotInitialized)?;
+    console.set_cursor(row, col);
+    Ok(())
+}
+
+/// Blank the console and push a full refresh of the now-empty grid.
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the console or display hasn't
+/// been initialized yet.
+pub fn console_clear() -> Result<(), DisplayError> {
+    let spec = display::display_get_spec()?;
+    let mut state = GLOBAL_CONSOLE.lock().unwrap();
+    let console = state.console.as_mut().ok_or(DisplayError::NotInitialized)?;
+
+    console.clear();
+    let full = console.render(&spec);
+    display::display_image_raw(&full, DisplayMode::Full)
+}
+
+/// Scroll the console up by one row and push a full refresh.
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the console or display hasn't
+/// been initialized yet.
+pub fn console_scroll() -> Result<(), DisplayError> {
+    let spec = display::display_get_spec()?;
+    let mut state = GLOBAL_CONSOLE.lock().unwrap();
+    let console = state.console.as_mut().ok_or(DisplayError::NotInitialized)?;
+
+    console.scroll();
+    let full = console.render(&spec);
+    display::display_image_raw(&full, DisplayMode::Full)
+}
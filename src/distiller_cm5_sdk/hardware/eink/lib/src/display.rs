@@ -1,6 +1,6 @@
 use crate::error::DisplayError;
 use crate::image;
-use crate::protocol::{DisplayMode, EinkProtocol, create_default_protocol};
+use crate::protocol::{DisplayMode, EinkProtocol, GrayPlane, create_default_protocol};
 use std::sync::Mutex;
 
 // Display driver trait for different e-ink variants
@@ -8,6 +8,21 @@ pub trait DisplayDriver {
     fn init(&mut self) -> Result<(), DisplayError>;
     fn display_image_raw(&mut self, data: &[u8], mode: DisplayMode) -> Result<(), DisplayError>;
     fn display_image_png(&mut self, filename: &str, mode: DisplayMode) -> Result<(), DisplayError>;
+    /// Push a sub-rectangle of 1-bit data and issue a windowed partial
+    /// refresh of just that rect, for callers that already know their
+    /// damage region and want to skip the cached-buffer diff.
+    ///
+    /// `x` and `w` must be 8-pixel (byte) aligned, matching e-ink's
+    /// byte-granular X addressing.
+    fn display_image_region(
+        &mut self,
+        data: &[u8],
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+        mode: DisplayMode,
+    ) -> Result<(), DisplayError>;
     fn clear(&mut self) -> Result<(), DisplayError>;
     fn sleep(&mut self) -> Result<(), DisplayError>;
     fn cleanup(&mut self) -> Result<(), DisplayError>;
@@ -18,6 +33,9 @@ pub trait DisplayDriver {
 pub struct GenericDisplay<P: EinkProtocol> {
     protocol: P,
     initialized: bool,
+    // Last full frame committed to the panel, used to compute a dirty
+    // rectangle for partial updates instead of re-sending the whole frame.
+    last_committed: Option<Vec<u8>>,
 }
 
 impl<P: EinkProtocol> GenericDisplay<P> {
@@ -25,8 +43,118 @@ impl<P: EinkProtocol> GenericDisplay<P> {
         Self {
             protocol,
             initialized: false,
+            last_committed: None,
         }
     }
+
+    /// Compute the smallest byte-aligned bounding box covering every row and
+    /// column where `data` differs from the cached `last_committed` buffer,
+    /// in `(min_row, max_row, min_col_byte, max_col_byte)` terms. Returns
+    /// `None` if the two buffers are identical.
+    fn diff_bounds(
+        cached: &[u8],
+        data: &[u8],
+        row_bytes: usize,
+        height: usize,
+    ) -> Option<(usize, usize, usize, usize)> {
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+
+        for row in 0..height {
+            let start = row * row_bytes;
+            let row_cached = &cached[start..start + row_bytes];
+            let row_new = &data[start..start + row_bytes];
+            if row_cached == row_new {
+                continue;
+            }
+
+            for (col, (a, b)) in row_cached.iter().zip(row_new).enumerate() {
+                if a == b {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    None => (row, row, col, col),
+                    Some((min_row, max_row, min_col, max_col)) => {
+                        (min_row.min(row), max_row.max(row), min_col.min(col), max_col.max(col))
+                    },
+                });
+            }
+        }
+
+        bounds
+    }
+
+    /// Transmit only the dirty rectangle between `cached` and `data`,
+    /// falling back to skipping the refresh entirely if nothing changed.
+    fn display_partial_diff(
+        &mut self,
+        cached: &[u8],
+        data: &[u8],
+        spec: &crate::firmware::DisplaySpec,
+    ) -> Result<(), DisplayError> {
+        let row_bytes = spec.width as usize / 8;
+        let height = spec.height as usize;
+
+        let Some((min_row, max_row, min_col, max_col)) =
+            Self::diff_bounds(cached, data, row_bytes, height)
+        else {
+            // Nothing changed; skip the refresh entirely.
+            self.last_committed = Some(data.to_vec());
+            return Ok(());
+        };
+
+        let x = (min_col * 8) as u32;
+        let w = ((max_col - min_col + 1) * 8) as u32;
+        let y = min_row as u32;
+        let h = (max_row - min_row + 1) as u32;
+
+        self.protocol.init_partial()?;
+        self.protocol.set_ram_window(x, y, w, h)?;
+        let write_ram_cmd = self.protocol.get_write_ram_command();
+        self.protocol.write_cmd(write_ram_cmd)?;
+        for row in min_row..=max_row {
+            let start = row * row_bytes + min_col;
+            let end = row * row_bytes + max_col + 1;
+            self.protocol.write_region_data(&data[start..end])?;
+        }
+        self.protocol.update_display(DisplayMode::Partial)?;
+
+        self.last_committed = Some(data.to_vec());
+        Ok(())
+    }
+
+    /// Push a full-frame 4-gray (2bpp) image, given as its two 1bpp
+    /// bitplanes, and issue a full refresh of it.
+    ///
+    /// Both planes must be sized to `spec.array_size()`, same as a 1-bit
+    /// frame; the split into two planes is done ahead of time by the caller
+    /// (see [`crate::image::convert_png_to_gray4_with_spec`]).
+    pub fn display_image_gray4(
+        &mut self,
+        old_plane: &[u8],
+        new_plane: &[u8],
+    ) -> Result<(), DisplayError> {
+        if !self.initialized {
+            return Err(DisplayError::NotInitialized);
+        }
+
+        let spec = self.protocol.get_spec().clone();
+        if old_plane.len() != spec.array_size() || new_plane.len() != spec.array_size() {
+            return Err(DisplayError::InvalidDataSize {
+                expected: spec.array_size(),
+                actual: old_plane.len().max(new_plane.len()),
+            });
+        }
+
+        self.protocol.write_gray_lut()?;
+        self.protocol.write_plane(GrayPlane::Old, old_plane)?;
+        self.protocol.write_plane(GrayPlane::New, new_plane)?;
+        self.protocol.update_display(DisplayMode::Gray4)?;
+
+        // The panel is no longer showing a plain 1bpp frame, so a cached
+        // diff against it would be meaningless.
+        self.last_committed = None;
+        Ok(())
+    }
 }
 
 impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
@@ -47,7 +175,7 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
             return Err(DisplayError::NotInitialized);
         }
 
-        let spec = self.protocol.get_spec();
+        let spec = self.protocol.get_spec().clone();
         if data.len() != spec.array_size() {
             return Err(DisplayError::InvalidDataSize {
                 expected: spec.array_size(),
@@ -55,9 +183,18 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
             });
         }
 
-        match mode {
-            DisplayMode::Partial => self.protocol.init_partial()?,
-            DisplayMode::Full => {} // Full mode uses default initialization
+        if matches!(mode, DisplayMode::Partial) {
+            if let Some(cached) = self.last_committed.clone() {
+                if cached.len() == data.len() {
+                    return self.display_partial_diff(&cached, data, &spec);
+                }
+            }
+        }
+
+        // Full-frame path, also used as the Partial fallback when there is
+        // no committed buffer yet to diff against.
+        if matches!(mode, DisplayMode::Partial) {
+            self.protocol.init_partial()?;
         }
 
         let write_ram_cmd = self.protocol.get_write_ram_command();
@@ -65,6 +202,59 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
         self.protocol.write_image_data(data)?;
         self.protocol.update_display(mode)?;
 
+        self.last_committed = Some(data.to_vec());
+        Ok(())
+    }
+
+    fn display_image_region(
+        &mut self,
+        data: &[u8],
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+        mode: DisplayMode,
+    ) -> Result<(), DisplayError> {
+        if !self.initialized {
+            return Err(DisplayError::NotInitialized);
+        }
+
+        if !x.is_multiple_of(8) || !w.is_multiple_of(8) {
+            return Err(DisplayError::Config(format!(
+                "Region x ({x}) and width ({w}) must be 8-pixel (byte) aligned"
+            )));
+        }
+
+        let spec = self.protocol.get_spec().clone();
+        if x + w > spec.width || y + h > spec.height {
+            return Err(DisplayError::Config(format!(
+                "Region ({x}, {y}) {w}x{h} exceeds display bounds {}x{}",
+                spec.width, spec.height
+            )));
+        }
+
+        let row_bytes = (w / 8) as usize;
+        let expected = row_bytes * h as usize;
+        if data.len() != expected {
+            return Err(DisplayError::InvalidDataSize {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        if matches!(mode, DisplayMode::Partial) {
+            self.protocol.init_partial()?;
+        }
+
+        self.protocol.set_ram_window(x, y, w, h)?;
+        let write_ram_cmd = self.protocol.get_write_ram_command();
+        self.protocol.write_cmd(write_ram_cmd)?;
+        self.protocol.write_region_data(data)?;
+        self.protocol.update_display(mode)?;
+
+        // The cached full frame no longer reflects what's on the panel;
+        // force the next partial update to fall back to a full-frame write.
+        self.last_committed = None;
         Ok(())
     }
 
@@ -88,6 +278,7 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
         if self.initialized {
             self.sleep()?;
             self.initialized = false;
+            self.last_committed = None;
             log::info!("Display SDK cleaned up");
         }
         Ok(())
@@ -142,6 +333,106 @@ pub fn display_image_png(filename: &str, mode: DisplayMode) -> Result<(), Displa
     }
 }
 
+pub fn display_image_region(
+    data: &[u8],
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+    mode: DisplayMode,
+) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE.lock().unwrap();
+
+    if let Some(display) = &mut state.display {
+        display.display_image_region(data, x, y, w, h, mode)
+    } else {
+        Err(DisplayError::NotInitialized)
+    }
+}
+
+/// Decode a PNG into the active display's 4-gray bitplanes and push it as a
+/// full-frame update.
+pub fn display_image_gray4_png(filename: &str) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE.lock().unwrap();
+
+    if let Some(display) = &mut state.display {
+        let spec = display.get_spec().clone();
+        let (old_plane, new_plane) = image::convert_png_to_gray4_with_spec(filename, &spec)?;
+        display.display_image_gray4(&old_plane, &new_plane)
+    } else {
+        Err(DisplayError::NotInitialized)
+    }
+}
+
+/// Decode any image format supported by the `image` crate and display it,
+/// scaling to fit the panel with a simple threshold (no dithering).
+pub fn display_image_file(filename: &str, mode: DisplayMode) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE.lock().unwrap();
+
+    if let Some(display) = &mut state.display {
+        let spec = display.get_spec().clone();
+        let processor = crate::image_processing::ImageProcessor::new(spec);
+        let raw_data = processor.process_image(
+            filename,
+            crate::image_processing::ScaleMode::Letterbox,
+            crate::image_processing::DitherMode::Threshold,
+            None,
+            None,
+            None,
+            false,
+        )?;
+        display.display_image_raw(&raw_data, mode)
+    } else {
+        Err(DisplayError::NotInitialized)
+    }
+}
+
+/// Decode any image format supported by the `image` crate and display it,
+/// applying the requested scaling and dithering before pushing the frame.
+pub fn display_image_auto(
+    filename: &str,
+    mode: DisplayMode,
+    scale_mode: crate::image_processing::ScaleMode,
+    dither_mode: crate::image_processing::DitherMode,
+) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE.lock().unwrap();
+
+    if let Some(display) = &mut state.display {
+        let spec = display.get_spec().clone();
+        let processor = crate::image_processing::ImageProcessor::new(spec);
+        let raw_data =
+            processor.process_image(filename, scale_mode, dither_mode, None, None, None, false)?;
+        display.display_image_raw(&raw_data, mode)
+    } else {
+        Err(DisplayError::NotInitialized)
+    }
+}
+
+/// Decode a raw pixel buffer already in memory (RGBA, grayscale, or a BMP
+/// file), scale and dither it, and display it without touching disk.
+#[allow(clippy::too_many_arguments)]
+pub fn display_image_buffer(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    format: crate::framebuffer::PixelFormat,
+    mode: DisplayMode,
+    scale_mode: crate::image_processing::ScaleMode,
+    dither_mode: crate::image_processing::DitherMode,
+) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE.lock().unwrap();
+
+    if let Some(display) = &mut state.display {
+        let spec = display.get_spec().clone();
+        let framebuffer = crate::framebuffer::Framebuffer::from_raw(
+            data, width, height, format, &spec, scale_mode, dither_mode,
+        )?;
+        display.display_image_raw(framebuffer.as_bytes(), mode)
+    } else {
+        Err(DisplayError::NotInitialized)
+    }
+}
+
 pub fn display_clear() -> Result<(), DisplayError> {
     let mut state = GLOBAL_STATE.lock().unwrap();
 
@@ -173,6 +464,23 @@ pub fn display_cleanup() -> Result<(), DisplayError> {
     Ok(())
 }
 
+/// Get the active display's spec, as actually initialized (not just the
+/// configured default).
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the display hasn't been
+/// initialized yet.
+pub fn display_get_spec() -> Result<crate::firmware::DisplaySpec, DisplayError> {
+    let state = GLOBAL_STATE.lock().unwrap();
+
+    if let Some(display) = &state.display {
+        Ok(display.get_spec().clone())
+    } else {
+        Err(DisplayError::NotInitialized)
+    }
+}
+
 pub fn display_get_dimensions() -> (u32, u32) {
     // For backwards compatibility, use default firmware
     image::get_dimensions()
@@ -187,17 +495,73 @@ pub fn convert_png_to_1bit(filename: &str) -> Result<Vec<u8>, DisplayError> {
 pub fn display_init_with_firmware<F: crate::firmware::DisplayFirmware + 'static>(
     firmware: F,
 ) -> Result<(), DisplayError> {
-    let state = GLOBAL_STATE.lock().unwrap();
+    let mut state = GLOBAL_STATE.lock().unwrap();
 
     if state.display.is_none() {
-        let protocol = crate::protocol::create_protocol_with_firmware(firmware)?;
-        let mut display = GenericDisplay::new(protocol);
+        let protocol = crate::protocol::create_boxed_protocol_with_firmware(firmware)?;
+        let mut display = DefaultDisplay::new(protocol);
         display.init()?;
-        // Note: This won't work directly due to type system constraints
-        // You'd need to use a trait object or enum for runtime firmware selection
-        // For now, this is a design template
+        state.display = Some(display);
     }
 
     Ok(())
 }
 
+/// Switch the active panel firmware at runtime, re-initializing the
+/// underlying hardware with the new protocol.
+///
+/// If the display has not been initialized yet, this only updates which
+/// protocol `display_init` will construct next.
+pub fn set_firmware(firmware_type: crate::config::FirmwareType) -> Result<(), DisplayError> {
+    crate::config::set_default_firmware(firmware_type)?;
+
+    let mut state = GLOBAL_STATE.lock().unwrap();
+    if let Some(display) = &mut state.display {
+        display.cleanup()?;
+        let protocol = crate::protocol::create_protocol_for_type(firmware_type)?;
+        let mut new_display = DefaultDisplay::new(protocol);
+        new_display.init()?;
+        *display = new_display;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROW_BYTES: usize = 2;
+    const HEIGHT: usize = 4;
+
+    #[test]
+    fn test_diff_bounds_identical_buffers_is_none() {
+        let cached = vec![0xAA; ROW_BYTES * HEIGHT];
+        let data = cached.clone();
+
+        assert!(DefaultDisplay::diff_bounds(&cached, &data, ROW_BYTES, HEIGHT).is_none());
+    }
+
+    #[test]
+    fn test_diff_bounds_single_byte_change() {
+        let cached = vec![0x00; ROW_BYTES * HEIGHT];
+        let mut data = cached.clone();
+        // Flip a bit in row 2, byte column 1.
+        data[2 * ROW_BYTES + 1] = 0xFF;
+
+        let bounds = DefaultDisplay::diff_bounds(&cached, &data, ROW_BYTES, HEIGHT);
+        assert_eq!(bounds, Some((2, 2, 1, 1)));
+    }
+
+    #[test]
+    fn test_diff_bounds_spans_multiple_rows_and_columns() {
+        let cached = vec![0x00; ROW_BYTES * HEIGHT];
+        let mut data = cached.clone();
+        data[0] = 0x01; // row 0, col 0
+        data[3 * ROW_BYTES + 1] = 0x80; // row 3, col 1
+
+        let bounds = DefaultDisplay::diff_bounds(&cached, &data, ROW_BYTES, HEIGHT);
+        assert_eq!(bounds, Some((0, 3, 0, 1)));
+    }
+}
+
@@ -0,0 +1,79 @@
+//! `embedded-graphics` integration, gated behind the `embedded-graphics`
+//! feature so the core SDK doesn't pull in the dependency by default.
+//!
+//! [`EinkCanvas`] wraps an [`EinkProtocol`] with an in-RAM [`Framebuffer`]
+//! and implements `embedded-graphics-core`'s `DrawTarget`/`OriginDimensions`
+//! over it, so callers can draw `embedded-graphics` primitives, text, and
+//! images and then [`EinkCanvas::flush`] the packed buffer to the panel.
+
+use embedded_graphics_core::{
+    Pixel,
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+};
+
+use crate::{
+    error::DisplayError,
+    framebuffer::Framebuffer,
+    protocol::{DisplayMode, EinkProtocol},
+};
+
+/// An `embedded-graphics` draw target backed by an in-RAM 1bpp framebuffer,
+/// flushed to the panel through [`EinkProtocol::write_image_data`] +
+/// [`EinkProtocol::update_display`].
+///
+/// `BinaryColor::On` maps to a black (inked) pixel and `BinaryColor::Off` to
+/// white (bare paper), matching how e-ink panels present a binary color.
+pub struct EinkCanvas<'a, P: EinkProtocol> {
+    protocol: &'a mut P,
+    framebuffer: Framebuffer,
+}
+
+impl<'a, P: EinkProtocol> EinkCanvas<'a, P> {
+    /// Create a blank (all-white) canvas sized to `protocol`'s
+    /// [`DisplaySpec`](crate::firmware::DisplaySpec).
+    pub fn new(protocol: &'a mut P) -> Self {
+        let spec = protocol.get_spec().clone();
+        let framebuffer = Framebuffer::new(&spec);
+        Self { protocol, framebuffer }
+    }
+
+    /// Push the in-RAM framebuffer to the panel and trigger a refresh.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if the underlying SPI write or refresh fails.
+    pub fn flush(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
+        self.protocol.write_image_data(self.framebuffer.as_bytes())?;
+        self.protocol.update_display(mode)
+    }
+}
+
+impl<P: EinkProtocol> OriginDimensions for EinkCanvas<'_, P> {
+    fn size(&self) -> Size {
+        Size::new(self.framebuffer.width(), self.framebuffer.height())
+    }
+}
+
+impl<P: EinkProtocol> DrawTarget for EinkCanvas<'_, P> {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.y < 0 {
+                continue;
+            }
+
+            // BinaryColor::On is the inked (black) pixel; Off is bare paper.
+            let white = color == BinaryColor::Off;
+            self.framebuffer.set_pixel(point.x as u32, point.y as u32, white);
+        }
+
+        Ok(())
+    }
+}
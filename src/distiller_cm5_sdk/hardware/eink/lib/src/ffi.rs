@@ -173,7 +173,7 @@ pub unsafe extern "C" fn display_image_file(filename: *const c_char, mode: c_int
 /// - `mode`: Display mode (0 = Full, 1 = Partial)
 /// - `scale_mode`: Scale mode (0 = Letterbox, 1 = `CropCenter`, 2 = Stretch)
 /// - `dither_mode`: Dither mode (0 = Threshold, 1 = `FloydSteinberg`, 2 =
-///   Ordered)
+///   Ordered, 3 = Atkinson, 4 = Stucki, 5 = `JarvisJudiceNinke`)
 ///
 /// # Returns
 ///
@@ -210,6 +210,9 @@ pub unsafe extern "C" fn display_image_auto(
         0 => crate::image_processing::DitherMode::Threshold,
         1 => crate::image_processing::DitherMode::FloydSteinberg,
         2 => crate::image_processing::DitherMode::Ordered,
+        3 => crate::image_processing::DitherMode::Atkinson,
+        4 => crate::image_processing::DitherMode::Stucki,
+        5 => crate::image_processing::DitherMode::JarvisJudiceNinke,
         _ => return 0,
     };
 
@@ -222,6 +225,182 @@ pub unsafe extern "C" fn display_image_auto(
     }
 }
 
+/// Push a sub-rectangle of 1-bit data and issue a windowed partial refresh of
+/// just that region, skipping the full-frame dirty-rectangle diff for
+/// callers that already know what changed.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `data` is a valid pointer to at least `ceil(w / 8) * h` bytes
+/// - `data` remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `data`: Pointer to raw 1-bit image data for the region
+/// - `x`, `y`: Top-left corner of the region in pixels (`x` must be a
+///   multiple of 8)
+/// - `w`, `h`: Region dimensions in pixels (`w` must be a multiple of 8)
+/// - `mode`: Display mode (0 = Full, 1 = Partial)
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_image_region(
+    data: *const u8,
+    x: c_uint,
+    y: c_uint,
+    w: c_uint,
+    h: c_uint,
+    mode: c_int,
+) -> c_int {
+    if data.is_null() {
+        return 0;
+    }
+
+    let display_mode = match mode {
+        0 => DisplayMode::Full,
+        1 => DisplayMode::Partial,
+        _ => return 0,
+    };
+
+    let row_bytes = (w / 8) as usize;
+    let data_slice = unsafe { std::slice::from_raw_parts(data, row_bytes * h as usize) };
+
+    match display::display_image_region(data_slice, x, y, w, h, display_mode) {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Display image region failed: {e}");
+            0
+        },
+    }
+}
+
+/// Decode a PNG into 4-gray (2bpp) bitplanes and display it with a full
+/// refresh, using the active firmware's custom waveform LUT.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `filename` is a valid pointer to a null-terminated C string
+/// - The string remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `filename`: Path to PNG file as null-terminated C string
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_image_gray4_png(filename: *const c_char) -> c_int {
+    if filename.is_null() {
+        return 0;
+    }
+
+    let Ok(filename_str) = unsafe { CStr::from_ptr(filename) }.to_str() else {
+        return 0;
+    };
+
+    match display::display_image_gray4_png(filename_str) {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Display image gray4 failed: {e}");
+            0
+        },
+    }
+}
+
+/// Display pixels handed over directly from memory (RGBA, grayscale, or a
+/// complete BMP file), scaling and dithering them the same way
+/// `display_image_auto` does for files.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `data` is a valid pointer to at least `data_len` bytes
+/// - `data` remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `data`, `data_len`: Pointer to and length of the raw pixel buffer
+/// - `width`, `height`: Source image dimensions in pixels (ignored for BMP,
+///   which reads its own dimensions from the file header)
+/// - `format`: Pixel format (0 = RGBA8888, 1 = Grayscale8, 2 = BMP)
+/// - `mode`: Display mode (0 = Full, 1 = Partial)
+/// - `scale_mode`: Scale mode (0 = Letterbox, 1 = `CropCenter`, 2 = Stretch)
+/// - `dither_mode`: Dither mode (0 = Threshold, 1 = `FloydSteinberg`, 2 =
+///   Ordered, 3 = Atkinson, 4 = Stucki, 5 = `JarvisJudiceNinke`)
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn display_image_buffer(
+    data: *const u8,
+    data_len: c_uint,
+    width: c_uint,
+    height: c_uint,
+    format: c_int,
+    mode: c_int,
+    scale_mode: c_int,
+    dither_mode: c_int,
+) -> c_int {
+    if data.is_null() {
+        return 0;
+    }
+
+    let data_slice = unsafe { std::slice::from_raw_parts(data, data_len as usize) };
+
+    let pixel_format = match format {
+        0 => crate::framebuffer::PixelFormat::Rgba8888,
+        1 => crate::framebuffer::PixelFormat::Grayscale8,
+        2 => crate::framebuffer::PixelFormat::Bmp,
+        _ => return 0,
+    };
+
+    let display_mode = match mode {
+        0 => DisplayMode::Full,
+        1 => DisplayMode::Partial,
+        _ => return 0,
+    };
+
+    let scale = match scale_mode {
+        0 => crate::image_processing::ScaleMode::Letterbox,
+        1 => crate::image_processing::ScaleMode::CropCenter,
+        2 => crate::image_processing::ScaleMode::Stretch,
+        _ => return 0,
+    };
+
+    let dither = match dither_mode {
+        0 => crate::image_processing::DitherMode::Threshold,
+        1 => crate::image_processing::DitherMode::FloydSteinberg,
+        2 => crate::image_processing::DitherMode::Ordered,
+        3 => crate::image_processing::DitherMode::Atkinson,
+        4 => crate::image_processing::DitherMode::Stucki,
+        5 => crate::image_processing::DitherMode::JarvisJudiceNinke,
+        _ => return 0,
+    };
+
+    match display::display_image_buffer(
+        data_slice,
+        width,
+        height,
+        pixel_format,
+        display_mode,
+        scale,
+        dither,
+    ) {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Display image buffer failed: {e}");
+            0
+        },
+    }
+}
+
 /// Clear the display to white.
 ///
 /// # Safety
@@ -362,6 +541,10 @@ pub unsafe extern "C" fn convert_png_to_1bit(
 /// # Returns
 ///
 /// 1 on success, 0 on failure
+///
+/// If the display has already been initialized, this swaps the active panel
+/// at runtime (re-initializing hardware with the new firmware) instead of
+/// only updating configuration for a future `display_init` call.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn display_set_firmware(firmware_str: *const c_char) -> c_int {
     if firmware_str.is_null() {
@@ -375,7 +558,15 @@ pub unsafe extern "C" fn display_set_firmware(firmware_str: *const c_char) -> c_
         }
     };
 
-    match config::set_default_firmware_from_str(firmware_str) {
+    let firmware_type = match firmware_str.parse::<config::FirmwareType>() {
+        Ok(t) => t,
+        Err(e) => {
+            log::error!("Failed to parse firmware type: {e}");
+            return 0;
+        },
+    };
+
+    match display::set_firmware(firmware_type) {
         Ok(()) => 1,
         Err(e) => {
             log::error!("Failed to set firmware: {e}");
@@ -0,0 +1,107 @@
+//! FFI exports for the on-device text console.
+//!
+//! This module provides C-compatible functions for driving the styled
+//! character-grid console defined in [`crate::console`].
+
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_int, c_uint},
+};
+
+use crate::console;
+
+/// Initialize the console, sizing its character grid to the active
+/// display's dimensions. The display must already be initialized.
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn console_init() -> c_int {
+    match console::console_init() {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Console init failed: {e}");
+            0
+        },
+    }
+}
+
+/// Print text at the cursor, wrapping and scrolling as needed, and refresh
+/// only the affected character rows.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `text` is a valid pointer to a null-terminated C string
+/// - The string remains valid for the duration of this call
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn console_print(text: *const c_char) -> c_int {
+    if text.is_null() {
+        return 0;
+    }
+
+    let Ok(text_str) = unsafe { CStr::from_ptr(text) }.to_str() else {
+        return 0;
+    };
+
+    match console::console_print(text_str) {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Console print failed: {e}");
+            0
+        },
+    }
+}
+
+/// Move the console cursor to `(row, col)`.
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn console_set_cursor(row: c_uint, col: c_uint) -> c_int {
+    match console::console_set_cursor(row, col) {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Console set cursor failed: {e}");
+            0
+        },
+    }
+}
+
+/// Blank the console and push a full refresh of the now-empty grid.
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn console_clear() -> c_int {
+    match console::console_clear() {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Console clear failed: {e}");
+            0
+        },
+    }
+}
+
+/// Scroll the console up by one row and push a full refresh.
+///
+/// # Returns
+///
+/// 1 on success, 0 on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn console_scroll() -> c_int {
+    match console::console_scroll() {
+        Ok(()) => 1,
+        Err(e) => {
+            log::error!("Console scroll failed: {e}");
+            0
+        },
+    }
+}
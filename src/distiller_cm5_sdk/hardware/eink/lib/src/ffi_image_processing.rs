@@ -236,7 +236,8 @@ pub unsafe extern "C" fn image_flip_vertical_1bit(
 /// - `gray_data`: Input grayscale image data (one byte per pixel)
 /// - `width`: Image width in pixels
 /// - `height`: Image height in pixels
-/// - `mode`: Dithering mode (0=Threshold, 1=FloydSteinberg, 2=Ordered)
+/// - `mode`: Dithering mode (0=Threshold, 1=FloydSteinberg, 2=Ordered,
+///   3=Atkinson, 4=Stucki, 5=JarvisJudiceNinke)
 /// - `output`: Output buffer for 1-bit dithered data
 ///
 /// # Returns
@@ -271,6 +272,9 @@ pub unsafe extern "C" fn image_dither(
         0 => DitherMode::Threshold,
         1 => DitherMode::FloydSteinberg,
         2 => DitherMode::Ordered,
+        3 => DitherMode::Atkinson,
+        4 => DitherMode::Stucki,
+        5 => DitherMode::JarvisJudiceNinke,
         _ => return 0,
     };
 
@@ -298,7 +302,8 @@ pub unsafe extern "C" fn image_dither(
 ///
 /// - `path`: Path to input image file
 /// - `scale_mode`: Scaling mode (0=Letterbox, 1=CropCenter, 2=Stretch)
-/// - `dither_mode`: Dithering mode (0=Threshold, 1=FloydSteinberg, 2=Ordered)
+/// - `dither_mode`: Dithering mode (0=Threshold, 1=FloydSteinberg, 2=Ordered,
+///   3=Atkinson, 4=Stucki, 5=JarvisJudiceNinke)
 /// - `brightness`: Brightness adjustment (-100 to +100, or -999 for none)
 /// - `contrast`: Contrast adjustment (-100 to +100, or -999 for none)
 /// - `transform`: Transformation (0=None, 1=Rotate90, 2=Rotate180, 3=Rotate270,
@@ -348,6 +353,9 @@ pub unsafe extern "C" fn image_process(
         0 => DitherMode::Threshold,
         1 => DitherMode::FloydSteinberg,
         2 => DitherMode::Ordered,
+        3 => DitherMode::Atkinson,
+        4 => DitherMode::Stucki,
+        5 => DitherMode::JarvisJudiceNinke,
         _ => return 0,
     };
 
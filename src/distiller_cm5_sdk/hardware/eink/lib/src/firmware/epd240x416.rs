@@ -315,6 +315,20 @@ impl DisplayFirmware for EPD240x416Firmware {
             .reset()
             .delay(20) // Reset delay (longer than default)
     }
+
+    fn get_gray4_init_sequence(&self) -> Option<CommandSequence> {
+        Some(self.get_4g_init_sequence())
+    }
+
+    fn get_gray4_lut_sequence(&self) -> Option<CommandSequence> {
+        Some(self.get_4g_lut_sequence())
+    }
+
+    fn get_gray4_plane_commands(&self) -> Option<(u8, u8)> {
+        // "Old" data RAM (0x10) carries the high bitplane, "new" data RAM
+        // (0x13) carries the low bitplane, matching `epd_w21_write_4g_img`.
+        Some((0x10, 0x13))
+    }
 }
 
 impl Default for EPD240x416Firmware {
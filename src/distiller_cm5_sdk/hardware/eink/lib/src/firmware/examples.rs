@@ -3,8 +3,9 @@
 /// This file demonstrates how to use the various firmware configurations
 /// for different display modes and variants.
 
+use crate::config::register_firmware;
 use crate::firmware::{EPD240x416Firmware, EPD128x250Firmware};
-use crate::protocol::{create_protocol_with_firmware, DisplayMode};
+use crate::protocol::{create_protocol_by_name, create_protocol_with_firmware, DisplayMode};
 use crate::display::{GenericDisplay, DisplayDriver};
 use crate::error::DisplayError;
 
@@ -94,6 +95,21 @@ pub fn example_runtime_switch(display_type: &str) -> Result<(), DisplayError> {
     Ok(())
 }
 
+/// Example: Runtime firmware selection via the named registry, so adding a
+/// new panel means registering a factory once instead of editing this match
+/// statement (compare with `example_runtime_switch` above).
+pub fn example_registry_switch(display_type: &str) -> Result<(), DisplayError> {
+    register_firmware("240x416", || Box::new(EPD240x416Firmware::new()))?;
+    register_firmware("128x250", || Box::new(EPD128x250Firmware::new()))?;
+
+    let protocol = create_protocol_by_name(display_type)?;
+    let mut display = GenericDisplay::new(protocol);
+    display.init()?;
+    display.cleanup()?;
+
+    Ok(())
+}
+
 /// Example: Creating a custom display variant
 /// This shows how you would create a new firmware for a different display
 pub fn example_custom_firmware() {
@@ -96,7 +96,10 @@ impl CommandSequence {
 }
 
 /// Firmware interface trait - implement this for different display variants
-pub trait DisplayFirmware {
+///
+/// Requires `Send + Sync` so a `GenericEinkProtocol` built over it can
+/// satisfy [`crate::protocol::EinkProtocol`]'s `Send + Sync` bound.
+pub trait DisplayFirmware: Send + Sync {
     /// Get the display specifications
     fn get_spec(&self) -> &DisplaySpec;
     /// Get the initialization command sequence
@@ -115,6 +118,58 @@ pub trait DisplayFirmware {
         CommandSequence::new().reset().delay(10)
     }
 
+    /// Get the command sequence that programs the controller's RAM X/Y
+    /// address window and cursor to a sub-rectangle, ahead of a windowed
+    /// partial update.
+    ///
+    /// `x` and `w` are in pixels but must already be aligned to 8-pixel
+    /// (one byte) boundaries, matching the SSD16xx-family register layout
+    /// used by the built-in firmware variants.
+    fn get_window_sequence(&self, x: u32, y: u32, w: u32, h: u32) -> CommandSequence {
+        let x_start_byte = (x / 8) as u8;
+        let x_end_byte = ((x + w) / 8 - 1) as u8;
+        let y_start = y;
+        let y_end = y + h - 1;
+
+        CommandSequence::new()
+            // Set Ram-X address start/end position
+            .cmd(0x44)
+            .data(x_start_byte)
+            .data(x_end_byte)
+            // Set Ram-Y address start/end position
+            .cmd(0x45)
+            .data((y_start % 256) as u8)
+            .data((y_start / 256) as u8)
+            .data((y_end % 256) as u8)
+            .data((y_end / 256) as u8)
+            // Set RAM x address count
+            .cmd(0x4E)
+            .data(x_start_byte)
+            // Set RAM y address count
+            .cmd(0x4F)
+            .data((y_start % 256) as u8)
+            .data((y_start / 256) as u8)
+    }
+
+    /// Get the initialization sequence for 4-gray (2bpp) mode, if this
+    /// firmware supports it.
+    fn get_gray4_init_sequence(&self) -> Option<CommandSequence> {
+        None
+    }
+
+    /// Get the waveform LUT sequence for 4-gray mode, if this firmware
+    /// supports it.
+    fn get_gray4_lut_sequence(&self) -> Option<CommandSequence> {
+        None
+    }
+
+    /// Get the `(old_plane_command, new_plane_command)` RAM write command
+    /// pair used to stream the two 1bpp bitplanes of a 4-gray frame, if this
+    /// firmware supports it.
+    fn get_gray4_plane_commands(&self) -> Option<(u8, u8)> {
+        None
+    }
+
     /// Validate that image data is the correct size
     ///
     /// # Errors
@@ -131,3 +186,33 @@ pub trait DisplayFirmware {
         Ok(())
     }
 }
+
+// Object-safe erasure so a firmware variant registered at runtime (see
+// crate::config::register_firmware) can be held generically by
+// GenericEinkProtocol, instead of requiring a concrete, compile-time type
+// added to ConfigurableProtocol and all its match arms.
+impl DisplayFirmware for Box<dyn DisplayFirmware> {
+    fn get_spec(&self) -> &DisplaySpec {
+        (**self).get_spec()
+    }
+
+    fn get_init_sequence(&self) -> CommandSequence {
+        (**self).get_init_sequence()
+    }
+
+    fn get_partial_init_sequence(&self) -> CommandSequence {
+        (**self).get_partial_init_sequence()
+    }
+
+    fn get_update_sequence(&self, is_partial: bool) -> CommandSequence {
+        (**self).get_update_sequence(is_partial)
+    }
+
+    fn get_sleep_sequence(&self) -> CommandSequence {
+        (**self).get_sleep_sequence()
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        (**self).get_write_ram_command()
+    }
+}
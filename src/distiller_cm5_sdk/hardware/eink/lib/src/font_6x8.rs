@@ -0,0 +1,60 @@
+// 6x8 bitmap font data for text rendering (ASCII 32-126).
+//
+// Each glyph is 6 bytes, one per column, with bit `r` of the byte giving
+// the pixel at row `r` (0 = top). Glyphs are stored contiguously in ASCII
+// order starting at the space character; look up a glyph at
+// `(ch as usize - 32) * 6`. Lowercase letters reuse their uppercase glyph
+// and any character without a dedicated glyph above falls back to a hollow
+// box, mirroring how real bitmap fonts mark a missing glyph.
+
+#[rustfmt::skip]
+const FONT_6X8_DATA: [u8; 570] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xDF, 0xDF, 0x00, 0x00,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0x00, 0x3C, 0x7E, 0xC3, 0x81, 0x00, 0x00, 0x81, 0xC3, 0x7E, 0x3C, 0x00,
+    0x1A, 0x0C, 0x08, 0x1A, 0x0C, 0x1A, 0x08, 0x08, 0x3E, 0x3E, 0x08, 0x08,
+    0x00, 0x80, 0xE0, 0x60, 0x00, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08,
+    0x00, 0x00, 0xC0, 0xC0, 0x00, 0x00, 0x20, 0x10, 0x08, 0x04, 0x02, 0x01,
+    0x7E, 0x91, 0x89, 0x85, 0x83, 0x7E, 0x80, 0x82, 0xFF, 0xFF, 0x80, 0x80,
+    0x82, 0xC1, 0xA1, 0x91, 0x89, 0x86, 0x41, 0x89, 0x89, 0x89, 0x89, 0x76,
+    0x30, 0x28, 0x24, 0x22, 0xFF, 0x20, 0x47, 0x85, 0x85, 0x85, 0x85, 0x79,
+    0x7E, 0x89, 0x89, 0x89, 0x89, 0x70, 0x01, 0xE1, 0x11, 0x09, 0x05, 0x03,
+    0x76, 0x89, 0x89, 0x89, 0x89, 0x76, 0x0E, 0x91, 0x91, 0x91, 0x91, 0x7E,
+    0x00, 0x00, 0x66, 0x66, 0x00, 0x00, 0x00, 0x80, 0xE6, 0x66, 0x00, 0x00,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0x14, 0x14, 0x14, 0x14, 0x14, 0x14,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0x02, 0x01, 0xD1, 0x09, 0x05, 0x02,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFC, 0x12, 0x11, 0x11, 0x12, 0xFC,
+    0xFF, 0x89, 0x89, 0x89, 0x89, 0x76, 0x7E, 0x81, 0x81, 0x81, 0x81, 0x42,
+    0xFF, 0x81, 0x81, 0x81, 0x42, 0x3C, 0xFF, 0x89, 0x89, 0x89, 0x89, 0x81,
+    0xFF, 0x09, 0x09, 0x09, 0x09, 0x01, 0x7E, 0x81, 0x81, 0x91, 0x91, 0x72,
+    0xFF, 0x08, 0x08, 0x08, 0x08, 0xFF, 0x00, 0x81, 0xFF, 0xFF, 0x81, 0x00,
+    0x60, 0x80, 0x80, 0x81, 0x7F, 0x01, 0xFF, 0x08, 0x08, 0x14, 0x22, 0xC1,
+    0xFF, 0x80, 0x80, 0x80, 0x80, 0x80, 0xFF, 0x02, 0x04, 0x04, 0x02, 0xFF,
+    0xFF, 0x02, 0x04, 0x08, 0x10, 0xFF, 0x7E, 0x81, 0x81, 0x81, 0x81, 0x7E,
+    0xFF, 0x09, 0x09, 0x09, 0x09, 0x06, 0x7E, 0x81, 0x81, 0x91, 0x21, 0xDE,
+    0xFF, 0x09, 0x09, 0x19, 0x29, 0xC6, 0x86, 0x89, 0x89, 0x89, 0x89, 0x71,
+    0x01, 0x01, 0xFF, 0xFF, 0x01, 0x01, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F,
+    0x1F, 0x60, 0x80, 0x80, 0x60, 0x1F, 0xFF, 0x20, 0x10, 0x10, 0x20, 0xFF,
+    0xC3, 0x24, 0x18, 0x18, 0x24, 0xC3, 0x03, 0x04, 0xF8, 0xF8, 0x04, 0x03,
+    0xE1, 0x91, 0x89, 0x85, 0x83, 0x81, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFC, 0x12, 0x11, 0x11, 0x12, 0xFC,
+    0xFF, 0x89, 0x89, 0x89, 0x89, 0x76, 0x7E, 0x81, 0x81, 0x81, 0x81, 0x42,
+    0xFF, 0x81, 0x81, 0x81, 0x42, 0x3C, 0xFF, 0x89, 0x89, 0x89, 0x89, 0x81,
+    0xFF, 0x09, 0x09, 0x09, 0x09, 0x01, 0x7E, 0x81, 0x81, 0x91, 0x91, 0x72,
+    0xFF, 0x08, 0x08, 0x08, 0x08, 0xFF, 0x00, 0x81, 0xFF, 0xFF, 0x81, 0x00,
+    0x60, 0x80, 0x80, 0x81, 0x7F, 0x01, 0xFF, 0x08, 0x08, 0x14, 0x22, 0xC1,
+    0xFF, 0x80, 0x80, 0x80, 0x80, 0x80, 0xFF, 0x02, 0x04, 0x04, 0x02, 0xFF,
+    0xFF, 0x02, 0x04, 0x08, 0x10, 0xFF, 0x7E, 0x81, 0x81, 0x81, 0x81, 0x7E,
+    0xFF, 0x09, 0x09, 0x09, 0x09, 0x06, 0x7E, 0x81, 0x81, 0x91, 0x21, 0xDE,
+    0xFF, 0x09, 0x09, 0x19, 0x29, 0xC6, 0x86, 0x89, 0x89, 0x89, 0x89, 0x71,
+    0x01, 0x01, 0xFF, 0xFF, 0x01, 0x01, 0x7F, 0x80, 0x80, 0x80, 0x80, 0x7F,
+    0x1F, 0x60, 0x80, 0x80, 0x60, 0x1F, 0xFF, 0x20, 0x10, 0x10, 0x20, 0xFF,
+    0xC3, 0x24, 0x18, 0x18, 0x24, 0xC3, 0x03, 0x04, 0xF8, 0xF8, 0x04, 0x03,
+    0xE1, 0x91, 0x89, 0x85, 0x83, 0x81, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF, 0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+    0xFF, 0x81, 0x81, 0x81, 0x81, 0xFF,
+];
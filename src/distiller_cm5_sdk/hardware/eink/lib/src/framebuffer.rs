@@ -0,0 +1,297 @@
+//! In-memory framebuffer and raw/BMP pixel ingestion, for callers that
+//! already have pixels in RAM (GUI toolkits, camera frames, generated
+//! content) instead of a file on disk.
+
+use image::{DynamicImage, GrayImage, RgbaImage};
+
+use crate::{
+    error::DisplayError,
+    firmware::DisplaySpec,
+    image_processing::{DitherMode, ImageProcessor, ScaleMode},
+};
+
+/// Pixel format of a raw buffer handed to [`Framebuffer::from_raw`].
+#[derive(Debug, Clone, Copy)]
+pub enum PixelFormat {
+    /// 4 bytes per pixel, red/green/blue/alpha.
+    Rgba8888,
+    /// 1 byte per pixel, grayscale.
+    Grayscale8,
+    /// A complete BMP file (header + pixel data); `width`/`height` are read
+    /// from the BMP header rather than the caller-supplied dimensions.
+    Bmp,
+}
+
+/// A 1-bit packed frame sized to a display's [`DisplaySpec`], with in-place
+/// drawing primitives. Bit value `1` is a white pixel, `0` is black,
+/// matching the packing used throughout [`crate::image`].
+pub struct Framebuffer {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Framebuffer {
+    /// Create a blank (all-white) framebuffer sized to `spec`.
+    #[must_use]
+    pub fn new(spec: &DisplaySpec) -> Self {
+        Self {
+            width: spec.width,
+            height: spec.height,
+            data: vec![0xFF; spec.array_size()],
+        }
+    }
+
+    /// Decode a raw pixel buffer, scale and dither it to `spec`, and return
+    /// the resulting framebuffer ready to hand to `display_image_raw`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Png` if `data` cannot be interpreted as the
+    /// given `format`, and `DisplayError::InvalidDataSize` if `data` is
+    /// too short for `width`/`height` in formats that take explicit
+    /// dimensions.
+    pub fn from_raw(
+        data: &[u8],
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        spec: &DisplaySpec,
+        scale_mode: ScaleMode,
+        dither_mode: DitherMode,
+    ) -> Result<Self, DisplayError> {
+        let img = match format {
+            PixelFormat::Rgba8888 => {
+                let expected = (width as usize)
+                    .saturating_mul(height as usize)
+                    .saturating_mul(4);
+                if data.len() < expected {
+                    return Err(DisplayError::InvalidDataSize {
+                        expected,
+                        actual: data.len(),
+                    });
+                }
+                let buf = RgbaImage::from_raw(width, height, data[..expected].to_vec())
+                    .ok_or_else(|| DisplayError::Png("Invalid RGBA8888 buffer".to_string()))?;
+                DynamicImage::ImageRgba8(buf)
+            },
+            PixelFormat::Grayscale8 => {
+                let expected = (width as usize).saturating_mul(height as usize);
+                if data.len() < expected {
+                    return Err(DisplayError::InvalidDataSize {
+                        expected,
+                        actual: data.len(),
+                    });
+                }
+                let buf = GrayImage::from_raw(width, height, data[..expected].to_vec())
+                    .ok_or_else(|| DisplayError::Png("Invalid Grayscale8 buffer".to_string()))?;
+                DynamicImage::ImageLuma8(buf)
+            },
+            PixelFormat::Bmp => decode_bmp(data)?,
+        };
+
+        let processor = ImageProcessor::new(spec.clone());
+        let packed =
+            processor.process_dynamic_image(img, scale_mode, dither_mode, None, None, None, false);
+
+        Ok(Self {
+            width: spec.width,
+            height: spec.height,
+            data: packed,
+        })
+    }
+
+    /// Framebuffer width in pixels.
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Framebuffer height in pixels.
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Packed 1-bit data, ready for `display_image_raw`.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Set a single pixel; `true` is white, `false` is black. Out-of-bounds
+    /// coordinates are ignored.
+    pub fn set_pixel(&mut self, x: u32, y: u32, white: bool) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let pixel_idx = (y * self.width + x) as usize;
+        let byte_idx = pixel_idx / 8;
+        let bit_idx = pixel_idx % 8;
+
+        if white {
+            self.data[byte_idx] |= 1 << (7 - bit_idx);
+        } else {
+            self.data[byte_idx] &= !(1 << (7 - bit_idx));
+        }
+    }
+
+    /// Read a single pixel; out-of-bounds coordinates read as white.
+    #[must_use]
+    pub fn get_pixel(&self, x: u32, y: u32) -> bool {
+        if x >= self.width || y >= self.height {
+            return true;
+        }
+
+        let pixel_idx = (y * self.width + x) as usize;
+        let byte_idx = pixel_idx / 8;
+        let bit_idx = pixel_idx % 8;
+
+        (self.data[byte_idx] >> (7 - bit_idx)) & 1 == 1
+    }
+
+    /// Fill a rectangle with a solid color; `true` is white, `false` is
+    /// black. The rectangle is clipped to the framebuffer bounds.
+    pub fn fill_rect(&mut self, x: u32, y: u32, w: u32, h: u32, white: bool) {
+        let x_end = (x + w).min(self.width);
+        let y_end = (y + h).min(self.height);
+
+        for py in y..y_end {
+            for px in x..x_end {
+                self.set_pixel(px, py, white);
+            }
+        }
+    }
+
+    /// Copy another framebuffer's pixels into this one at `(dst_x, dst_y)`,
+    /// clipping to this framebuffer's bounds.
+    pub fn blit(&mut self, src: &Self, dst_x: u32, dst_y: u32) {
+        for sy in 0..src.height {
+            for sx in 0..src.width {
+                self.set_pixel(dst_x + sx, dst_y + sy, src.get_pixel(sx, sy));
+            }
+        }
+    }
+}
+
+/// Decode a BMP file's `BITMAPINFOHEADER` and pixel array into a
+/// [`DynamicImage`]. Supports uncompressed 24-bit and 32-bit RGB(A) and
+/// 8-bit paletted images, both bottom-up (the common case) and top-down
+/// row order.
+fn decode_bmp(data: &[u8]) -> Result<DynamicImage, DisplayError> {
+    const HEADER_ERR: &str = "Truncated BMP header";
+
+    let read_u16 = |offset: usize| -> Result<u16, DisplayError> {
+        data.get(offset..offset + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .ok_or_else(|| DisplayError::Png(HEADER_ERR.to_string()))
+    };
+    let read_u32 = |offset: usize| -> Result<u32, DisplayError> {
+        data.get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .ok_or_else(|| DisplayError::Png(HEADER_ERR.to_string()))
+    };
+    let read_i32 = |offset: usize| -> Result<i32, DisplayError> {
+        data.get(offset..offset + 4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .ok_or_else(|| DisplayError::Png(HEADER_ERR.to_string()))
+    };
+
+    if data.len() < 54 || &data[0..2] != b"BM" {
+        return Err(DisplayError::Png("Not a BMP file".to_string()));
+    }
+
+    let pixel_data_offset = read_u32(10)? as usize;
+    let header_size = read_u32(14)?;
+    if header_size < 40 {
+        return Err(DisplayError::Png(format!(
+            "Unsupported BMP header size: {header_size}"
+        )));
+    }
+
+    let width = read_i32(18)?;
+    let raw_height = read_i32(22)?;
+    let bpp = read_u16(28)?;
+    let compression = read_u32(30)?;
+
+    if compression != 0 {
+        return Err(DisplayError::Png(
+            "Unsupported BMP compression (only BI_RGB is supported)".to_string(),
+        ));
+    }
+    if width <= 0 {
+        return Err(DisplayError::Png(format!("Invalid BMP width: {width}")));
+    }
+
+    let width = width as u32;
+    let top_down = raw_height < 0;
+    let height = raw_height.unsigned_abs();
+
+    // Each row is padded to a 4-byte boundary.
+    let row_bytes = ((width as usize) * (bpp as usize) / 8).div_ceil(4) * 4;
+
+    let palette = if bpp == 8 {
+        let palette_offset = 14 + header_size as usize;
+        let mut entries = Vec::with_capacity(256);
+        for i in 0..256 {
+            let entry_offset = palette_offset + i * 4;
+            let Some(entry) = data.get(entry_offset..entry_offset + 4) else {
+                break;
+            };
+            // Palette entries are stored BGRA; take the red channel as a
+            // stand-in gray level (BMP palettes used here are grayscale).
+            entries.push(entry[2]);
+        }
+        Some(entries)
+    } else {
+        None
+    };
+
+    let mut gray = GrayImage::new(width, height);
+
+    for row in 0..height {
+        // BMP rows are stored bottom-up unless the height is negative.
+        let src_row = if top_down { row } else { height - 1 - row };
+        let row_start = pixel_data_offset + src_row as usize * row_bytes;
+
+        for col in 0..width {
+            let gray_value = match bpp {
+                24 => {
+                    let px = row_start + col as usize * 3;
+                    let Some(bgr) = data.get(px..px + 3) else {
+                        return Err(DisplayError::Png("Truncated BMP pixel data".to_string()));
+                    };
+                    ((u16::from(bgr[0]) + u16::from(bgr[1]) + u16::from(bgr[2])) / 3) as u8
+                },
+                32 => {
+                    let px = row_start + col as usize * 4;
+                    let Some(bgra) = data.get(px..px + 4) else {
+                        return Err(DisplayError::Png("Truncated BMP pixel data".to_string()));
+                    };
+                    ((u16::from(bgra[0]) + u16::from(bgra[1]) + u16::from(bgra[2])) / 3) as u8
+                },
+                8 => {
+                    let px = row_start + col as usize;
+                    let Some(&index) = data.get(px) else {
+                        return Err(DisplayError::Png("Truncated BMP pixel data".to_string()));
+                    };
+                    palette
+                        .as_ref()
+                        .and_then(|p| p.get(index as usize))
+                        .copied()
+                        .unwrap_or(index)
+                },
+                other => {
+                    return Err(DisplayError::Png(format!(
+                        "Unsupported BMP bit depth: {other}"
+                    )));
+                },
+            };
+
+            gray.put_pixel(col, row, image::Luma([gray_value]));
+        }
+    }
+
+    Ok(DynamicImage::ImageLuma8(gray))
+}
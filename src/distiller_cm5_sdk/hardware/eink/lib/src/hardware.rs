@@ -16,7 +16,10 @@ pub const RST_PIN: NonZeroU32 = NonZeroU32::new(13).unwrap();
 pub const BUSY_PIN: NonZeroU32 = NonZeroU32::new(9).unwrap();
 
 /// GPIO Controller trait for different hardware variants
-pub trait GpioController {
+///
+/// Requires `Send + Sync` so a `GenericEinkProtocol` built over it can
+/// satisfy [`crate::protocol::EinkProtocol`]'s `Send + Sync` bound.
+pub trait GpioController: Send + Sync {
     /// Create a new GPIO controller instance
     ///
     /// # Errors
@@ -108,7 +111,10 @@ impl GpioController for DefaultGpioController {
 }
 
 /// SPI Controller trait for different hardware variants
-pub trait SpiController {
+///
+/// Requires `Send + Sync` so a `GenericEinkProtocol` built over it can
+/// satisfy [`crate::protocol::EinkProtocol`]'s `Send + Sync` bound.
+pub trait SpiController: Send + Sync {
     /// Create a new SPI controller instance
     ///
     /// # Errors
@@ -192,6 +198,16 @@ impl<G: GpioController, S: SpiController> HardwareInterface<G, S> {
         Ok(Self { gpio, spi })
     }
 
+    /// Build a hardware interface from already-constructed GPIO and SPI
+    /// controllers, for backends like
+    /// [`EmbeddedHalGpio`](crate::hardware_hal::EmbeddedHalGpio)/
+    /// [`EmbeddedHalSpi`](crate::hardware_hal::EmbeddedHalSpi) whose pin/bus
+    /// handles come from the caller rather than from a single well-known
+    /// device that `G::new()`/`S::new()` can open on their own.
+    pub fn from_parts(gpio: G, spi: S) -> Self {
+        Self { gpio, spi }
+    }
+
     /// Set the Data/Command pin state
     ///
     /// # Errors
@@ -0,0 +1,139 @@
+//! Hardware backend built on the `embedded-hal` 1.0 traits, so the same
+//! `GenericEinkProtocol` can drive a panel on any board exposing
+//! `embedded-hal`-compatible GPIO/SPI handles (rp2040, esp32,
+//! STM32/embassy, ...) instead of only Linux's `gpiod`/`spidev`.
+//!
+//! Construct the pin/bus handles with the target's HAL crate, then build a
+//! [`HardwareInterface`] from them with [`HardwareInterface::from_parts`],
+//! bypassing [`GpioController::new`]/[`SpiController::new`] -- those only
+//! make sense for [`DefaultGpioController`]/[`DefaultSpiController`]'s
+//! single well-known Linux device, not a caller-supplied HAL handle.
+//!
+//! [`DefaultGpioController`]: crate::hardware::DefaultGpioController
+//! [`DefaultSpiController`]: crate::hardware::DefaultSpiController
+
+use std::sync::Mutex;
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{InputPin, OutputPin};
+use embedded_hal::spi::SpiDevice;
+
+use crate::error::DisplayError;
+use crate::hardware::{GpioController, HardwareInterface, SpiController};
+
+const NO_DEFAULT_CTOR: &str = "has no default pin assignment; construct it directly and pass it \
+                                to HardwareInterface::from_parts instead of HardwareInterface::new";
+
+/// `embedded-hal` GPIO backend: DC/RST as [`OutputPin`], BUSY as
+/// [`InputPin`]. The pins are wrapped in a `Mutex` so the type can satisfy
+/// [`GpioController`]'s `&self` methods (and remain `Sync`) despite
+/// `embedded-hal`'s pin traits taking `&mut self`.
+pub struct EmbeddedHalGpio<DC, RST, BUSY> {
+    dc: Mutex<DC>,
+    rst: Mutex<RST>,
+    busy: Mutex<BUSY>,
+}
+
+impl<DC, RST, BUSY> EmbeddedHalGpio<DC, RST, BUSY>
+where
+    DC: OutputPin,
+    RST: OutputPin,
+    BUSY: InputPin,
+{
+    /// Wrap already-configured `embedded-hal` pin handles.
+    pub fn new(dc: DC, rst: RST, busy: BUSY) -> Self {
+        Self {
+            dc: Mutex::new(dc),
+            rst: Mutex::new(rst),
+            busy: Mutex::new(busy),
+        }
+    }
+}
+
+impl<DC, RST, BUSY> GpioController for EmbeddedHalGpio<DC, RST, BUSY>
+where
+    DC: OutputPin + Send,
+    RST: OutputPin + Send,
+    BUSY: InputPin + Send,
+{
+    fn new() -> Result<Self, DisplayError> {
+        Err(DisplayError::Config(format!("EmbeddedHalGpio {NO_DEFAULT_CTOR}")))
+    }
+
+    fn write_dc(&self, value: bool) -> Result<(), DisplayError> {
+        let mut dc = self.dc.lock().unwrap();
+        let result = if value { dc.set_high() } else { dc.set_low() };
+        result.map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))
+    }
+
+    fn write_rst(&self, value: bool) -> Result<(), DisplayError> {
+        let mut rst = self.rst.lock().unwrap();
+        let result = if value { rst.set_high() } else { rst.set_low() };
+        result.map_err(|_| DisplayError::Gpio("Failed to set RST pin".to_string()))
+    }
+
+    fn read_busy(&self) -> Result<bool, DisplayError> {
+        self.busy
+            .lock()
+            .unwrap()
+            .is_high()
+            .map_err(|_| DisplayError::Gpio("Failed to read BUSY pin".to_string()))
+    }
+}
+
+/// `embedded-hal` SPI backend, built on [`SpiDevice`] (which owns chip-select
+/// handling) and [`DelayNs`] for the brief pause between chunks of an
+/// oversized transfer, replacing the `std::thread::sleep` used for the same
+/// purpose in [`DefaultSpiController`](crate::hardware::DefaultSpiController).
+pub struct EmbeddedHalSpi<SPI, DELAY> {
+    spi: SPI,
+    delay: DELAY,
+}
+
+impl<SPI, DELAY> EmbeddedHalSpi<SPI, DELAY>
+where
+    SPI: SpiDevice,
+    DELAY: DelayNs,
+{
+    /// Wrap an already-configured `embedded-hal` SPI device and delay
+    /// provider.
+    pub fn new(spi: SPI, delay: DELAY) -> Self {
+        Self { spi, delay }
+    }
+}
+
+impl<SPI, DELAY> SpiController for EmbeddedHalSpi<SPI, DELAY>
+where
+    SPI: SpiDevice + Send + Sync,
+    DELAY: DelayNs + Send + Sync,
+{
+    fn new() -> Result<Self, DisplayError> {
+        Err(DisplayError::Config(format!("EmbeddedHalSpi {NO_DEFAULT_CTOR}")))
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        // Mirror DefaultSpiController's chunking so a single oversized
+        // transfer doesn't overrun a target's DMA/FIFO buffer.
+        const MAX_CHUNK_SIZE: usize = 4096;
+
+        if data.len() <= MAX_CHUNK_SIZE {
+            self.spi
+                .write(data)
+                .map_err(|e| DisplayError::Spi(format!("Failed to write data: {e:?}")))
+        } else {
+            for chunk in data.chunks(MAX_CHUNK_SIZE) {
+                self.spi
+                    .write(chunk)
+                    .map_err(|e| DisplayError::Spi(format!("Failed to write data chunk: {e:?}")))?;
+
+                self.delay.delay_us(100);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A [`HardwareInterface`] backed entirely by `embedded-hal` 1.0 pin/bus
+/// handles, for boards outside the Linux `gpiod`/`spidev` ecosystem.
+pub type EmbeddedHalHardwareInterface<DC, RST, BUSY, SPI, DELAY> =
+    HardwareInterface<EmbeddedHalGpio<DC, RST, BUSY>, EmbeddedHalSpi<SPI, DELAY>>;
@@ -57,6 +57,62 @@ pub fn convert_png_to_1bit(filename: &str) -> Result<Vec<u8>, DisplayError> {
     convert_png_to_1bit_with_spec(filename, &spec)
 }
 
+/// Convert a PNG image to a pair of 4-gray (2bpp) bitplanes for a specific
+/// display spec.
+///
+/// Each pixel is quantized to one of 4 gray levels, then split into a high
+/// and a low bitplane (`(old_plane, new_plane)`), matching the controller's
+/// two-bank 4-gray RAM layout.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Png` if the file cannot be read or conversion fails
+pub fn convert_png_to_gray4_with_spec(
+    filename: &str,
+    spec: &DisplaySpec,
+) -> Result<(Vec<u8>, Vec<u8>), DisplayError> {
+    let image = lodepng::decode32_file(filename)
+        .map_err(|e| DisplayError::Png(format!("Failed to decode PNG: {e}")))?;
+
+    if image.width != spec.width as usize || image.height != spec.height as usize {
+        return Err(DisplayError::Png(format!(
+            "Invalid image size: {}x{}, expected {}x{}",
+            image.width, image.height, spec.width, spec.height
+        )));
+    }
+
+    let mut old_plane = vec![0u8; spec.array_size()];
+    let mut new_plane = vec![0u8; spec.array_size()];
+
+    for y in 0..image.height {
+        for x in 0..image.width {
+            let pixel_idx = y * image.width + x;
+            let pixel = image.buffer[pixel_idx];
+
+            // Convert RGBA to grayscale
+            let gray = (u16::from(pixel.r) + u16::from(pixel.g) + u16::from(pixel.b)) / 3;
+
+            // Quantize to one of 4 levels, then split into a high and a low
+            // bit: high bit -> old plane, low bit -> new plane.
+            let level = (gray / 64).min(3);
+            let high_bit = (level >> 1) & 1;
+            let low_bit = level & 1;
+
+            let byte_idx = pixel_idx / 8;
+            let bit_idx = pixel_idx % 8;
+
+            if high_bit == 1 {
+                old_plane[byte_idx] |= 1 << (7 - bit_idx);
+            }
+            if low_bit == 1 {
+                new_plane[byte_idx] |= 1 << (7 - bit_idx);
+            }
+        }
+    }
+
+    Ok((old_plane, new_plane))
+}
+
 /// Get display dimensions from a display spec
 #[must_use]
 pub fn get_dimensions_from_spec(spec: &DisplaySpec) -> (u32, u32) {
@@ -4,32 +4,46 @@
 //! This library provides a comprehensive interface for e-ink display control
 //! including hardware abstraction, firmware variants, image processing, and
 //! configuration management.
+//!
+//! This is the CM5-specific counterpart to `distiller-sdk-eink`; the two
+//! crates share the same API shape but build and version independently.
 
 #![warn(clippy::all)]
-#![warn(clippy::pedantic)]
-#![warn(missing_docs)]
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::cast_sign_loss)]
 
+/// Async `EinkProtocol` variant built on `embedded-hal-async`
+pub mod async_protocol;
 pub mod config;
+pub mod console;
 pub mod display;
+/// `embedded-graphics` `DrawTarget` integration (feature = "embedded-graphics")
+#[cfg(feature = "embedded-graphics")]
+pub mod embedded_graphics_target;
 pub mod error;
 /// FFI bindings for C interoperability
 pub mod ffi;
+/// FFI bindings for the text console
+pub mod ffi_console;
 /// FFI bindings for image processing functions
 pub mod ffi_image_processing;
 pub mod firmware;
+pub mod framebuffer;
 pub mod hardware;
+pub mod hardware_hal;
 pub mod image;
 pub mod image_processing;
 pub mod protocol;
 
 // Re-export public API
+pub use async_protocol::{AsyncEinkProtocol, GenericAsyncEinkProtocol};
 pub use config::{
     FirmwareType,
+    create_firmware_by_name,
     get_default_firmware,
     initialize_config,
+    register_firmware,
     set_default_firmware,
     set_default_firmware_from_str,
 };
@@ -40,19 +54,26 @@ pub use display::{
     display_clear,
     display_get_dimensions,
     display_image_auto,
+    display_image_buffer,
     display_image_file,
+    display_image_gray4_png,
     display_image_png,
+    display_image_region,
     display_image_raw,
     display_init,
     display_sleep,
 };
+pub use console::{CellStyle, Console};
+#[cfg(feature = "embedded-graphics")]
+pub use embedded_graphics_target::EinkCanvas;
 pub use error::DisplayError;
 pub use firmware::{Command, CommandSequence, DisplayFirmware, DisplaySpec};
+pub use framebuffer::{Framebuffer, PixelFormat};
 pub use hardware::{DefaultHardwareInterface, GpioController, HardwareInterface, SpiController};
+pub use hardware_hal::{EmbeddedHalGpio, EmbeddedHalHardwareInterface, EmbeddedHalSpi};
 pub use image::{
-    convert_image_to_1bit,
-    convert_image_to_1bit_with_spec,
     convert_png_to_1bit,
+    convert_png_to_1bit_with_spec,
     create_black_image,
     create_white_image,
     get_dimensions,
@@ -8,10 +8,23 @@ use crate::hardware::{GpioController, HardwareInterface, SpiController, delay_ms
 pub enum DisplayMode {
     Full = 0,    // Full refresh (slow, high quality)
     Partial = 1, // Partial refresh (fast, good quality)
+    Gray4 = 2,   // 4-level grayscale refresh (two 1bpp bitplanes)
+}
+
+/// Which of a 4-gray controller's two RAM bitplanes a byte stream targets.
+#[derive(Debug, Clone, Copy)]
+pub enum GrayPlane {
+    /// The controller's "old"/high-bit RAM bank
+    Old,
+    /// The controller's "new"/low-bit RAM bank
+    New,
 }
 
 // E-ink display protocol trait
-pub trait EinkProtocol {
+///
+/// Requires `Send + Sync` so `Box<dyn EinkProtocol>` can live behind
+/// `display::GLOBAL_STATE`'s mutex.
+pub trait EinkProtocol: Send + Sync {
     fn init_hardware(&mut self) -> Result<(), DisplayError>;
     fn init_partial(&mut self) -> Result<(), DisplayError>;
     fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError>;
@@ -22,6 +35,101 @@ pub trait EinkProtocol {
     fn sleep(&mut self) -> Result<(), DisplayError>;
     fn get_spec(&self) -> &crate::firmware::DisplaySpec;
     fn get_write_ram_command(&self) -> u8;
+    /// Program the controller's RAM X/Y address window and cursor ahead of a
+    /// windowed partial update. `x`/`w` must already be byte-aligned.
+    fn set_ram_window(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError>;
+    /// Write a sub-rectangle's worth of image bytes without validating the
+    /// length against the firmware's full `array_size`, for use after
+    /// `set_ram_window` has restricted the active window.
+    fn write_region_data(&mut self, data: &[u8]) -> Result<(), DisplayError>;
+    /// Load the firmware's 4-gray waveform LUT into the controller.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if the firmware has no 4-gray support
+    fn write_gray_lut(&mut self) -> Result<(), DisplayError>;
+    /// Write one of the two 1bpp bitplanes a 4-gray update requires.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if the firmware has no 4-gray support
+    fn write_plane(&mut self, plane: GrayPlane, data: &[u8]) -> Result<(), DisplayError>;
+    /// Drive a 4-gray update from a single combined 2bpp framebuffer, for
+    /// callers that don't want to pre-split their own bitplanes.
+    ///
+    /// `planes` must be packed 2 bits per pixel, MSB-first within each byte
+    /// (4 pixels per byte), row-major with no padding. Each pixel's high bit
+    /// goes to the controller's "old" bitplane and its low bit to the
+    /// "new" bitplane, then the gray LUT is loaded and a [`DisplayMode::Gray4`]
+    /// update is triggered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `planes` doesn't match the
+    /// firmware's pixel count, or `DisplayError::Config` if the firmware has
+    /// no 4-gray support.
+    fn update_display_gray4(&mut self, planes: &[u8]) -> Result<(), DisplayError> {
+        let spec = self.get_spec().clone();
+        let pixel_count = (spec.width * spec.height) as usize;
+        let expected = pixel_count.div_ceil(4);
+        if planes.len() != expected {
+            return Err(DisplayError::InvalidDataSize {
+                expected,
+                actual: planes.len(),
+            });
+        }
+
+        let mut old_plane = vec![0u8; spec.array_size()];
+        let mut new_plane = vec![0u8; spec.array_size()];
+
+        for pixel_idx in 0..pixel_count {
+            let src_byte = planes[pixel_idx / 4];
+            let shift = 6 - (pixel_idx % 4) * 2;
+            let value = (src_byte >> shift) & 0b11;
+
+            let byte_idx = pixel_idx / 8;
+            let bit_idx = pixel_idx % 8;
+            if (value >> 1) & 1 == 1 {
+                old_plane[byte_idx] |= 1 << (7 - bit_idx);
+            }
+            if value & 1 == 1 {
+                new_plane[byte_idx] |= 1 << (7 - bit_idx);
+            }
+        }
+
+        self.write_gray_lut()?;
+        self.write_plane(GrayPlane::Old, &old_plane)?;
+        self.write_plane(GrayPlane::New, &new_plane)?;
+        self.update_display(DisplayMode::Gray4)
+    }
+
+    /// Program the RAM window to `(x, y, w, h)`, stream `data` into it, and
+    /// trigger a partial refresh restricted to that rectangle, for callers
+    /// that already know which sub-rectangle changed (a clock, a counter, a
+    /// menu highlight) instead of diffing the whole frame.
+    ///
+    /// `data` must already be tightly packed for the `w`x`h` region (no
+    /// per-row padding), matching what [`Self::write_region_data`] expects.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if programming the window, writing the region,
+    /// or triggering the update fails.
+    fn update_region(
+        &mut self,
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+        data: &[u8],
+    ) -> Result<(), DisplayError> {
+        self.init_partial()?;
+        self.set_ram_window(x, y, w, h)?;
+        let write_ram_cmd = self.get_write_ram_command();
+        self.write_cmd(write_ram_cmd)?;
+        self.write_region_data(data)?;
+        self.update_display(DisplayMode::Partial)
+    }
 }
 
 // Generic E-ink protocol implementation using firmware abstraction
@@ -134,21 +242,58 @@ impl<G: GpioController, S: SpiController, F: DisplayFirmware> EinkProtocol
     fn get_write_ram_command(&self) -> u8 {
         self.firmware.get_write_ram_command()
     }
+
+    fn set_ram_window(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError> {
+        let window_sequence = self.firmware.get_window_sequence(x, y, w, h);
+        self.execute_sequence(window_sequence)
+    }
+
+    fn write_region_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        self.hardware.write_dc(true)?;
+        self.hardware.spi_write_all(data)?;
+        Ok(())
+    }
+
+    fn write_gray_lut(&mut self) -> Result<(), DisplayError> {
+        let init_sequence = self.firmware.get_gray4_init_sequence().ok_or_else(|| {
+            DisplayError::Config("Firmware does not support 4-gray mode".to_string())
+        })?;
+        self.execute_sequence(init_sequence)?;
+
+        let lut_sequence = self.firmware.get_gray4_lut_sequence().ok_or_else(|| {
+            DisplayError::Config("Firmware does not support 4-gray mode".to_string())
+        })?;
+        self.execute_sequence(lut_sequence)
+    }
+
+    fn write_plane(&mut self, plane: GrayPlane, data: &[u8]) -> Result<(), DisplayError> {
+        let (old_cmd, new_cmd) = self.firmware.get_gray4_plane_commands().ok_or_else(|| {
+            DisplayError::Config("Firmware does not support 4-gray mode".to_string())
+        })?;
+
+        let cmd = match plane {
+            GrayPlane::Old => old_cmd,
+            GrayPlane::New => new_cmd,
+        };
+
+        self.write_cmd(cmd)?;
+        self.write_image_data(data)
+    }
 }
 
 // Type alias for the default protocol using current firmware
 // Runtime firmware selection
 pub enum ConfigurableProtocol {
-    EPD128x250(GenericEinkProtocol<
+    EPD128x250(Box<GenericEinkProtocol<
         crate::hardware::DefaultGpioController,
         crate::hardware::DefaultSpiController,
         crate::firmware::EPD128x250Firmware,
-    >),
-    EPD240x416(GenericEinkProtocol<
+    >>),
+    EPD240x416(Box<GenericEinkProtocol<
         crate::hardware::DefaultGpioController,
         crate::hardware::DefaultSpiController,
         crate::firmware::EPD240x416Firmware,
-    >),
+    >>),
 }
 
 impl EinkProtocol for ConfigurableProtocol {
@@ -221,9 +366,101 @@ impl EinkProtocol for ConfigurableProtocol {
             ConfigurableProtocol::EPD240x416(p) => p.get_write_ram_command(),
         }
     }
+
+    fn set_ram_window(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError> {
+        match self {
+            ConfigurableProtocol::EPD128x250(p) => p.set_ram_window(x, y, w, h),
+            ConfigurableProtocol::EPD240x416(p) => p.set_ram_window(x, y, w, h),
+        }
+    }
+
+    fn write_region_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        match self {
+            ConfigurableProtocol::EPD128x250(p) => p.write_region_data(data),
+            ConfigurableProtocol::EPD240x416(p) => p.write_region_data(data),
+        }
+    }
+
+    fn write_gray_lut(&mut self) -> Result<(), DisplayError> {
+        match self {
+            ConfigurableProtocol::EPD128x250(p) => p.write_gray_lut(),
+            ConfigurableProtocol::EPD240x416(p) => p.write_gray_lut(),
+        }
+    }
+
+    fn write_plane(&mut self, plane: GrayPlane, data: &[u8]) -> Result<(), DisplayError> {
+        match self {
+            ConfigurableProtocol::EPD128x250(p) => p.write_plane(plane, data),
+            ConfigurableProtocol::EPD240x416(p) => p.write_plane(plane, data),
+        }
+    }
+}
+
+// Object-safe erasure so the global display state can swap panels at runtime
+// instead of being monomorphized over a single concrete protocol type.
+impl EinkProtocol for Box<dyn EinkProtocol> {
+    fn init_hardware(&mut self) -> Result<(), DisplayError> {
+        (**self).init_hardware()
+    }
+
+    fn init_partial(&mut self) -> Result<(), DisplayError> {
+        (**self).init_partial()
+    }
+
+    fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
+        (**self).write_cmd(cmd)
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
+        (**self).write_data(data)
+    }
+
+    fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        (**self).write_image_data(data)
+    }
+
+    fn check_status(&mut self) -> Result<(), DisplayError> {
+        (**self).check_status()
+    }
+
+    fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
+        (**self).update_display(mode)
+    }
+
+    fn sleep(&mut self) -> Result<(), DisplayError> {
+        (**self).sleep()
+    }
+
+    fn get_spec(&self) -> &crate::firmware::DisplaySpec {
+        (**self).get_spec()
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        (**self).get_write_ram_command()
+    }
+
+    fn set_ram_window(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError> {
+        (**self).set_ram_window(x, y, w, h)
+    }
+
+    fn write_region_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        (**self).write_region_data(data)
+    }
+
+    fn write_gray_lut(&mut self) -> Result<(), DisplayError> {
+        (**self).write_gray_lut()
+    }
+
+    fn write_plane(&mut self, plane: GrayPlane, data: &[u8]) -> Result<(), DisplayError> {
+        (**self).write_plane(plane, data)
+    }
 }
 
-pub type DefaultProtocol = ConfigurableProtocol;
+/// Type-erased protocol used by the global display state so the active panel
+/// can be swapped at runtime instead of being fixed at compile time.
+pub type BoxedProtocol = Box<dyn EinkProtocol>;
+
+pub type DefaultProtocol = BoxedProtocol;
 
 // Helper function to create default protocol
 pub fn create_default_protocol() -> Result<DefaultProtocol, DisplayError> {
@@ -235,18 +472,61 @@ pub fn create_default_protocol() -> Result<DefaultProtocol, DisplayError> {
         crate::config::FirmwareType::EPD128x250
     });
     
-    match firmware_type {
+    let protocol = match firmware_type {
         crate::config::FirmwareType::EPD128x250 => {
             let firmware = crate::firmware::EPD128x250Firmware::new();
             let protocol = GenericEinkProtocol::new(hardware, firmware);
-            Ok(ConfigurableProtocol::EPD128x250(protocol))
+            ConfigurableProtocol::EPD128x250(Box::new(protocol))
         }
         crate::config::FirmwareType::EPD240x416 => {
             let firmware = crate::firmware::EPD240x416Firmware::new();
             let protocol = GenericEinkProtocol::new(hardware, firmware);
-            Ok(ConfigurableProtocol::EPD240x416(protocol))
+            ConfigurableProtocol::EPD240x416(Box::new(protocol))
         }
-    }
+    };
+
+    Ok(Box::new(protocol))
+}
+
+/// Create a boxed protocol for a specific firmware type, swapping the active
+/// panel independently of the globally configured default.
+///
+/// # Errors
+///
+/// Returns `DisplayError` if hardware initialization fails
+pub fn create_protocol_for_type(
+    firmware_type: crate::config::FirmwareType,
+) -> Result<BoxedProtocol, DisplayError> {
+    let hardware = crate::hardware::DefaultHardwareInterface::new()?;
+
+    let protocol = match firmware_type {
+        crate::config::FirmwareType::EPD128x250 => {
+            let firmware = crate::firmware::EPD128x250Firmware::new();
+            ConfigurableProtocol::EPD128x250(Box::new(GenericEinkProtocol::new(hardware, firmware)))
+        }
+        crate::config::FirmwareType::EPD240x416 => {
+            let firmware = crate::firmware::EPD240x416Firmware::new();
+            ConfigurableProtocol::EPD240x416(Box::new(GenericEinkProtocol::new(hardware, firmware)))
+        }
+    };
+
+    Ok(Box::new(protocol))
+}
+
+/// Create a boxed protocol for a firmware variant registered by name via
+/// [`crate::config::register_firmware`], for panels added without a
+/// `FirmwareType` variant or `ConfigurableProtocol` match arm of their own.
+///
+/// # Errors
+///
+/// Returns `DisplayError` if no firmware is registered under `name` or
+/// hardware initialization fails
+pub fn create_protocol_by_name(name: &str) -> Result<BoxedProtocol, DisplayError> {
+    let firmware = crate::config::create_firmware_by_name(name)?;
+    let hardware = crate::hardware::DefaultHardwareInterface::new()?;
+    let protocol: GenericEinkProtocol<_, _, Box<dyn DisplayFirmware>> =
+        GenericEinkProtocol::new(hardware, firmware);
+    Ok(Box::new(protocol))
 }
 
 // Helper function to create protocol with custom firmware
@@ -264,3 +544,34 @@ pub fn create_protocol_with_firmware<F: DisplayFirmware>(
     Ok(GenericEinkProtocol::new(hardware, firmware))
 }
 
+/// Create a protocol for custom firmware on a caller-supplied hardware
+/// interface, e.g. one built from [`crate::hardware_hal::EmbeddedHalGpio`]/
+/// [`crate::hardware_hal::EmbeddedHalSpi`] via
+/// [`HardwareInterface::from_parts`]. Unlike [`create_protocol_with_firmware`],
+/// this isn't tied to [`crate::hardware::DefaultGpioController`]/
+/// [`crate::hardware::DefaultSpiController`], so it works on any board
+/// exposing `embedded-hal`-compatible pin/bus handles.
+pub fn create_protocol_with_hardware<G: GpioController, S: SpiController, F: DisplayFirmware>(
+    hardware: HardwareInterface<G, S>,
+    firmware: F,
+) -> GenericEinkProtocol<G, S, F> {
+    GenericEinkProtocol::new(hardware, firmware)
+}
+
+/// Create a boxed protocol driving custom, caller-supplied firmware.
+///
+/// This is the runtime-dispatch counterpart of [`create_protocol_with_firmware`]:
+/// the concrete `GenericEinkProtocol<_, _, F>` is erased behind
+/// [`BoxedProtocol`] so it can be stored in the global display state
+/// alongside the built-in panel variants.
+///
+/// # Errors
+///
+/// Returns `DisplayError` if hardware initialization fails
+pub fn create_boxed_protocol_with_firmware<F: DisplayFirmware + 'static>(
+    firmware: F,
+) -> Result<BoxedProtocol, DisplayError> {
+    let protocol = create_protocol_with_firmware(firmware)?;
+    Ok(Box::new(protocol))
+}
+
@@ -0,0 +1,89 @@
+//! Zip-archive asset loading, so an application can ship all of its
+//! screens as a single bundle and pick frames by entry name at runtime
+//! instead of extracting loose files to disk first.
+
+use std::{fs::File, io::Read};
+
+use crate::{
+    display,
+    error::DisplayError,
+    image_processing::{DitherMode, ScaleMode, Transform},
+    protocol::DisplayMode,
+};
+
+/// Read the named `entry` from the zip archive at `archive_path` into
+/// memory.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Io` if the archive cannot be opened or is not a
+/// valid zip file, or if `entry` does not exist within it.
+fn read_entry(archive_path: &str, entry: &str) -> Result<Vec<u8>, DisplayError> {
+    let file = File::open(archive_path).map_err(DisplayError::Io)?;
+
+    let mut zip = zip::ZipArchive::new(file).map_err(|e| {
+        DisplayError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Failed to open archive '{archive_path}': {e}"),
+        ))
+    })?;
+
+    let mut zip_file = zip.by_name(entry).map_err(|e| {
+        DisplayError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("Entry '{entry}' not found in archive '{archive_path}': {e}"),
+        ))
+    })?;
+
+    let mut data = Vec::with_capacity(usize::try_from(zip_file.size()).unwrap_or(0));
+    zip_file
+        .read_to_end(&mut data)
+        .map_err(DisplayError::Io)?;
+
+    Ok(data)
+}
+
+/// Display `entry` from the zip archive at `archive`, decoded through the
+/// same format-detection path as [`display::display_image_file`] — the
+/// image must exactly match the panel's dimensions.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Io` if the archive or entry can't be read,
+/// `DisplayError::Png` if the decoded image doesn't match the panel size,
+/// or whatever error the display operation itself produces.
+pub fn display_image_from_archive(
+    archive: &str,
+    entry: &str,
+    mode: DisplayMode,
+) -> Result<(), DisplayError> {
+    let data = read_entry(archive, entry)?;
+    display::display_image_bytes(&data, mode)
+}
+
+/// Display `entry` from the zip archive at `archive`, run through the
+/// existing auto scale/dither pipeline — the in-archive analogue of
+/// [`display::display_image_auto`].
+///
+/// # Errors
+///
+/// Returns `DisplayError::Io` if the archive or entry can't be read, or
+/// whatever error image processing or the display operation produces.
+pub fn display_image_from_archive_auto(
+    archive: &str,
+    entry: &str,
+    mode: DisplayMode,
+    scale_mode: ScaleMode,
+    dither_mode: DitherMode,
+    transform: Option<Transform>,
+) -> Result<(), DisplayError> {
+    let data = read_entry(archive, entry)?;
+    display::display_image_auto_bytes(
+        &data,
+        mode,
+        scale_mode,
+        crate::image_processing::ResizeQuality::Lanczos3,
+        dither_mode,
+        transform,
+    )
+}
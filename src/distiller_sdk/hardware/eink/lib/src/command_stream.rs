@@ -0,0 +1,159 @@
+//! Binary command-stream parser and executor, for batching several
+//! display operations (clear, full refresh, partial-region writes, sleep)
+//! into a single call instead of one FFI round-trip per operation.
+//!
+//! Wire format:
+//!
+//! ```text
+//! header: magic:u32 | version:u8 | record_count:u16            (7 bytes)
+//! record: type:u8 | body
+//!   type 0 (Clear):         no body
+//!   type 1 (FullFrame):     byte_len:u32 | bytes[byte_len]
+//!   type 2 (PartialRegion): x:u16 | y:u16 | w:u16 | h:u16 | byte_len:u32 | bytes[byte_len]
+//!   type 3 (Sleep):         no body
+//! ```
+//!
+//! All integers are little-endian. The whole stream is parsed and
+//! validated against the configured firmware spec before any record is
+//! executed, so a record that fails validation can never leave an
+//! earlier record's SPI write partially applied.
+
+use crate::{config, display, error::DisplayError, protocol::DisplayMode};
+
+/// Magic number identifying a valid command stream (`"DCMD"`, little-endian).
+pub const STREAM_MAGIC: u32 = u32::from_le_bytes(*b"DCMD");
+
+/// Current stream format version.
+pub const STREAM_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 7;
+
+enum Record<'a> {
+    Clear,
+    FullFrame(&'a [u8]),
+    PartialRegion { x: u16, y: u16, w: u16, h: u16, data: &'a [u8] },
+    Sleep,
+}
+
+fn invalid_data(len: usize) -> DisplayError {
+    DisplayError::InvalidDataSize { expected: 0, actual: len }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|s| u16::from_le_bytes(s.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|s| u32::from_le_bytes(s.try_into().unwrap()))
+}
+
+/// Parse and validate every record in `data` against `array_size` (the
+/// configured firmware's full-frame byte length) and `(width, height)`
+/// (its pixel dimensions), without touching hardware.
+fn parse_stream(
+    data: &[u8],
+    array_size: usize,
+    width: u32,
+    height: u32,
+) -> Result<Vec<Record<'_>>, DisplayError> {
+    if data.len() < HEADER_LEN {
+        return Err(invalid_data(data.len()));
+    }
+
+    let magic = read_u32(data, 0).ok_or_else(|| invalid_data(data.len()))?;
+    let version = data[4];
+    let record_count = read_u16(data, 5).ok_or_else(|| invalid_data(data.len()))?;
+
+    if magic != STREAM_MAGIC || version != STREAM_VERSION {
+        return Err(invalid_data(data.len()));
+    }
+
+    let mut records = Vec::with_capacity(record_count as usize);
+    let mut offset = HEADER_LEN;
+
+    for _ in 0..record_count {
+        let &record_type = data.get(offset).ok_or_else(|| invalid_data(data.len()))?;
+        offset += 1;
+
+        match record_type {
+            0 => records.push(Record::Clear),
+            3 => records.push(Record::Sleep),
+            1 => {
+                let byte_len = read_u32(data, offset).ok_or_else(|| invalid_data(data.len()))?;
+                offset += 4;
+                let end = offset
+                    .checked_add(byte_len as usize)
+                    .ok_or_else(|| invalid_data(data.len()))?;
+                let bytes = data.get(offset..end).ok_or_else(|| invalid_data(data.len()))?;
+                if bytes.len() != array_size {
+                    return Err(DisplayError::InvalidDataSize {
+                        expected: array_size,
+                        actual: bytes.len(),
+                    });
+                }
+                records.push(Record::FullFrame(bytes));
+                offset = end;
+            },
+            2 => {
+                let x = read_u16(data, offset).ok_or_else(|| invalid_data(data.len()))?;
+                let y = read_u16(data, offset + 2).ok_or_else(|| invalid_data(data.len()))?;
+                let w = read_u16(data, offset + 4).ok_or_else(|| invalid_data(data.len()))?;
+                let h = read_u16(data, offset + 6).ok_or_else(|| invalid_data(data.len()))?;
+                offset += 8;
+                let byte_len = read_u32(data, offset).ok_or_else(|| invalid_data(data.len()))?;
+                offset += 4;
+                let end = offset
+                    .checked_add(byte_len as usize)
+                    .ok_or_else(|| invalid_data(data.len()))?;
+                let bytes = data.get(offset..end).ok_or_else(|| invalid_data(data.len()))?;
+
+                if w == 0 || h == 0 || x % 8 != 0 || w % 8 != 0 || u32::from(x) + u32::from(w) > width || u32::from(y) + u32::from(h) > height {
+                    return Err(invalid_data(data.len()));
+                }
+                let expected_len = (w as usize).div_ceil(8) * h as usize;
+                if bytes.len() != expected_len {
+                    return Err(DisplayError::InvalidDataSize {
+                        expected: expected_len,
+                        actual: bytes.len(),
+                    });
+                }
+
+                records.push(Record::PartialRegion { x, y, w, h, data: bytes });
+                offset = end;
+            },
+            _ => return Err(invalid_data(data.len())),
+        }
+    }
+
+    Ok(records)
+}
+
+/// Parse `data` as a binary command stream (see the module docs for the
+/// wire format) and execute every record against the global display, in
+/// order.
+///
+/// # Errors
+///
+/// Returns `DisplayError::InvalidDataSize` if the stream is malformed,
+/// truncated, or any record falls outside the configured panel's bounds
+/// — validated before any record is executed — or whatever error the
+/// first failing display operation produces.
+pub fn execute_stream(data: &[u8]) -> Result<(), DisplayError> {
+    let spec = config::get_default_spec()?;
+    let records = parse_stream(data, spec.array_size(), spec.width, spec.height)?;
+
+    for record in records {
+        match record {
+            Record::Clear => display::display_clear()?,
+            Record::Sleep => display::display_sleep()?,
+            Record::FullFrame(bytes) => display::display_image_raw(bytes, DisplayMode::Full)?,
+            Record::PartialRegion { x, y, w, h, data } => {
+                display::display_image_region(data, u32::from(x), u32::from(y), u32::from(w), u32::from(h))?;
+            },
+        }
+    }
+
+    Ok(())
+}
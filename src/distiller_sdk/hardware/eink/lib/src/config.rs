@@ -10,6 +10,7 @@ use std::{
 use crate::{
     error::DisplayError,
     firmware::{DisplayFirmware, DisplaySpec, EPD128x250Firmware, EPD240x416Firmware},
+    firmware_descriptor,
 };
 
 /// Supported firmware types
@@ -61,6 +62,17 @@ impl FirmwareType {
             FirmwareType::EPD240x416 => "EPD240x416",
         }
     }
+
+    /// Recommended SPI bus tuning for this firmware's panel, used to fill
+    /// in whichever of `HardwareConfig`'s `spi_speed_hz`/`spi_mode`/
+    /// `spi_max_chunk`/`spi_chunk_delay_us` the user hasn't set explicitly.
+    #[must_use]
+    pub fn recommended_spi_config(&self) -> crate::hardware::SpiTuning {
+        match self {
+            FirmwareType::EPD128x250 => EPD128x250Firmware::recommended_spi_config(),
+            FirmwareType::EPD240x416 => EPD240x416Firmware::recommended_spi_config(),
+        }
+    }
 }
 
 impl std::fmt::Display for FirmwareType {
@@ -77,6 +89,112 @@ impl FromStr for FirmwareType {
     }
 }
 
+/// Either a compiled-in [`FirmwareType`] or the name of a
+/// [`crate::firmware_descriptor::FirmwareDescriptor`] registered at
+/// runtime, so [`set_default_firmware_from_str`] can resolve a panel that
+/// was never compiled into this binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FirmwareSelection {
+    /// A firmware type built into this binary.
+    Builtin(FirmwareType),
+    /// The name a firmware descriptor was registered under via
+    /// [`crate::firmware_descriptor::register_descriptor`].
+    Descriptor(String),
+}
+
+impl FirmwareSelection {
+    /// Create a firmware instance for this selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if this is a `Descriptor` selection
+    /// and no descriptor is currently registered under that name.
+    pub fn create_firmware(&self) -> Result<Box<dyn DisplayFirmware>, DisplayError> {
+        match self {
+            Self::Builtin(firmware_type) => Ok(firmware_type.create_firmware()),
+            Self::Descriptor(name) => {
+                firmware_descriptor::create_firmware_from_registry(name).ok_or_else(|| {
+                    DisplayError::Config(format!("No firmware descriptor registered as '{name}'"))
+                })
+            },
+        }
+    }
+
+    /// Parse a selection from a string: a built-in [`FirmwareType`] name
+    /// takes precedence, falling back to a registered descriptor name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if `s` matches neither a built-in
+    /// firmware type nor a registered descriptor.
+    pub fn parse(s: &str) -> Result<Self, DisplayError> {
+        match FirmwareType::parse(s) {
+            Ok(firmware_type) => Ok(Self::Builtin(firmware_type)),
+            Err(builtin_err) => {
+                if firmware_descriptor::is_registered(s) {
+                    Ok(Self::Descriptor(s.to_string()))
+                } else {
+                    Err(builtin_err)
+                }
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for FirmwareSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Builtin(firmware_type) => write!(f, "{firmware_type}"),
+            Self::Descriptor(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Which physical transport drives the panel's GPIO/SPI signals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HardwareBackend {
+    /// The SBC's own `spidev`/`gpiod` interfaces (the default).
+    #[default]
+    Native,
+    /// A Silicon Labs CP2130 USB-to-SPI bridge, so a display can be driven
+    /// from a host PC without any SBC-side wiring. Requires the `usb-spi`
+    /// feature.
+    UsbCp2130,
+}
+
+impl HardwareBackend {
+    /// Parse a hardware backend from its config string (`"native"` or
+    /// `"usb-cp2130"`, case-insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if `s` is neither.
+    pub fn parse(s: &str) -> Result<Self, DisplayError> {
+        match s.to_lowercase().as_str() {
+            "native" => Ok(Self::Native),
+            "usb-cp2130" | "usb_cp2130" | "cp2130" => Ok(Self::UsbCp2130),
+            _ => Err(DisplayError::Config(format!(
+                "Unknown hardware backend: {s}. Supported values: native, usb-cp2130"
+            ))),
+        }
+    }
+
+    /// Get string representation.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Native => "native",
+            Self::UsbCp2130 => "usb-cp2130",
+        }
+    }
+}
+
+impl std::fmt::Display for HardwareBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Hardware configuration for GPIO and SPI
 #[derive(Debug, Clone)]
 pub struct HardwareConfig {
@@ -92,6 +210,29 @@ pub struct HardwareConfig {
     pub rst_pin: u32,
     /// Busy GPIO pin offset
     pub busy_pin: u32,
+    /// Which transport carries GPIO/SPI to the panel
+    pub backend: HardwareBackend,
+    /// How long `check_status` waits for BUSY to deassert before giving up
+    /// with `DisplayError::Timeout`
+    pub busy_timeout_ms: u64,
+    /// SPI clock speed override, in Hz. Defaults to the panel firmware's
+    /// recommendation when unset.
+    pub spi_speed_hz: Option<u32>,
+    /// SPI mode override (0-3). Defaults to the panel firmware's
+    /// recommendation when unset.
+    pub spi_mode: Option<u8>,
+    /// Largest single SPI transfer override, in bytes, before it's split
+    /// into chunks. Defaults to the panel firmware's recommendation when
+    /// unset.
+    pub spi_max_chunk: Option<usize>,
+    /// Delay between chunks of a split SPI transfer override, in
+    /// microseconds. Defaults to the panel firmware's recommendation when
+    /// unset.
+    pub spi_chunk_delay_us: Option<u64>,
+    /// Optional chip-select GPIO pin, for panels that need CS driven by
+    /// this crate rather than left to the bus controller. `None` when the
+    /// bus has exactly one device and the controller's own CS suffices.
+    pub cs_pin: Option<u32>,
 }
 
 impl Default for HardwareConfig {
@@ -104,6 +245,13 @@ impl Default for HardwareConfig {
             dc_pin: 7,
             rst_pin: 13,
             busy_pin: 9,
+            backend: HardwareBackend::Native,
+            busy_timeout_ms: 10_000,
+            spi_speed_hz: None,
+            spi_mode: None,
+            spi_max_chunk: None,
+            spi_chunk_delay_us: None,
+            cs_pin: None,
         }
     }
 }
@@ -111,17 +259,21 @@ impl Default for HardwareConfig {
 /// Global configuration for the display system
 #[derive(Debug, Clone)]
 pub struct DisplayConfig {
-    /// Default firmware type for the display
-    pub default_firmware: FirmwareType,
+    /// Default firmware selection for the display
+    pub default_firmware: FirmwareSelection,
     /// Hardware configuration
     pub hardware: HardwareConfig,
+    /// Directory to search for runtime-loadable waveform files (see
+    /// [`crate::waveform`]), e.g. `/opt/distiller-sdk/waveforms/`
+    pub waveform_dir: Option<String>,
 }
 
 impl Default for DisplayConfig {
     fn default() -> Self {
         Self {
-            default_firmware: FirmwareType::EPD128x250, // Keep existing default
+            default_firmware: FirmwareSelection::Builtin(FirmwareType::EPD128x250), // Keep existing default
             hardware: HardwareConfig::default(),
+            waveform_dir: None,
         }
     }
 }
@@ -146,52 +298,81 @@ pub fn set_default_firmware(firmware_type: FirmwareType) -> Result<(), DisplayEr
         .map_err(|e| DisplayError::Config(format!("Failed to acquire config lock: {e}")))?;
 
     log::info!("Setting default firmware to: {firmware_type}");
-    config_guard.default_firmware = firmware_type;
+    config_guard.default_firmware = FirmwareSelection::Builtin(firmware_type);
     Ok(())
 }
 
-/// Set the default firmware type from string
+/// Set the default firmware from a string naming either a built-in
+/// [`FirmwareType`] or a descriptor registered via
+/// [`crate::firmware_descriptor::register_descriptor`].
 ///
 /// # Errors
 ///
-/// Returns `DisplayError::Config` if the firmware type is not recognized or the
-/// lock cannot be acquired
+/// Returns `DisplayError::Config` if `firmware_str` matches neither a
+/// built-in firmware type nor a registered descriptor, or the lock
+/// cannot be acquired
 pub fn set_default_firmware_from_str(firmware_str: &str) -> Result<(), DisplayError> {
-    let firmware_type = FirmwareType::parse(firmware_str)?;
-    set_default_firmware(firmware_type)
+    let selection = FirmwareSelection::parse(firmware_str)?;
+    let config = init_config();
+    let mut config_guard = config
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire config lock: {e}")))?;
+
+    log::info!("Setting default firmware to: {selection}");
+    config_guard.default_firmware = selection;
+    Ok(())
 }
 
-/// Get the current default firmware type
+/// Get the current default firmware type.
 ///
 /// # Errors
 ///
-/// Returns `DisplayError::Config` if the configuration lock cannot be acquired
+/// Returns `DisplayError::Config` if the configuration lock cannot be
+/// acquired, or if the current default firmware is a runtime-loaded
+/// descriptor rather than a built-in [`FirmwareType`] (use
+/// [`get_default_firmware_selection`] to handle both cases).
 pub fn get_default_firmware() -> Result<FirmwareType, DisplayError> {
+    match get_default_firmware_selection()? {
+        FirmwareSelection::Builtin(firmware_type) => Ok(firmware_type),
+        FirmwareSelection::Descriptor(name) => Err(DisplayError::Config(format!(
+            "default firmware '{name}' is a runtime-loaded descriptor, not a built-in FirmwareType"
+        ))),
+    }
+}
+
+/// Get the current default firmware selection (built-in or descriptor).
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if the configuration lock cannot be acquired
+pub fn get_default_firmware_selection() -> Result<FirmwareSelection, DisplayError> {
     let config = init_config();
     let config_guard = config
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire config lock: {e}")))?;
-    Ok(config_guard.default_firmware)
+    Ok(config_guard.default_firmware.clone())
 }
 
-/// Create a firmware instance using the default firmware type
+/// Create a firmware instance using the default firmware selection.
 ///
 /// # Errors
 ///
-/// Returns `DisplayError::Config` if the configuration lock cannot be acquired
+/// Returns `DisplayError::Config` if the configuration lock cannot be
+/// acquired, or if the default selection is a descriptor that is no
+/// longer registered
 pub fn create_default_firmware() -> Result<Box<dyn DisplayFirmware>, DisplayError> {
-    let firmware_type = get_default_firmware()?;
-    Ok(firmware_type.create_firmware())
+    get_default_firmware_selection()?.create_firmware()
 }
 
 /// Get the display spec for the default firmware
 ///
 /// # Errors
 ///
-/// Returns `DisplayError::Config` if the configuration lock cannot be acquired
+/// Returns `DisplayError::Config` if the configuration lock cannot be
+/// acquired, or if the default selection is a descriptor that is no
+/// longer registered
 pub fn get_default_spec() -> Result<DisplaySpec, DisplayError> {
-    let firmware_type = get_default_firmware()?;
-    Ok(firmware_type.get_spec())
+    Ok(create_default_firmware()?.get_spec().clone())
 }
 
 /// Configuration from environment variables
@@ -208,13 +389,125 @@ pub fn init_from_env() -> Result<(), DisplayError> {
     Ok(())
 }
 
-/// Parse INI-style configuration file
+/// Strip one layer of matching `"..."` or `'...'` quotes from `value`, so
+/// config values can contain leading/trailing whitespace or look like
+/// comments without extra escaping.
+#[must_use]
+fn strip_quotes(value: &str) -> &str {
+    let bytes = value.as_bytes();
+    if bytes.len() >= 2 {
+        let first = bytes[0];
+        let last = bytes[bytes.len() - 1];
+        if (first == b'"' && last == b'"') || (first == b'\'' && last == b'\'') {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+/// Apply a single parsed `key = value` pair from `section` to `config`,
+/// logging which `source` supplied it.
 ///
 /// # Errors
 ///
-/// Returns `DisplayError::Config` if parsing fails
-pub fn parse_ini_config(content: &str) -> Result<DisplayConfig, DisplayError> {
-    let mut config = DisplayConfig::default();
+/// Returns `DisplayError::Config` if a recognized key has a malformed value.
+fn apply_kv(
+    config: &mut DisplayConfig,
+    section: &str,
+    key: &str,
+    value: &str,
+    source: &str,
+) -> Result<(), DisplayError> {
+    let value = strip_quotes(value);
+
+    match section {
+        "display" => match key {
+            "firmware" => {
+                config.default_firmware = FirmwareSelection::parse(value)?;
+                log::info!("Setting firmware to '{value}' (from {source})");
+            },
+            "waveform_dir" => {
+                config.waveform_dir = Some(value.to_string());
+                log::info!("Setting waveform directory to '{value}' (from {source})");
+            },
+            _ => {},
+        },
+        "hardware" => {
+            match key {
+                "platform" => config.hardware.platform = value.to_string(),
+                "spi_device" => config.hardware.spi_device = value.to_string(),
+                "gpio_chip" => config.hardware.gpio_chip = value.to_string(),
+                "backend" => config.hardware.backend = HardwareBackend::parse(value)?,
+                "busy_timeout_ms" => {
+                    config.hardware.busy_timeout_ms = value
+                        .parse()
+                        .map_err(|_| DisplayError::Config(format!("Invalid busy_timeout_ms: {value}")))?;
+                },
+                "spi_speed_hz" => {
+                    config.hardware.spi_speed_hz = Some(
+                        value
+                            .parse()
+                            .map_err(|_| DisplayError::Config(format!("Invalid spi_speed_hz: {value}")))?,
+                    );
+                },
+                "spi_mode" => {
+                    let mode: u8 = value
+                        .parse()
+                        .map_err(|_| DisplayError::Config(format!("Invalid spi_mode: {value}")))?;
+                    if mode > 3 {
+                        return Err(DisplayError::Config(format!(
+                            "Invalid spi_mode: {value}. Supported values: 0, 1, 2, 3"
+                        )));
+                    }
+                    config.hardware.spi_mode = Some(mode);
+                },
+                "spi_max_chunk" => {
+                    config.hardware.spi_max_chunk = Some(
+                        value
+                            .parse()
+                            .map_err(|_| DisplayError::Config(format!("Invalid spi_max_chunk: {value}")))?,
+                    );
+                },
+                "spi_chunk_delay_us" => {
+                    config.hardware.spi_chunk_delay_us = Some(value.parse().map_err(|_| {
+                        DisplayError::Config(format!("Invalid spi_chunk_delay_us: {value}"))
+                    })?);
+                },
+                _ => return Ok(()),
+            }
+            log::info!("Setting hardware.{key} to '{value}' (from {source})");
+        },
+        "gpio_pins" => {
+            let pin = value
+                .parse()
+                .map_err(|_| DisplayError::Config(format!("Invalid {key}: {value}")))?;
+            match key {
+                "dc_pin" => config.hardware.dc_pin = pin,
+                "rst_pin" => config.hardware.rst_pin = pin,
+                "busy_pin" => config.hardware.busy_pin = pin,
+                "cs_pin" => config.hardware.cs_pin = Some(pin),
+                _ => return Ok(()),
+            }
+            log::info!("Setting gpio_pins.{key} to '{value}' (from {source})");
+        },
+        _ => {},
+    }
+
+    Ok(())
+}
+
+/// Apply every `key = value` line in `content` (INI-style, `#` comments,
+/// `[section]` headers, optionally quoted values) to `config`, logging
+/// `source` as the origin of each value that gets set.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if any recognized key has a malformed value.
+fn apply_ini_content(
+    config: &mut DisplayConfig,
+    content: &str,
+    source: &str,
+) -> Result<(), DisplayError> {
     let mut current_section = "";
 
     for line in content.lines() {
@@ -235,42 +528,143 @@ pub fn parse_ini_config(content: &str) -> Result<DisplayConfig, DisplayError> {
         if let Some(eq_pos) = line.find('=') {
             let key = line[..eq_pos].trim();
             let value = line[eq_pos + 1..].trim();
-
-            match current_section {
-                "display" => {
-                    if key == "firmware" {
-                        config.default_firmware = FirmwareType::parse(value)?;
-                    }
-                },
-                "hardware" => match key {
-                    "platform" => config.hardware.platform = value.to_string(),
-                    "spi_device" => config.hardware.spi_device = value.to_string(),
-                    "gpio_chip" => config.hardware.gpio_chip = value.to_string(),
-                    _ => {},
-                },
-                "gpio_pins" => match key {
-                    "dc_pin" => {
-                        config.hardware.dc_pin = value
-                            .parse()
-                            .map_err(|_| DisplayError::Config(format!("Invalid dc_pin: {value}")))?
-                    },
-                    "rst_pin" => {
-                        config.hardware.rst_pin = value.parse().map_err(|_| {
-                            DisplayError::Config(format!("Invalid rst_pin: {value}"))
-                        })?
-                    },
-                    "busy_pin" => {
-                        config.hardware.busy_pin = value.parse().map_err(|_| {
-                            DisplayError::Config(format!("Invalid busy_pin: {value}"))
-                        })?
-                    },
-                    _ => {},
-                },
-                _ => {},
-            }
+            apply_kv(config, current_section, key, value, source)?;
         }
     }
 
+    Ok(())
+}
+
+/// Parse INI-style configuration file
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if parsing fails
+pub fn parse_ini_config(content: &str) -> Result<DisplayConfig, DisplayError> {
+    let mut config = DisplayConfig::default();
+    apply_ini_content(&mut config, content, "main config file")?;
+    Ok(config)
+}
+
+/// The `eink.conf.d` drop-in directory alongside `main_config_path`,
+/// applied after the main config file and in lexical filename order —
+/// mirrors the `loader_conf_files`/`loader_conf_dirs` override model.
+fn system_confd_dir(main_config_path: &str) -> std::path::PathBuf {
+    Path::new(main_config_path)
+        .parent()
+        .unwrap_or_else(|| Path::new("/"))
+        .join("eink.conf.d")
+}
+
+/// Per-user drop-in directory (`~/.distiller/eink.conf.d`), applied after
+/// the system-wide one, if `$HOME` resolves.
+fn user_confd_dir() -> Option<std::path::PathBuf> {
+    std::env::var_os("HOME").map(|home| Path::new(&home).join(".distiller/eink.conf.d"))
+}
+
+/// Apply every `*.conf` fragment in `dir`, in lexical order. A missing
+/// directory is not an error — it simply contributes nothing.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if a fragment exists but fails to parse.
+fn apply_confd_dir(config: &mut DisplayConfig, dir: &Path) -> Result<(), DisplayError> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(());
+    };
+
+    let mut fragment_paths: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "conf"))
+        .collect();
+    fragment_paths.sort();
+
+    for path in fragment_paths {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| DisplayError::Config(format!("Cannot read {}: {e}", path.display())))?;
+        apply_ini_content(config, &content, &path.display().to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Apply environment variable overrides, the last and highest-precedence
+/// layer.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if a set environment variable has a
+/// malformed value.
+fn apply_env_overrides(config: &mut DisplayConfig) -> Result<(), DisplayError> {
+    if let Ok(value) = std::env::var("DISTILLER_EINK_FIRMWARE") {
+        apply_kv(config, "display", "firmware", &value, "$DISTILLER_EINK_FIRMWARE")?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_HARDWARE_BACKEND") {
+        apply_kv(config, "hardware", "backend", &value, "$DISTILLER_EINK_HARDWARE_BACKEND")?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_BUSY_TIMEOUT_MS") {
+        apply_kv(
+            config,
+            "hardware",
+            "busy_timeout_ms",
+            &value,
+            "$DISTILLER_EINK_BUSY_TIMEOUT_MS",
+        )?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_SPI_SPEED_HZ") {
+        apply_kv(config, "hardware", "spi_speed_hz", &value, "$DISTILLER_EINK_SPI_SPEED_HZ")?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_SPI_MODE") {
+        apply_kv(config, "hardware", "spi_mode", &value, "$DISTILLER_EINK_SPI_MODE")?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_SPI_MAX_CHUNK") {
+        apply_kv(config, "hardware", "spi_max_chunk", &value, "$DISTILLER_EINK_SPI_MAX_CHUNK")?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_SPI_CHUNK_DELAY_US") {
+        apply_kv(
+            config,
+            "hardware",
+            "spi_chunk_delay_us",
+            &value,
+            "$DISTILLER_EINK_SPI_CHUNK_DELAY_US",
+        )?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_CS_PIN") {
+        apply_kv(config, "gpio_pins", "cs_pin", &value, "$DISTILLER_EINK_CS_PIN")?;
+    }
+    if let Ok(value) = std::env::var("DISTILLER_EINK_WAVEFORM_DIR") {
+        apply_kv(config, "display", "waveform_dir", &value, "$DISTILLER_EINK_WAVEFORM_DIR")?;
+    }
+    Ok(())
+}
+
+/// Build a [`DisplayConfig`] by layering every configuration source in
+/// order of increasing precedence: built-in defaults, then
+/// `main_config_path` (if it exists), then its sibling `eink.conf.d`
+/// fragments in lexical order, then `~/.distiller/eink.conf.d` fragments,
+/// then environment variables. Each value logs which source set it;
+/// `apply_ini_content`/`apply_kv` only touch keys actually present in a
+/// given source, so later layers override individual keys rather than
+/// replacing the whole config.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if any source contains a malformed value.
+pub fn load_layered_config(main_config_path: &str) -> Result<DisplayConfig, DisplayError> {
+    let mut config = DisplayConfig::default();
+
+    if let Ok(content) = fs::read_to_string(main_config_path) {
+        apply_ini_content(&mut config, &content, main_config_path)?;
+    }
+
+    apply_confd_dir(&mut config, &system_confd_dir(main_config_path))?;
+    if let Some(dir) = user_confd_dir() {
+        apply_confd_dir(&mut config, &dir)?;
+    }
+
+    apply_env_overrides(&mut config)?;
+
     Ok(config)
 }
 
@@ -323,10 +717,7 @@ pub fn initialize_config() -> Result<(), DisplayError> {
         )));
     }
 
-    let content = fs::read_to_string(config_path)
-        .map_err(|e| DisplayError::Config(format!("Cannot read config: {e}")))?;
-
-    let config = parse_ini_config(&content)?;
+    let config = load_layered_config(config_path)?;
 
     // Validate hardware paths exist
     if !Path::new(&config.hardware.spi_device).exists() {
@@ -371,6 +762,30 @@ pub fn get_hardware_config() -> Result<HardwareConfig, DisplayError> {
     Ok(guard.hardware.clone())
 }
 
+/// Get the configured hardware transport backend (native vs. USB bridge).
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if the configuration lock cannot be acquired
+pub fn get_hardware_backend() -> Result<HardwareBackend, DisplayError> {
+    Ok(get_hardware_config()?.backend)
+}
+
+/// Get the configured waveform directory, if one was set via the
+/// `waveform_dir` key in the `[display]` section or
+/// `DISTILLER_EINK_WAVEFORM_DIR`.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if the configuration lock cannot be acquired
+pub fn get_waveform_dir() -> Result<Option<String>, DisplayError> {
+    let config = init_config();
+    let guard = config
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Config lock failed: {e}")))?;
+    Ok(guard.waveform_dir.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,7 +820,39 @@ mod tests {
     #[test]
     fn test_config_default() {
         let config = DisplayConfig::default();
-        assert_eq!(config.default_firmware, FirmwareType::EPD128x250);
+        assert_eq!(
+            config.default_firmware,
+            FirmwareSelection::Builtin(FirmwareType::EPD128x250)
+        );
+    }
+
+    #[test]
+    fn test_set_firmware_from_descriptor_registry() {
+        // A valid descriptor with a tiny init sequence, registered under a
+        // name that isn't a built-in FirmwareType.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"DEFW");
+        bytes.push(1); // format version
+        bytes.extend_from_slice(&64u16.to_le_bytes()); // width
+        bytes.extend_from_slice(&64u16.to_le_bytes()); // height
+        bytes.push(0); // empty name
+        bytes.push(0xFF); // terminator
+        bytes.push(0xFF);
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        let checksum = bytes.iter().fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        crate::firmware_descriptor::register_descriptor("config-test-panel", &bytes).unwrap();
+        set_default_firmware_from_str("config-test-panel").unwrap();
+        assert_eq!(
+            get_default_firmware_selection().unwrap(),
+            FirmwareSelection::Descriptor("config-test-panel".to_string())
+        );
+        assert!(get_default_firmware().is_err());
+        assert_eq!(get_default_spec().unwrap().width, 64);
+
+        // Reset to default so other tests in this module aren't affected.
+        set_default_firmware(FirmwareType::EPD128x250).unwrap();
     }
 
     #[test]
@@ -417,4 +864,90 @@ mod tests {
         set_default_firmware(FirmwareType::EPD128x250).unwrap();
         assert_eq!(get_default_firmware().unwrap(), FirmwareType::EPD128x250);
     }
+
+    #[test]
+    fn test_hardware_backend_parsing() {
+        assert_eq!(HardwareBackend::parse("native").unwrap(), HardwareBackend::Native);
+        assert_eq!(
+            HardwareBackend::parse("usb-cp2130").unwrap(),
+            HardwareBackend::UsbCp2130
+        );
+        assert_eq!(
+            HardwareBackend::parse("CP2130").unwrap(),
+            HardwareBackend::UsbCp2130
+        );
+        assert!(HardwareBackend::parse("invalid").is_err());
+        assert_eq!(HardwareBackend::default(), HardwareBackend::Native);
+    }
+
+    #[test]
+    fn test_strip_quotes() {
+        assert_eq!(strip_quotes("\"hello world\""), "hello world");
+        assert_eq!(strip_quotes("'hello'"), "hello");
+        assert_eq!(strip_quotes("bare"), "bare");
+        assert_eq!(strip_quotes("\""), "\"");
+    }
+
+    #[test]
+    fn test_busy_timeout_ms_parsing() {
+        let mut config = DisplayConfig::default();
+        assert_eq!(config.hardware.busy_timeout_ms, 10_000);
+
+        apply_ini_content(&mut config, "[hardware]\nbusy_timeout_ms = 5000\n", "test fragment")
+            .unwrap();
+        assert_eq!(config.hardware.busy_timeout_ms, 5000);
+
+        let err = apply_ini_content(
+            &mut config,
+            "[hardware]\nbusy_timeout_ms = not-a-number\n",
+            "test fragment",
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_spi_tuning_parsing() {
+        let mut config = DisplayConfig::default();
+        assert_eq!(config.hardware.spi_speed_hz, None);
+
+        apply_ini_content(
+            &mut config,
+            "[hardware]\nspi_speed_hz = 20000000\nspi_mode = 2\nspi_max_chunk = 2048\nspi_chunk_delay_us = 50\n",
+            "test fragment",
+        )
+        .unwrap();
+        assert_eq!(config.hardware.spi_speed_hz, Some(20_000_000));
+        assert_eq!(config.hardware.spi_mode, Some(2));
+        assert_eq!(config.hardware.spi_max_chunk, Some(2048));
+        assert_eq!(config.hardware.spi_chunk_delay_us, Some(50));
+
+        assert!(apply_ini_content(&mut config, "[hardware]\nspi_mode = 7\n", "test fragment").is_err());
+    }
+
+    #[test]
+    fn test_cs_pin_parsing() {
+        let mut config = DisplayConfig::default();
+        assert_eq!(config.hardware.cs_pin, None);
+
+        apply_ini_content(&mut config, "[gpio_pins]\ncs_pin = 17\n", "test fragment").unwrap();
+        assert_eq!(config.hardware.cs_pin, Some(17));
+    }
+
+    #[test]
+    fn test_waveform_dir_parsing() {
+        let mut config = DisplayConfig::default();
+        assert_eq!(config.waveform_dir, None);
+
+        apply_ini_content(
+            &mut config,
+            "[display]\nwaveform_dir = /opt/distiller-sdk/waveforms\n",
+            "test fragment",
+        )
+        .unwrap();
+        assert_eq!(
+            config.waveform_dir,
+            Some("/opt/distiller-sdk/waveforms".to_string())
+        );
+    }
+
 }
@@ -0,0 +1,102 @@
+//! Structured per-operation diagnostics for [`crate::display::GenericDisplay`],
+//! mirroring how `firmware_class` logs every success and failure
+//! consistently against the device instead of leaving callers to scrape
+//! ad hoc log lines. Every wrapped operation emits one structured record
+//! (operation, firmware, byte count, busy-wait duration, elapsed time,
+//! outcome) and folds it into a cumulative [`DisplayStats`] queryable
+//! through [`crate::display::display_stats`] without needing a log parser.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::error::DisplayError;
+
+/// Busy-wait time accumulated by [`crate::protocol::EinkProtocol::check_status`]
+/// polls since the last [`take_busy_wait_ns`] call, so
+/// [`DisplayStats::record`] can attribute just the busy-wait portion of
+/// whichever operation is currently in flight.
+static BUSY_WAIT_NS: AtomicU64 = AtomicU64::new(0);
+
+/// Called by `check_status` implementations to add `duration` to the
+/// busy-wait time attributed to the operation currently in flight.
+pub fn record_busy_wait(duration: Duration) {
+    BUSY_WAIT_NS.fetch_add(u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX), Ordering::Relaxed);
+}
+
+/// Read and reset the accumulated busy-wait time, so a caller wrapping one
+/// operation gets just that operation's share of it.
+fn take_busy_wait_ns() -> u64 {
+    BUSY_WAIT_NS.swap(0, Ordering::Relaxed)
+}
+
+/// Cumulative, queryable panel diagnostics — see [`crate::display::display_stats`].
+#[derive(Debug, Clone, Default)]
+pub struct DisplayStats {
+    /// Total number of wrapped operations attempted (`init`,
+    /// `display_image_raw`, `display_region`, `clear`, `sleep`).
+    pub total_operations: u64,
+    /// Total number of wrapped operations that returned `Err`.
+    pub total_errors: u64,
+    /// The last error observed, formatted via `Display`, or `None` if no
+    /// operation has failed yet.
+    pub last_error: Option<String>,
+    /// Total busy-wait time spent polling the panel's BUSY line, across
+    /// every wrapped operation.
+    pub total_busy_wait_ms: u64,
+    total_refresh_ns: u64,
+    refresh_count: u64,
+}
+
+impl DisplayStats {
+    /// Mean wall-clock latency of `display_image_raw`/`display_region`
+    /// refreshes, or `None` if none have completed yet.
+    #[must_use]
+    pub fn average_refresh_latency_ms(&self) -> Option<f64> {
+        if self.refresh_count == 0 {
+            None
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            Some(self.total_refresh_ns as f64 / self.refresh_count as f64 / 1_000_000.0)
+        }
+    }
+
+    /// Fold one completed operation's outcome into the cumulative stats
+    /// and emit its structured log line.
+    pub(crate) fn record<T>(
+        &mut self,
+        op: &str,
+        firmware_name: &str,
+        byte_count: usize,
+        elapsed: Duration,
+        result: &Result<T, DisplayError>,
+    ) {
+        let busy_wait_ns = take_busy_wait_ns();
+        let busy_wait_ms = busy_wait_ns / 1_000_000;
+        let elapsed_ms = elapsed.as_millis();
+
+        self.total_operations += 1;
+        self.total_busy_wait_ms += busy_wait_ms;
+
+        match result {
+            Ok(_) => {
+                if matches!(op, "display_image_raw" | "display_region") {
+                    self.total_refresh_ns +=
+                        u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+                    self.refresh_count += 1;
+                }
+                log::info!(
+                    "op={op} firmware={firmware_name} bytes={byte_count} busy_wait_ms={busy_wait_ms} elapsed_ms={elapsed_ms} outcome=ok"
+                );
+            },
+            Err(e) => {
+                self.total_errors += 1;
+                self.last_error = Some(e.to_string());
+                log::error!(
+                    "op={op} firmware={firmware_name} bytes={byte_count} busy_wait_ms={busy_wait_ms} elapsed_ms={elapsed_ms} outcome=error error={e}"
+                );
+            },
+        }
+    }
+}
@@ -1,16 +1,20 @@
 //! Display module providing high-level e-ink display control.
 
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 use crate::{
     error::DisplayError,
     image,
     image_processing::Transform,
+    notify,
     protocol::{DisplayMode, EinkProtocol, create_default_protocol},
 };
 
 /// Display driver trait for different e-ink variants
-pub trait DisplayDriver {
+///
+/// Requires `Send + Sync` so `Box<dyn DisplayDriver>` can live behind the
+/// `GLOBAL_STATE` mutex.
+pub trait DisplayDriver: Send + Sync {
     /// Initialize the display hardware
     ///
     /// # Errors
@@ -46,6 +50,7 @@ pub trait DisplayDriver {
         filename: &str,
         mode: DisplayMode,
         scale_mode: crate::image_processing::ScaleMode,
+        resize_quality: crate::image_processing::ResizeQuality,
         dither_mode: crate::image_processing::DitherMode,
         transform: Option<Transform>,
     ) -> Result<(), DisplayError>;
@@ -69,62 +74,162 @@ pub trait DisplayDriver {
     fn cleanup(&mut self) -> Result<(), DisplayError>;
     /// Get the display specifications
     fn get_spec(&self) -> &crate::firmware::DisplaySpec;
+    /// Load a waveform file (see [`crate::waveform`]) and replay its command
+    /// sequence into the protocol right away. `mode` only appears in the log
+    /// line: the waveform is not bound to full or partial updates, and a
+    /// later update sequence that writes its own LUT replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::NotInitialized` if the display has not been
+    /// initialized, or `DisplayError` if the file cannot be read, fails to
+    /// parse, or any command/data write fails.
+    fn load_waveform(&mut self, path: &str, mode: DisplayMode) -> Result<(), DisplayError>;
+    /// Push a sub-rectangle of 1-bit data and issue a windowed partial
+    /// refresh of just that rectangle, instead of rewriting and redrawing
+    /// the whole panel — the same damage-tracking approach the tiny DRM
+    /// e-ink drivers use to push only changed areas.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::NotInitialized` if the display has not been
+    /// initialized, `DisplayError::Config` if the active display driver
+    /// isn't built on [`crate::protocol::ConfigurableProtocol`] or the
+    /// rectangle isn't byte-aligned on the X axis, or
+    /// `DisplayError::InvalidDataSize` if the rectangle falls outside the
+    /// panel bounds or `data`'s length doesn't match `(w, h)`.
+    fn display_region(
+        &mut self,
+        data: &[u8],
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+    ) -> Result<(), DisplayError>;
+    /// Flush a [`crate::framebuffer::Framebuffer`] drawn via
+    /// `embedded-graphics` to the panel, skipping the PNG round-trip.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if the framebuffer wasn't
+    /// sized for this display's spec, or any error `display_image_raw` can
+    /// return.
+    fn display_framebuffer(
+        &mut self,
+        framebuffer: &crate::framebuffer::Framebuffer,
+        mode: DisplayMode,
+    ) -> Result<(), DisplayError>;
+    /// Select the waveform LUT subsequent partial updates use, trading
+    /// image quality/ghosting for speed — pick
+    /// [`crate::firmware::RefreshSpeed::Fast`] for high-frequency UI
+    /// updates and [`crate::firmware::RefreshSpeed::Normal`] for clean
+    /// frames. Full refreshes are unaffected.
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed);
+    /// Downcast to the concrete driver, for protocol-specific operations
+    /// that aren't part of this trait's common surface.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+    /// Cumulative diagnostics collected from every wrapped operation (see
+    /// [`crate::diagnostics::DisplayStats`]).
+    fn stats(&self) -> crate::diagnostics::DisplayStats;
 }
 
 /// Generic display implementation
 pub struct GenericDisplay<P: EinkProtocol> {
     protocol: P,
     initialized: bool,
+    stats: crate::diagnostics::DisplayStats,
 }
 
 impl<P: EinkProtocol> GenericDisplay<P> {
     /// Create a new generic display with the given protocol
     #[must_use]
-    pub const fn new(protocol: P) -> Self {
+    pub fn new(protocol: P) -> Self {
         Self {
             protocol,
             initialized: false,
+            stats: crate::diagnostics::DisplayStats::default(),
         }
     }
 }
 
-impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
+impl<P: EinkProtocol + 'static> GenericDisplay<P> {
+    /// Run `op_fn`, then fold its outcome into `self.stats` and emit its
+    /// structured log line, tagged with `op`'s name and `byte_count`.
+    fn record<T>(
+        &mut self,
+        op: &str,
+        byte_count: usize,
+        op_fn: impl FnOnce(&mut Self) -> Result<T, DisplayError>,
+    ) -> Result<T, DisplayError> {
+        let start = std::time::Instant::now();
+        let result = op_fn(self);
+        let elapsed = start.elapsed();
+        let firmware_name = self.protocol.get_spec().name.clone();
+        self.stats.record(op, &firmware_name, byte_count, elapsed, &result);
+        result
+    }
+}
+
+impl<P: EinkProtocol + Send + Sync + 'static> DisplayDriver for GenericDisplay<P> {
     fn init(&mut self) -> Result<(), DisplayError> {
-        if self.initialized {
-            return Ok(());
-        }
+        self.record("init", 0, |this| {
+            if this.initialized {
+                return Ok(());
+            }
 
-        self.protocol.init_hardware()?;
-        self.initialized = true;
+            this.protocol.init_hardware()?;
+            this.initialized = true;
 
-        log::info!("Display SDK initialized successfully");
-        Ok(())
+            log::info!("Display SDK initialized successfully");
+            Ok(())
+        })
     }
 
-    fn display_image_raw(&mut self, data: &[u8], mode: DisplayMode) -> Result<(), DisplayError> {
-        if !self.initialized {
-            return Err(DisplayError::NotInitialized);
-        }
-
+    fn display_framebuffer(
+        &mut self,
+        framebuffer: &crate::framebuffer::Framebuffer,
+        mode: DisplayMode,
+    ) -> Result<(), DisplayError> {
         let spec = self.protocol.get_spec();
-        if data.len() != spec.array_size() {
+        let expected = spec.array_size();
+        let data = framebuffer.as_bytes();
+        if data.len() != expected {
             return Err(DisplayError::InvalidDataSize {
-                expected: spec.array_size(),
+                expected,
                 actual: data.len(),
             });
         }
 
-        match mode {
-            DisplayMode::Partial => self.protocol.init_partial()?,
-            DisplayMode::Full => {}, // Full mode uses default initialization
-        }
-
-        let write_ram_cmd = self.protocol.get_write_ram_command();
-        self.protocol.write_cmd(write_ram_cmd)?;
-        self.protocol.write_image_data(data)?;
-        self.protocol.update_display(mode)?;
+        self.display_image_raw(data, mode)
+    }
 
-        Ok(())
+    fn display_image_raw(&mut self, data: &[u8], mode: DisplayMode) -> Result<(), DisplayError> {
+        let byte_count = data.len();
+        self.record("display_image_raw", byte_count, |this| {
+            if !this.initialized {
+                return Err(DisplayError::NotInitialized);
+            }
+
+            let spec = this.protocol.get_spec();
+            if data.len() != spec.array_size() {
+                return Err(DisplayError::InvalidDataSize {
+                    expected: spec.array_size(),
+                    actual: data.len(),
+                });
+            }
+
+            match mode {
+                DisplayMode::Partial => this.protocol.init_partial()?,
+                DisplayMode::Full => {}, // Full mode uses default initialization
+            }
+
+            let write_ram_cmd = this.protocol.get_write_ram_command();
+            this.protocol.write_cmd(write_ram_cmd)?;
+            this.protocol.write_image_data(data)?;
+            this.protocol.update_display(mode)?;
+
+            Ok(())
+        })
     }
 
     fn display_image_png(&mut self, filename: &str, mode: DisplayMode) -> Result<(), DisplayError> {
@@ -148,6 +253,7 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
         filename: &str,
         mode: DisplayMode,
         scale_mode: crate::image_processing::ScaleMode,
+        resize_quality: crate::image_processing::ResizeQuality,
         dither_mode: crate::image_processing::DitherMode,
         transform: Option<Transform>,
     ) -> Result<(), DisplayError> {
@@ -158,7 +264,9 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
         let raw_data = processor.process_image(
             filename,
             scale_mode,
+            resize_quality,
             dither_mode,
+            crate::image_processing::PixelDepth::One,
             None, // brightness
             None, // contrast
             transform,
@@ -169,13 +277,15 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
     }
 
     fn clear(&mut self) -> Result<(), DisplayError> {
-        let spec = self.protocol.get_spec();
-        let white_data = image::create_white_image_with_spec(spec);
-        self.display_image_raw(&white_data, DisplayMode::Full)
+        self.record("clear", 0, |this| {
+            let spec = this.protocol.get_spec();
+            let white_data = image::create_white_image_with_spec(spec);
+            this.display_image_raw(&white_data, DisplayMode::Full)
+        })
     }
 
     fn sleep(&mut self) -> Result<(), DisplayError> {
-        self.protocol.sleep()
+        self.record("sleep", 0, |this| this.protocol.sleep())
     }
 
     fn cleanup(&mut self) -> Result<(), DisplayError> {
@@ -190,6 +300,118 @@ impl<P: EinkProtocol> DisplayDriver for GenericDisplay<P> {
     fn get_spec(&self) -> &crate::firmware::DisplaySpec {
         self.protocol.get_spec()
     }
+
+    fn load_waveform(&mut self, path: &str, mode: DisplayMode) -> Result<(), DisplayError> {
+        if !self.initialized {
+            return Err(DisplayError::NotInitialized);
+        }
+
+        let waveform = crate::waveform::load_from_file(path)?;
+        log::info!(
+            "Loading waveform '{}' for {mode:?} mode from {path}",
+            waveform.panel()
+        );
+        self.protocol.load_waveform(waveform.into_command_sequence())
+    }
+
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed) {
+        self.protocol.set_refresh_speed(speed);
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn stats(&self) -> crate::diagnostics::DisplayStats {
+        self.stats.clone()
+    }
+
+    fn display_region(
+        &mut self,
+        data: &[u8],
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+    ) -> Result<(), DisplayError> {
+        let byte_count = data.len();
+        self.record("display_region", byte_count, |this| {
+            (this as &mut dyn std::any::Any)
+                .downcast_mut::<GenericDisplay<crate::protocol::ConfigurableProtocol>>()
+                .ok_or_else(|| {
+                    DisplayError::Config(
+                        "active display driver has no windowed partial-update support".to_string(),
+                    )
+                })
+                .and_then(|display| display.display_image_region(data, x, y, w, h))
+        })
+    }
+}
+
+impl GenericDisplay<crate::protocol::ConfigurableProtocol> {
+    /// Push a sub-rectangle of 1-bit data and issue a windowed partial
+    /// refresh, instead of redrawing the whole panel. Falls back to a full
+    /// [`DisplayMode::Full`] refresh when `(x, y, w, h)` spans the entire
+    /// panel, since a windowed refresh of the whole frame has no benefit
+    /// over the simpler full-refresh path.
+    ///
+    /// `data` must be exactly `ceil(w/8) * h` bytes, tightly packed with
+    /// no per-row padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::NotInitialized` if the display has not been
+    /// initialized, `DisplayError::Config` if `x` or `w` isn't a multiple
+    /// of 8 (the panel addresses RAM in whole bytes along the X axis), or
+    /// `DisplayError::InvalidDataSize` if `data`'s length doesn't match
+    /// `(x, y, w, h)` or the rectangle falls outside the panel bounds.
+    pub fn display_image_region(
+        &mut self,
+        data: &[u8],
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+    ) -> Result<(), DisplayError> {
+        if !self.initialized {
+            return Err(DisplayError::NotInitialized);
+        }
+
+        let spec = self.protocol.get_spec();
+        let in_bounds = x.checked_add(w).is_some_and(|right| right <= spec.width)
+            && y.checked_add(h).is_some_and(|bottom| bottom <= spec.height)
+            && u16::try_from(x).is_ok()
+            && u16::try_from(y).is_ok()
+            && u16::try_from(w).is_ok()
+            && u16::try_from(h).is_ok();
+        if w == 0 || h == 0 || !in_bounds {
+            return Err(DisplayError::InvalidDataSize {
+                expected: spec.array_size(),
+                actual: data.len(),
+            });
+        }
+
+        if !x.is_multiple_of(8) || !w.is_multiple_of(8) {
+            return Err(DisplayError::Config(format!(
+                "Region x ({x}) and width ({w}) must be 8-pixel (byte) aligned"
+            )));
+        }
+
+        let expected_len = (w as usize).div_ceil(8) * h as usize;
+        if data.len() != expected_len {
+            return Err(DisplayError::InvalidDataSize {
+                expected: expected_len,
+                actual: data.len(),
+            });
+        }
+
+        if x == 0 && y == 0 && w == spec.width && h == spec.height {
+            return self.display_image_raw(data, DisplayMode::Full);
+        }
+
+        self.protocol
+            .write_window(x as u16, y as u16, w as u16, h as u16, data)
+    }
 }
 
 /// Default display driver type using configurable protocol
@@ -197,11 +419,32 @@ pub type DefaultDisplay = GenericDisplay<crate::protocol::DefaultProtocol>;
 
 // Global state for C FFI compatibility
 struct GlobalDisplayState {
-    display: Option<DefaultDisplay>,
+    display: Option<Box<dyn DisplayDriver>>,
 }
 
 static GLOBAL_STATE: Mutex<GlobalDisplayState> = Mutex::new(GlobalDisplayState { display: None });
 
+/// Release `state` and deliver any events queued while it was held, plus
+/// `success_event` if `result` is `Ok` (an error always delivers `ERROR`
+/// with the failing operation's FFI code instead). This is the only place
+/// that calls into [`notify::flush`], so the registered callback's
+/// "never invoked while holding the display mutex" guarantee holds as long
+/// as every public function in this module routes its result through here.
+fn finish<T>(
+    state: MutexGuard<'_, GlobalDisplayState>,
+    result: Result<T, DisplayError>,
+    success_event: Option<(i32, i32)>,
+) -> Result<T, DisplayError> {
+    drop(state);
+
+    match &result {
+        Ok(_) => notify::flush(success_event),
+        Err(e) => notify::flush(Some((notify::event::ERROR, notify::ffi_code(e)))),
+    }
+
+    result
+}
+
 /// Initialize the display hardware
 ///
 /// # Errors
@@ -212,14 +455,20 @@ pub fn display_init() -> Result<(), DisplayError> {
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if state.display.is_none() {
-        let protocol = create_default_protocol()?;
-        let mut display = DefaultDisplay::new(protocol);
-        display.init()?;
-        state.display = Some(display);
-    }
-
-    Ok(())
+    let already_initialized = state.display.is_some();
+    let result = if already_initialized {
+        Ok(())
+    } else {
+        create_default_protocol().and_then(|protocol| {
+            let mut display = DefaultDisplay::new(protocol);
+            display.init()?;
+            state.display = Some(Box::new(display));
+            Ok(())
+        })
+    };
+
+    let success_event = (!already_initialized).then_some((notify::event::INITIALIZED, 0));
+    finish(state, result, success_event)
 }
 
 /// Display a raw 1-bit image
@@ -232,11 +481,35 @@ pub fn display_image_raw(data: &[u8], mode: DisplayMode) -> Result<(), DisplayEr
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
+    let result = if let Some(display) = &mut state.display {
         display.display_image_raw(data, mode)
     } else {
         Err(DisplayError::NotInitialized)
-    }
+    };
+
+    finish(state, result, None)
+}
+
+/// Flush a [`crate::framebuffer::Framebuffer`] drawn via `embedded-graphics`.
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the display is not initialized or display fails
+pub fn display_framebuffer(
+    framebuffer: &crate::framebuffer::Framebuffer,
+    mode: DisplayMode,
+) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        display.display_framebuffer(framebuffer, mode)
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
 }
 
 /// Display a PNG image
@@ -250,11 +523,13 @@ pub fn display_image_png(filename: &str, mode: DisplayMode) -> Result<(), Displa
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
+    let result = if let Some(display) = &mut state.display {
         display.display_image_png(filename, mode)
     } else {
         Err(DisplayError::NotInitialized)
-    }
+    };
+
+    finish(state, result, None)
 }
 
 /// Display any supported image file format
@@ -268,11 +543,13 @@ pub fn display_image_file(filename: &str, mode: DisplayMode) -> Result<(), Displ
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
+    let result = if let Some(display) = &mut state.display {
         display.display_image_file(filename, mode)
     } else {
         Err(DisplayError::NotInitialized)
-    }
+    };
+
+    finish(state, result, None)
 }
 
 /// Display image with automatic processing
@@ -285,6 +562,7 @@ pub fn display_image_auto(
     filename: &str,
     mode: DisplayMode,
     scale_mode: crate::image_processing::ScaleMode,
+    resize_quality: crate::image_processing::ResizeQuality,
     dither_mode: crate::image_processing::DitherMode,
     transform: Option<Transform>,
 ) -> Result<(), DisplayError> {
@@ -292,11 +570,193 @@ pub fn display_image_auto(
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
-        display.display_image_auto(filename, mode, scale_mode, dither_mode, transform)
+    let result = if let Some(display) = &mut state.display {
+        display.display_image_auto(filename, mode, scale_mode, resize_quality, dither_mode, transform)
     } else {
         Err(DisplayError::NotInitialized)
-    }
+    };
+
+    finish(state, result, None)
+}
+
+/// Display an RLE-compressed raw 1-bit image (see [`crate::image::rle_decode`])
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the display is not initialized, the stream
+/// fails to decode to exactly the configured array size, or display fails
+pub fn display_image_raw_rle(data: &[u8], mode: DisplayMode) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        let array_size = display.get_spec().array_size();
+        image::rle_decode(data, array_size).and_then(|decoded| display.display_image_raw(&decoded, mode))
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
+}
+
+/// Display an image decoded from an in-memory buffer (e.g. a zip archive
+/// entry), requiring it to exactly match the panel's dimensions — the
+/// in-memory analogue of [`display_image_file`]
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the display is not initialized, the buffer
+/// cannot be decoded or doesn't match the panel size, or display fails
+pub fn display_image_bytes(data: &[u8], mode: DisplayMode) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        let spec = display.get_spec();
+        image::convert_image_bytes_to_1bit_with_spec(data, spec)
+            .and_then(|raw| display.display_image_raw(&raw, mode))
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
+}
+
+/// Display an image decoded from an in-memory buffer with automatic
+/// scaling and dithering — the in-memory analogue of
+/// [`display_image_auto`]
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the display is not initialized, processing
+/// fails, or display fails
+pub fn display_image_auto_bytes(
+    data: &[u8],
+    mode: DisplayMode,
+    scale_mode: crate::image_processing::ScaleMode,
+    resize_quality: crate::image_processing::ResizeQuality,
+    dither_mode: crate::image_processing::DitherMode,
+    transform: Option<Transform>,
+) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        let spec = display.get_spec().clone();
+        let processor = crate::image_processing::ImageProcessor::new(spec);
+        processor
+            .process_image_bytes(
+                data,
+                scale_mode,
+                resize_quality,
+                dither_mode,
+                crate::image_processing::PixelDepth::One,
+                None,
+                None,
+                transform,
+                false,
+            )
+            .and_then(|raw| display.display_image_raw(&raw, mode))
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
+}
+
+/// Convert a PNG image to 1-bit format and RLE-compress it
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the file cannot be read or conversion fails
+pub fn convert_png_to_1bit_rle(filename: &str) -> Result<Vec<u8>, DisplayError> {
+    // For backwards compatibility, use default firmware
+    image::convert_png_to_1bit_rle(filename)
+}
+
+/// Push a sub-rectangle of 1-bit data and issue a windowed partial refresh
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the display is not initialized, `data`'s
+/// length doesn't match the rectangle, the rectangle falls outside the
+/// panel bounds, or the active firmware has no windowed partial-update
+/// support
+pub fn display_image_region(data: &[u8], x: u32, y: u32, w: u32, h: u32) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        display.display_region(data, x, y, w, h)
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
+}
+
+/// Load a waveform file and install it into the active protocol
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the display is not initialized, the file
+/// cannot be read or fails to parse, or installing it fails
+pub fn display_load_waveform(path: &str, mode: DisplayMode) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        display.load_waveform(path, mode)
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
+}
+
+/// Query cumulative diagnostics (operation counts, last error, average
+/// refresh latency) collected across every wrapped operation so far — see
+/// [`crate::diagnostics::DisplayStats`]. A pure read: unlike the other
+/// functions in this module it doesn't flush queued notifications, since
+/// it doesn't perform an operation of its own.
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the display has not been
+/// initialized
+pub fn display_stats() -> Result<crate::diagnostics::DisplayStats, DisplayError> {
+    let state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    state.display.as_ref().map_or(Err(DisplayError::NotInitialized), |display| Ok(display.stats()))
+}
+
+/// Select the waveform LUT subsequent partial updates use, trading image
+/// quality/ghosting for speed — see [`crate::firmware::RefreshSpeed`].
+///
+/// # Errors
+///
+/// Returns `DisplayError::NotInitialized` if the display has not been
+/// initialized
+pub fn set_refresh_speed(speed: crate::firmware::RefreshSpeed) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let result = if let Some(display) = &mut state.display {
+        display.set_refresh_speed(speed);
+        Ok(())
+    } else {
+        Err(DisplayError::NotInitialized)
+    };
+
+    finish(state, result, None)
 }
 
 /// Clear the display to white
@@ -309,11 +769,13 @@ pub fn display_clear() -> Result<(), DisplayError> {
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
+    let result = if let Some(display) = &mut state.display {
         display.clear()
     } else {
         Err(DisplayError::NotInitialized)
-    }
+    };
+
+    finish(state, result, None)
 }
 
 /// Put the display into sleep mode
@@ -327,11 +789,13 @@ pub fn display_sleep() -> Result<(), DisplayError> {
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
+    let result = if let Some(display) = &mut state.display {
         display.sleep()
     } else {
         Err(DisplayError::NotInitialized)
-    }
+    };
+
+    finish(state, result, Some((notify::event::ENTERED_SLEEP, 0)))
 }
 
 /// Clean up display resources and put it to sleep
@@ -344,12 +808,18 @@ pub fn display_cleanup() -> Result<(), DisplayError> {
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if let Some(display) = &mut state.display {
-        display.cleanup()?;
+    let had_display = state.display.is_some();
+    let result = if let Some(display) = &mut state.display {
+        display.cleanup()
+    } else {
+        Ok(())
+    };
+    if result.is_ok() {
         state.display = None;
     }
 
-    Ok(())
+    let success_event = had_display.then_some((notify::event::CLEANED_UP, 0));
+    finish(state, result, success_event)
 }
 
 /// Get the current display dimensions
@@ -369,26 +839,95 @@ pub fn convert_png_to_1bit(filename: &str) -> Result<Vec<u8>, DisplayError> {
     image::convert_png_to_1bit(filename)
 }
 
-/// Initialize display with custom firmware
+/// Convert a PNG image to 1-bit format using the default firmware spec,
+/// selecting between a flat threshold and Floyd-Steinberg dithering
 ///
 /// # Errors
 ///
-/// Returns `DisplayError` if initialization fails
+/// Returns `DisplayError` if the file cannot be read or conversion fails
+pub fn convert_png_to_1bit_dithered(
+    filename: &str,
+    mode: image::ConversionMode,
+) -> Result<Vec<u8>, DisplayError> {
+    // For backwards compatibility, use default firmware
+    image::convert_png_to_1bit_dithered(filename, mode)
+}
+
+/// Initialize the display with a custom firmware implementation, for panels
+/// not covered by the built-in [`crate::config::FirmwareType`] variants.
+///
+/// If the display is already initialized, this is a no-op (the running
+/// firmware is left in place, same as [`display_init`]).
+///
+/// # Errors
+///
+/// Returns `DisplayError` if hardware initialization fails
+#[cfg(feature = "linux")]
 pub fn display_init_with_firmware<F: crate::firmware::DisplayFirmware + 'static>(
     firmware: F,
 ) -> Result<(), DisplayError> {
-    let state = GLOBAL_STATE
+    let mut state = GLOBAL_STATE
         .lock()
         .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
 
-    if state.display.is_none() {
-        let protocol = crate::protocol::create_protocol_with_firmware(firmware)?;
-        let mut display = GenericDisplay::new(protocol);
-        display.init()?;
-        // Note: This won't work directly due to type system constraints
-        // You'd need to use a trait object or enum for runtime firmware
-        // selection For now, this is a design template
-    }
+    let already_initialized = state.display.is_some();
+    let result = if already_initialized {
+        Ok(())
+    } else {
+        crate::protocol::create_protocol_with_firmware(firmware).and_then(|protocol| {
+            let mut display = GenericDisplay::new(protocol);
+            display.init()?;
+            state.display = Some(Box::new(display));
+            Ok(())
+        })
+    };
+
+    let success_event = (!already_initialized).then_some((notify::event::INITIALIZED, 0));
+    finish(state, result, success_event)
+}
 
-    Ok(())
+/// Initialize the display with an explicit firmware selection, picked at
+/// runtime rather than baked in via a generic parameter — e.g. to switch
+/// between `EPD128x250` and `EPD240x416` without the call site knowing
+/// either protocol type, the same kind of runtime selection
+/// [`crate::config::get_default_firmware`] drives for [`display_init`].
+///
+/// If the display is already initialized, this is a no-op.
+///
+/// # Errors
+///
+/// Returns `DisplayError` if hardware initialization fails
+#[cfg(feature = "linux")]
+pub fn display_init_with_firmware_type(
+    firmware_type: crate::config::FirmwareType,
+) -> Result<(), DisplayError> {
+    let mut state = GLOBAL_STATE
+        .lock()
+        .map_err(|e| DisplayError::Config(format!("Failed to acquire state lock: {e}")))?;
+
+    let already_initialized = state.display.is_some();
+    let result = if already_initialized {
+        Ok(())
+    } else {
+        crate::hardware::DefaultHardwareInterface::new().and_then(|hardware| {
+            let mut display: Box<dyn DisplayDriver> = match firmware_type {
+                crate::config::FirmwareType::EPD128x250 => {
+                    let firmware = crate::firmware::EPD128x250Firmware::new();
+                    let protocol = crate::protocol::GenericEinkProtocol::new(hardware, firmware);
+                    Box::new(GenericDisplay::new(protocol))
+                },
+                crate::config::FirmwareType::EPD240x416 => {
+                    let firmware = crate::firmware::EPD240x416Firmware::new();
+                    let protocol = crate::protocol::GenericEinkProtocol::new(hardware, firmware);
+                    Box::new(GenericDisplay::new(protocol))
+                },
+            };
+            display.init()?;
+            state.display = Some(display);
+            Ok(())
+        })
+    };
+
+    let success_event = (!already_initialized).then_some((notify::event::INITIALIZED, 0));
+    finish(state, result, success_event)
 }
@@ -0,0 +1,147 @@
+//! An `embedded-hal` 1.0 driver that executes [`CommandSequence`]s directly
+//! over an SPI device and GPIO pins, for boards outside the `gpiod`/`spidev`
+//! Linux stack that [`GenericEinkProtocol`](crate::protocol::GenericEinkProtocol)'s
+//! `HardwareInterface` targets.
+//!
+//! This mirrors how the `epd-waveshare` crate layers a driver over raw
+//! command/LUT data: [`DisplayDriver::execute`] interprets a firmware's
+//! [`CommandSequence`] step by step instead of going through the
+//! `GpioController`/`SpiController` abstraction.
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{InputPin, OutputPin};
+use embedded_hal::spi::SpiDevice;
+
+use crate::error::DisplayError;
+use crate::firmware::{Command, CommandSequence};
+
+/// Number of 10ms `check_status` polls of BUSY before giving up with
+/// `DisplayError::Timeout`, matching `GenericEinkProtocol`'s watchdog.
+const BUSY_TIMEOUT_POLLS: u32 = 1000;
+
+/// Drives a panel directly over `embedded-hal` 1.0 SPI/GPIO by interpreting
+/// [`CommandSequence`]s: pulling DC low/high around command and data bytes,
+/// toggling RST with the sequence's own delay timing, and polling BUSY until
+/// idle on `CheckStatus`.
+pub struct DisplayDriver<SPI, DC, RST, BUSY, DELAY> {
+    spi: SPI,
+    dc: DC,
+    rst: RST,
+    busy: BUSY,
+    delay: DELAY,
+}
+
+impl<SPI, DC, RST, BUSY, DELAY> DisplayDriver<SPI, DC, RST, BUSY, DELAY>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    RST: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+{
+    /// Build a driver from already-configured `embedded-hal` pin/bus handles.
+    pub fn new(spi: SPI, dc: DC, rst: RST, busy: BUSY, delay: DELAY) -> Self {
+        Self { spi, dc, rst, busy, delay }
+    }
+
+    /// Pull DC low and write a command byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if setting DC fails, or
+    /// `DisplayError::Spi` if the write fails
+    pub fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
+        self.delay.delay_us(10);
+        self.dc
+            .set_low()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(&[cmd])
+            .map_err(|e| DisplayError::Spi(format!("Failed to write command: {e:?}")))
+    }
+
+    /// Pull DC high and write a data byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if setting DC fails, or
+    /// `DisplayError::Spi` if the write fails
+    pub fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
+        self.delay.delay_us(10);
+        self.dc
+            .set_high()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(&[data])
+            .map_err(|e| DisplayError::Spi(format!("Failed to write data: {e:?}")))
+    }
+
+    /// Pull DC high and write a full frame of image data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if setting DC fails, or
+    /// `DisplayError::Spi` if the write fails
+    pub fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        self.dc
+            .set_high()
+            .map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))?;
+        self.spi
+            .write(data)
+            .map_err(|e| DisplayError::Spi(format!("Failed to write image data: {e:?}")))
+    }
+
+    /// Poll BUSY until it goes idle, bounded by a timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Timeout` if BUSY doesn't clear in time, or
+    /// `DisplayError::Gpio` if reading the pin fails
+    pub fn check_status(&mut self) -> Result<(), DisplayError> {
+        let mut watchdog_counter = 0;
+        while self
+            .busy
+            .is_high()
+            .map_err(|_| DisplayError::Gpio("Failed to read BUSY pin".to_string()))?
+            && watchdog_counter < BUSY_TIMEOUT_POLLS
+        {
+            self.delay.delay_ms(10);
+            watchdog_counter += 1;
+        }
+
+        if watchdog_counter >= BUSY_TIMEOUT_POLLS {
+            log::warn!("Display busy timeout");
+            return Err(DisplayError::Timeout);
+        }
+
+        Ok(())
+    }
+
+    /// Interpret and transmit every step of `seq`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command, data write, reset, or status
+    /// check fails
+    pub fn execute(&mut self, seq: &CommandSequence) -> Result<(), DisplayError> {
+        for command in &seq.commands {
+            match command {
+                Command::WriteCommand(cmd) => self.write_cmd(*cmd)?,
+                Command::WriteData(data) => self.write_data(*data)?,
+                Command::Delay(ms) => self.delay.delay_ms(*ms as u32),
+                Command::CheckStatus => self.check_status()?,
+                Command::Reset => {
+                    self.rst
+                        .set_low()
+                        .map_err(|_| DisplayError::Gpio("Failed to set RST pin".to_string()))?;
+                    self.delay.delay_ms(10);
+                    self.rst
+                        .set_high()
+                        .map_err(|_| DisplayError::Gpio("Failed to set RST pin".to_string()))?;
+                    self.delay.delay_ms(10);
+                },
+            }
+        }
+        Ok(())
+    }
+}
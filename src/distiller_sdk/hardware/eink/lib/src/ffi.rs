@@ -4,7 +4,16 @@ use std::{
     ptr,
 };
 
-use crate::{config, display, protocol::DisplayMode, error::DisplayError};
+use image::GrayImage;
+
+use crate::{
+    archive, config, display,
+    image_processing::{Compositor, DitherMode, ImageProcessor, PixelDepth, QrRenderer, RasterOp, ShapeDrawer},
+    notify,
+    protocol::DisplayMode,
+    qr::EcLevel,
+    error::DisplayError, splash,
+};
 
 // Error code constants for FFI
 const SUCCESS: c_int = 1;
@@ -16,7 +25,6 @@ const ERR_NOT_INITIALIZED: c_int = -5;
 const ERR_INVALID_DATA: c_int = -6;
 const ERR_PNG: c_int = -7;
 const ERR_IO: c_int = -8;
-const ERR_UNKNOWN: c_int = -99;
 
 /// Map DisplayError to error code
 fn error_to_code(e: &DisplayError) -> c_int {
@@ -105,6 +113,104 @@ pub unsafe extern "C" fn display_image_raw(data: *const u8, mode: c_int) -> c_in
     }
 }
 
+/// Display an RLE-compressed raw 1-bit image on the e-ink display (see
+/// [`crate::image::rle_decode`] for the wire format).
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `data` is a valid pointer to at least `len` bytes
+/// - `data` remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `data`: Pointer to RLE-compressed 1-bit image data
+/// - `len`: Length of `data` in bytes
+/// - `mode`: Display mode (0 = Full, 1 = Partial)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_image_raw_rle(data: *const u8, len: usize, mode: c_int) -> c_int {
+    if data.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let data_slice = unsafe { std::slice::from_raw_parts(data, len) };
+    let display_mode = match mode {
+        0 => DisplayMode::Full,
+        1 => DisplayMode::Partial,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    match display::display_image_raw_rle(data_slice, display_mode) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Display image raw RLE failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Convert a PNG image to RLE-compressed 1-bit format suitable for
+/// [`display_image_raw_rle`].
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `filename` is a valid pointer to a null-terminated C string
+/// - `output_data` is a valid pointer to at least `max_len` bytes of
+///   writable memory
+/// - `out_len` is a valid pointer to writable memory
+/// - All pointers remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `filename`: Path to PNG file as null-terminated C string
+/// - `output_data`: Output buffer for the RLE-compressed data
+/// - `max_len`: Capacity of `output_data` in bytes
+/// - `out_len`: Output pointer that receives the actual encoded length
+///
+/// # Returns
+///
+/// - 1 on success
+/// - `ERR_INVALID_DATA` if the encoded result doesn't fit in `max_len`
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn convert_png_to_1bit_rle(
+    filename: *const c_char,
+    output_data: *mut u8,
+    max_len: usize,
+    out_len: *mut usize,
+) -> c_int {
+    if filename.is_null() || output_data.is_null() || out_len.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(filename_str) = unsafe { CStr::from_ptr(filename) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    match display::convert_png_to_1bit_rle(filename_str) {
+        Ok(encoded) => {
+            if encoded.len() > max_len {
+                return ERR_INVALID_DATA;
+            }
+            unsafe {
+                ptr::copy_nonoverlapping(encoded.as_ptr(), output_data, encoded.len());
+                *out_len = encoded.len();
+            }
+            SUCCESS
+        },
+        Err(e) => {
+            log::error!("Convert PNG to 1bit RLE failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
 /// Display a PNG image on the e-ink display.
 ///
 /// # Safety
@@ -202,8 +308,9 @@ pub unsafe extern "C" fn display_image_file(filename: *const c_char, mode: c_int
 /// - `filename`: Path to image file as null-terminated C string
 /// - `mode`: Display mode (0 = Full, 1 = Partial)
 /// - `scale_mode`: Scale mode (0 = Letterbox, 1 = `CropCenter`, 2 = Stretch)
+/// - `resize_quality`: Resize quality (0 = Nearest, 1 = Bilinear, 2 = Lanczos3)
 /// - `dither_mode`: Dither mode (0 = Threshold, 1 = `FloydSteinberg`, 2 =
-///   Ordered)
+///   Ordered, 3 = Atkinson, 4 = `JarvisJudiceNinke`, 5 = Stucki)
 ///
 /// # Returns
 ///
@@ -214,6 +321,7 @@ pub unsafe extern "C" fn display_image_auto(
     filename: *const c_char,
     mode: c_int,
     scale_mode: c_int,
+    resize_quality: c_int,
     dither_mode: c_int,
 ) -> c_int {
     if filename.is_null() {
@@ -237,14 +345,24 @@ pub unsafe extern "C" fn display_image_auto(
         _ => return ERR_INVALID_DATA,
     };
 
+    let quality = match resize_quality {
+        0 => crate::image_processing::ResizeQuality::Nearest,
+        1 => crate::image_processing::ResizeQuality::Bilinear,
+        2 => crate::image_processing::ResizeQuality::Lanczos3,
+        _ => return ERR_INVALID_DATA,
+    };
+
     let dither = match dither_mode {
         0 => crate::image_processing::DitherMode::Threshold,
         1 => crate::image_processing::DitherMode::FloydSteinberg,
         2 => crate::image_processing::DitherMode::Ordered,
+        3 => crate::image_processing::DitherMode::Atkinson,
+        4 => crate::image_processing::DitherMode::JarvisJudiceNinke,
+        5 => crate::image_processing::DitherMode::Stucki,
         _ => return ERR_INVALID_DATA,
     };
 
-    match display::display_image_auto(filename_str, display_mode, scale, dither) {
+    match display::display_image_auto(filename_str, display_mode, scale, quality, dither, None) {
         Ok(()) => SUCCESS,
         Err(e) => {
             log::error!("Display image auto failed: {e}");
@@ -253,6 +371,129 @@ pub unsafe extern "C" fn display_image_auto(
     }
 }
 
+/// Execute a binary command stream batching several display operations
+/// (clear, full refresh, partial-region writes, sleep) in one call. See
+/// [`crate::command_stream`] for the wire format.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `data` is a valid pointer to at least `len` bytes
+/// - `data` remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `data`: Pointer to the binary command stream
+/// - `len`: Length of `data` in bytes
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants); no record is
+///   executed if any record in the stream fails validation
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_execute_stream(data: *const u8, len: usize) -> c_int {
+    if data.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let data_slice = unsafe { std::slice::from_raw_parts(data, len) };
+
+    match crate::command_stream::execute_stream(data_slice) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Execute command stream failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Push a sub-rectangle of 1-bit data and issue a windowed partial
+/// refresh, without redrawing the whole panel.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `data` is a valid pointer to at least `ceil(w / 8) * h` bytes
+/// - `data` remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `data`: Pointer to tightly packed 1-bit data for the region, no
+///   per-row padding
+/// - `x`, `y`: Top-left corner of the region, in pixels
+/// - `w`, `h`: Width and height of the region, in pixels
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_image_region(
+    data: *const u8,
+    x: c_uint,
+    y: c_uint,
+    w: c_uint,
+    h: c_uint,
+) -> c_int {
+    if data.is_null() || w == 0 || h == 0 {
+        return ERR_INVALID_DATA;
+    }
+
+    let expected_len = (w as usize).div_ceil(8) * h as usize;
+    let data_slice = unsafe { std::slice::from_raw_parts(data, expected_len) };
+
+    match display::display_image_region(data_slice, x, y, w, h) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Display image region failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Load a waveform file and install it into the active protocol.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `path` is a valid pointer to a null-terminated C string
+/// - The string remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `path`: Path to the waveform file as a null-terminated C string
+/// - `mode`: Display mode this waveform is loaded for (0 = Full, 1 = Partial)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_load_waveform(path: *const c_char, mode: c_int) -> c_int {
+    if path.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(path_str) = unsafe { CStr::from_ptr(path) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    let display_mode = match mode {
+        0 => DisplayMode::Full,
+        1 => DisplayMode::Partial,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    match display::display_load_waveform(path_str, display_mode) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Display load waveform failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
 /// Clear the display to white.
 ///
 /// # Safety
@@ -378,6 +619,69 @@ pub unsafe extern "C" fn convert_png_to_1bit(
     }
 }
 
+/// Convert a PNG image to 1-bit format, selecting between a flat threshold
+/// and Floyd-Steinberg dithering.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `filename` is a valid pointer to a null-terminated C string
+/// - `output_data` is a valid pointer to at least `array_size` bytes of
+///   writable memory
+/// - Both pointers remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `filename`: Path to PNG file as null-terminated C string
+/// - `dither_mode`: Dither mode (0 = Threshold, 1 = `FloydSteinberg`)
+/// - `output_data`: Output buffer for converted 1-bit data
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn convert_png_to_1bit_dithered(
+    filename: *const c_char,
+    dither_mode: c_int,
+    output_data: *mut u8,
+) -> c_int {
+    if filename.is_null() || output_data.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(filename_str) = unsafe { CStr::from_ptr(filename) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    let mode = match dither_mode {
+        0 => crate::image::ConversionMode::Threshold,
+        1 => crate::image::ConversionMode::FloydSteinberg,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    match display::convert_png_to_1bit_dithered(filename_str, mode) {
+        Ok(data) => {
+            // Get the configured firmware array size
+            let array_size = match config::get_default_spec() {
+                Ok(spec) => spec.array_size(),
+                Err(e) => {
+                    log::error!("Failed to get default firmware spec: {e}");
+                    return error_to_code(&e);
+                },
+            };
+            unsafe {
+                ptr::copy_nonoverlapping(data.as_ptr(), output_data, array_size);
+            }
+            SUCCESS
+        },
+        Err(e) => {
+            log::error!("Convert PNG to 1bit (dithered) failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
 // Configuration FFI functions
 
 /// Set the display firmware type.
@@ -490,6 +794,756 @@ pub extern "C" fn display_initialize_config() -> c_int {
     }
 }
 
+/// Display `entry` from the zip archive at `archive`, decoded through the
+/// same format-detection path as [`display_image_file`], without the
+/// caller having to extract files to disk first.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `archive` and `entry` are valid pointers to null-terminated C strings
+/// - both remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `archive`: Path to the zip archive
+/// - `entry`: Name of the entry within the archive to display
+/// - `mode`: Display mode (0 = Full, 1 = Partial)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - `ERR_IO` if the archive or entry can't be read
+/// - `ERR_PNG` if the decoded image doesn't match the panel size
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_image_from_archive(
+    archive_path: *const c_char,
+    entry: *const c_char,
+    mode: c_int,
+) -> c_int {
+    if archive_path.is_null() || entry.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(archive_str) = unsafe { CStr::from_ptr(archive_path) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+    let Ok(entry_str) = unsafe { CStr::from_ptr(entry) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    let display_mode = match mode {
+        0 => DisplayMode::Full,
+        1 => DisplayMode::Partial,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    match archive::display_image_from_archive(archive_str, entry_str, display_mode) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Display image from archive failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Display `entry` from the zip archive at `archive`, run through the
+/// existing auto scale/dither pipeline (see [`display_image_auto`]),
+/// without the caller having to extract files to disk first.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `archive` and `entry` are valid pointers to null-terminated C strings
+/// - both remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `archive`: Path to the zip archive
+/// - `entry`: Name of the entry within the archive to display
+/// - `mode`: Display mode (0 = Full, 1 = Partial)
+/// - `scale_mode`: Scaling mode (0 = Letterbox, 1 = CropCenter, 2 = Stretch)
+/// - `dither_mode`: Dithering mode (0-5, see [`display_image_auto`])
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_image_from_archive_auto(
+    archive_path: *const c_char,
+    entry: *const c_char,
+    mode: c_int,
+    scale_mode: c_int,
+    dither_mode: c_int,
+) -> c_int {
+    if archive_path.is_null() || entry.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(archive_str) = unsafe { CStr::from_ptr(archive_path) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+    let Ok(entry_str) = unsafe { CStr::from_ptr(entry) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    let display_mode = match mode {
+        0 => DisplayMode::Full,
+        1 => DisplayMode::Partial,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let scale = match scale_mode {
+        0 => crate::image_processing::ScaleMode::Letterbox,
+        1 => crate::image_processing::ScaleMode::CropCenter,
+        2 => crate::image_processing::ScaleMode::Stretch,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let dither = match dither_mode {
+        0 => crate::image_processing::DitherMode::Threshold,
+        1 => crate::image_processing::DitherMode::FloydSteinberg,
+        2 => crate::image_processing::DitherMode::Ordered,
+        3 => crate::image_processing::DitherMode::Atkinson,
+        4 => crate::image_processing::DitherMode::JarvisJudiceNinke,
+        5 => crate::image_processing::DitherMode::Stucki,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    match archive::display_image_from_archive_auto(archive_str, entry_str, display_mode, scale, dither, None) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Display image from archive auto failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Display the boot splash found in `dir`, auto-selected for the active
+/// panel's resolution. See [`crate::splash::display_splash`] for the
+/// asset-selection rules.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `dir` is a valid pointer to a null-terminated C string
+/// - `dir` remains valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `dir`: Path to the directory containing splash assets
+///
+/// # Returns
+///
+/// - 1 on success
+/// - `ERR_CONFIG` if the active display spec cannot be resolved
+/// - `ERR_IO` if no suitable splash asset is found in `dir`
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_splash(dir: *const c_char) -> c_int {
+    if dir.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(dir_str) = unsafe { CStr::from_ptr(dir) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    match splash::display_splash(dir_str) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            log::error!("Display splash failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Render `text` as a QR code into a new buffer sized for the active
+/// display spec, with its top-left quiet-zone corner at `(x, y)`.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `text` is a valid pointer to a null-terminated UTF-8 C string
+/// - `output_data` is a valid pointer to at least `array_size` bytes of
+///   writable memory for the active display spec
+///
+/// # Parameters
+///
+/// - `text`: The string to encode, as a null-terminated C string
+/// - `x`, `y`: Top-left corner of the QR code's quiet zone, in pixels
+/// - `module_size`: Side length of each QR module, in pixels
+/// - `quiet_zone`: Width of the blank border around the symbol, in modules
+/// - `ec_level`: Error correction level (0=L, 1=M, 2=Q, 3=H)
+/// - `output_data`: Output buffer for the rendered 1-bit image
+///
+/// # Returns
+///
+/// - 1 on success
+/// - 0 if `text` is too large for the smallest QR version that fits the
+///   panel at the given `module_size` and `quiet_zone`
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qr_render(
+    text: *const c_char,
+    x: c_uint,
+    y: c_uint,
+    module_size: c_uint,
+    quiet_zone: c_uint,
+    ec_level: c_int,
+    output_data: *mut u8,
+) -> c_int {
+    if text.is_null() || output_data.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(text_str) = unsafe { CStr::from_ptr(text) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    let Some(level) = EcLevel::from_code(ec_level) else {
+        return ERR_INVALID_DATA;
+    };
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let renderer = QrRenderer::new(spec.width, spec.height);
+    match renderer.render_qr(text_str, x, y, module_size, quiet_zone, level) {
+        Ok(Some(buffer)) => {
+            unsafe {
+                ptr::copy_nonoverlapping(buffer.as_ptr(), output_data, spec.array_size());
+            }
+            SUCCESS
+        },
+        Ok(None) => 0,
+        Err(e) => {
+            log::error!("QR render failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Render `text` as a QR code onto an existing 1-bit buffer, with its
+/// top-left quiet-zone corner at `(x, y)`.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `text` is a valid pointer to a null-terminated UTF-8 C string
+/// - `buffer` is a valid pointer to at least `array_size` bytes of
+///   writable memory for the active display spec
+///
+/// # Parameters
+///
+/// - `text`: The string to encode, as a null-terminated C string
+/// - `x`, `y`: Top-left corner of the QR code's quiet zone, in pixels
+/// - `module_size`: Side length of each QR module, in pixels
+/// - `quiet_zone`: Width of the blank border around the symbol, in modules
+/// - `ec_level`: Error correction level (0=L, 1=M, 2=Q, 3=H)
+/// - `buffer`: Existing 1-bit image buffer to draw onto
+///
+/// # Returns
+///
+/// - 1 on success
+/// - 0 if `text` is too large for the smallest QR version that fits the
+///   panel at the given `module_size` and `quiet_zone` (`buffer` is left
+///   untouched)
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn qr_overlay(
+    text: *const c_char,
+    x: c_uint,
+    y: c_uint,
+    module_size: c_uint,
+    quiet_zone: c_uint,
+    ec_level: c_int,
+    buffer: *mut u8,
+) -> c_int {
+    if text.is_null() || buffer.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let Ok(text_str) = unsafe { CStr::from_ptr(text) }.to_str() else {
+        return ERR_INVALID_DATA;
+    };
+
+    let Some(level) = EcLevel::from_code(ec_level) else {
+        return ERR_INVALID_DATA;
+    };
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let renderer = QrRenderer::new(spec.width, spec.height);
+    let buffer_slice = unsafe { std::slice::from_raw_parts_mut(buffer, spec.array_size()) };
+    match renderer.overlay_qr(buffer_slice, text_str, x, y, module_size, quiet_zone, level) {
+        Ok(true) => SUCCESS,
+        Ok(false) => 0,
+        Err(e) => {
+            log::error!("QR overlay failed: {e}");
+            error_to_code(&e)
+        },
+    }
+}
+
+/// Draw an arbitrary-angle line from `(x0, y0)` to `(x1, y1)` onto an
+/// existing 1-bit buffer for the active display spec, using Bresenham's
+/// integer algorithm.
+///
+/// # Safety
+///
+/// The caller must ensure `buffer` is a valid pointer to at least
+/// `array_size` bytes of writable memory for the active display spec.
+///
+/// # Parameters
+///
+/// - `buffer`: Existing 1-bit image buffer to draw onto
+/// - `x0`, `y0`, `x1`, `y1`: Line endpoints, in pixels
+/// - `value`: Pixel value to draw with (0 or 1)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shape_draw_line(
+    buffer: *mut u8,
+    x0: c_int,
+    y0: c_int,
+    x1: c_int,
+    y1: c_int,
+    value: c_int,
+) -> c_int {
+    if buffer.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let shapes = ShapeDrawer::new(spec.width, spec.height);
+    let buffer_slice = unsafe { std::slice::from_raw_parts_mut(buffer, spec.array_size()) };
+    shapes.draw_line(buffer_slice, x0, y0, x1, y1, value != 0);
+    SUCCESS
+}
+
+/// Draw a circle outline centered at `(cx, cy)` with radius `r` onto an
+/// existing 1-bit buffer for the active display spec, using the midpoint
+/// circle algorithm.
+///
+/// # Safety
+///
+/// The caller must ensure `buffer` is a valid pointer to at least
+/// `array_size` bytes of writable memory for the active display spec.
+///
+/// # Parameters
+///
+/// - `buffer`: Existing 1-bit image buffer to draw onto
+/// - `cx`, `cy`: Circle center, in pixels
+/// - `r`: Circle radius, in pixels
+/// - `value`: Pixel value to draw with (0 or 1)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shape_draw_circle(buffer: *mut u8, cx: c_int, cy: c_int, r: c_int, value: c_int) -> c_int {
+    if buffer.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let shapes = ShapeDrawer::new(spec.width, spec.height);
+    let buffer_slice = unsafe { std::slice::from_raw_parts_mut(buffer, spec.array_size()) };
+    shapes.draw_circle(buffer_slice, cx, cy, r, value != 0);
+    SUCCESS
+}
+
+/// Draw an ellipse outline centered at `(cx, cy)` with radii `rx`/`ry`
+/// onto an existing 1-bit buffer for the active display spec, using the
+/// midpoint ellipse algorithm.
+///
+/// # Safety
+///
+/// The caller must ensure `buffer` is a valid pointer to at least
+/// `array_size` bytes of writable memory for the active display spec.
+///
+/// # Parameters
+///
+/// - `buffer`: Existing 1-bit image buffer to draw onto
+/// - `cx`, `cy`: Ellipse center, in pixels
+/// - `rx`, `ry`: Ellipse radii, in pixels
+/// - `value`: Pixel value to draw with (0 or 1)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shape_draw_ellipse(
+    buffer: *mut u8,
+    cx: c_int,
+    cy: c_int,
+    rx: c_int,
+    ry: c_int,
+    value: c_int,
+) -> c_int {
+    if buffer.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let shapes = ShapeDrawer::new(spec.width, spec.height);
+    let buffer_slice = unsafe { std::slice::from_raw_parts_mut(buffer, spec.array_size()) };
+    shapes.draw_ellipse(buffer_slice, cx, cy, rx, ry, value != 0);
+    SUCCESS
+}
+
+/// Composite a `src_width`x`src_height` 1-bit source bitmap onto an
+/// existing 1-bit destination buffer for the active display spec, at
+/// `(x, y)`, pixel by pixel.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `dst` is a valid pointer to at least `array_size` bytes of writable
+///   memory for the active display spec
+/// - `src` is a valid pointer to at least `(src_width.div_ceil(8)) *
+///   src_height` bytes
+///
+/// # Parameters
+///
+/// - `dst`: Destination 1-bit buffer to composite onto
+/// - `src`: Source 1-bit bitmap to composite from
+/// - `src_width`, `src_height`: Dimensions of `src`, in pixels
+/// - `x`, `y`: Destination offset for `src`'s top-left corner, in pixels
+/// - `op`: Compositing operator (0=Copy/blit, 1=Or, 2=And, 3=Xor,
+///   4=`AndNot`/mask-out)
+///
+/// # Returns
+///
+/// - 1 on success
+/// - Negative error code on failure (see error constants)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn buffer_composite(
+    dst: *mut u8,
+    src: *const u8,
+    src_width: c_uint,
+    src_height: c_uint,
+    x: c_int,
+    y: c_int,
+    op: c_int,
+) -> c_int {
+    if dst.is_null() || src.is_null() || src_width == 0 || src_height == 0 {
+        return ERR_INVALID_DATA;
+    }
+
+    let raster_op = match op {
+        0 => RasterOp::Copy,
+        1 => RasterOp::Or,
+        2 => RasterOp::And,
+        3 => RasterOp::Xor,
+        4 => RasterOp::AndNot,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let src_len = (src_width as usize).div_ceil(8) * src_height as usize;
+    let dst_slice = unsafe { std::slice::from_raw_parts_mut(dst, spec.array_size()) };
+    let src_slice = unsafe { std::slice::from_raw_parts(src, src_len) };
+
+    Compositor::composite_at(dst_slice, spec.width, spec.height, src_slice, src_width, src_height, x, y, raster_op);
+    SUCCESS
+}
+
+/// Quantize a grayscale buffer to `bpc` bits per pixel and pack
+/// `8 / bpc` pixels per byte, MSB-first — the FFI-facing multi-depth
+/// analogue of [`convert_png_to_1bit`].
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `gray_data` is a valid pointer to at least `width * height` bytes
+/// - `output_data` is a valid pointer to at least `max_len` bytes of
+///   writable memory
+/// - `out_len` is a valid pointer to writable memory
+/// - All pointers remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `gray_data`: One grayscale byte per pixel, row-major
+/// - `width`, `height`: Dimensions of `gray_data` in pixels
+/// - `bpc`: Bits per pixel to pack to (1, 2, or 4)
+/// - `output_data`: Output buffer for the packed data
+/// - `max_len`: Capacity of `output_data` in bytes
+/// - `out_len`: Output pointer that receives the actual packed length
+///
+/// # Returns
+///
+/// - 1 on success
+/// - `ERR_INVALID_DATA` if `bpc` isn't 1, 2, or 4, `gray_data` doesn't
+///   match `width * height`, or the packed result doesn't fit in `max_len`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn image_pack_nbit(
+    gray_data: *const u8,
+    width: c_uint,
+    height: c_uint,
+    bpc: c_int,
+    output_data: *mut u8,
+    max_len: usize,
+    out_len: *mut usize,
+) -> c_int {
+    if gray_data.is_null() || output_data.is_null() || out_len.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let depth = match bpc {
+        1 => PixelDepth::One,
+        2 => PixelDepth::Two,
+        4 => PixelDepth::Four,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let pixel_count = (width as usize) * (height as usize);
+    let gray_slice = unsafe { std::slice::from_raw_parts(gray_data, pixel_count) };
+    let Some(gray) = GrayImage::from_raw(width, height, gray_slice.to_vec()) else {
+        return ERR_INVALID_DATA;
+    };
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let packed = ImageProcessor::new(spec).pack_nbit(&gray, depth);
+    if packed.len() > max_len {
+        return ERR_INVALID_DATA;
+    }
+    unsafe {
+        ptr::copy_nonoverlapping(packed.as_ptr(), output_data, packed.len());
+        *out_len = packed.len();
+    }
+    SUCCESS
+}
+
+/// Unpack bytes produced by [`image_pack_nbit`] back to one 8-bit luma
+/// value per pixel, expanding each level to the full 0-255 range.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `packed_data` is a valid pointer to at least `packed_len` bytes
+/// - `output_data` is a valid pointer to at least `max_len` bytes of
+///   writable memory
+/// - `out_len` is a valid pointer to writable memory
+/// - All pointers remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `packed_data`: Packed `bpc`-bit data, MSB-first
+/// - `packed_len`: Length of `packed_data` in bytes
+/// - `bpc`: Bits per pixel `packed_data` was packed with (1, 2, or 4)
+/// - `pixel_count`: Number of pixels to unpack
+/// - `output_data`: Output buffer for the unpacked grayscale bytes
+/// - `max_len`: Capacity of `output_data` in bytes
+/// - `out_len`: Output pointer that receives the actual unpacked length
+///
+/// # Returns
+///
+/// - 1 on success
+/// - `ERR_INVALID_DATA` if `bpc` isn't 1, 2, or 4, or the unpacked result
+///   doesn't fit in `max_len`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn image_unpack_nbit(
+    packed_data: *const u8,
+    packed_len: usize,
+    bpc: c_int,
+    pixel_count: usize,
+    output_data: *mut u8,
+    max_len: usize,
+    out_len: *mut usize,
+) -> c_int {
+    if packed_data.is_null() || output_data.is_null() || out_len.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let depth = match bpc {
+        1 => PixelDepth::One,
+        2 => PixelDepth::Two,
+        4 => PixelDepth::Four,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let packed_slice = unsafe { std::slice::from_raw_parts(packed_data, packed_len) };
+    let unpacked = ImageProcessor::new(spec).unpack_nbit(packed_slice, depth, pixel_count);
+    if unpacked.len() > max_len {
+        return ERR_INVALID_DATA;
+    }
+    unsafe {
+        ptr::copy_nonoverlapping(unpacked.as_ptr(), output_data, unpacked.len());
+        *out_len = unpacked.len();
+    }
+    SUCCESS
+}
+
+/// Dither a grayscale buffer down to `bpc` bits per pixel, packed
+/// `8 / bpc` pixels per byte MSB-first — the raw-buffer analogue of
+/// [`display_image_auto`]'s dithering step, generalized past 1-bit output.
+///
+/// # Safety
+///
+/// The caller must ensure:
+/// - `gray_data` is a valid pointer to at least `width * height` bytes
+/// - `output_data` is a valid pointer to at least `max_len` bytes of
+///   writable memory
+/// - `out_len` is a valid pointer to writable memory
+/// - All pointers remain valid for the duration of this call
+///
+/// # Parameters
+///
+/// - `gray_data`: One grayscale byte per pixel, row-major
+/// - `width`, `height`: Dimensions of `gray_data` in pixels
+/// - `dither_mode`: Dither mode (0 = Threshold, 1 = `FloydSteinberg`, 2 =
+///   Ordered, 3 = Atkinson, 4 = Sierra, 5 = `JarvisJudiceNinke`, 6 = Stucki)
+/// - `bpc`: Bits per pixel to dither to (1, 2, or 4)
+/// - `output_data`: Output buffer for the packed, dithered data
+/// - `max_len`: Capacity of `output_data` in bytes
+/// - `out_len`: Output pointer that receives the actual packed length
+///
+/// # Returns
+///
+/// - 1 on success
+/// - `ERR_INVALID_DATA` if `dither_mode` or `bpc` is out of range,
+///   `gray_data` doesn't match `width * height`, or the result doesn't
+///   fit in `max_len`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn image_dither(
+    gray_data: *const u8,
+    width: c_uint,
+    height: c_uint,
+    dither_mode: c_int,
+    bpc: c_int,
+    output_data: *mut u8,
+    max_len: usize,
+    out_len: *mut usize,
+) -> c_int {
+    if gray_data.is_null() || output_data.is_null() || out_len.is_null() {
+        return ERR_INVALID_DATA;
+    }
+
+    let mode = match dither_mode {
+        0 => DitherMode::Threshold,
+        1 => DitherMode::FloydSteinberg,
+        2 => DitherMode::Ordered,
+        3 => DitherMode::Atkinson,
+        4 => DitherMode::Sierra,
+        5 => DitherMode::JarvisJudiceNinke,
+        6 => DitherMode::Stucki,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let depth = match bpc {
+        1 => PixelDepth::One,
+        2 => PixelDepth::Two,
+        4 => PixelDepth::Four,
+        _ => return ERR_INVALID_DATA,
+    };
+
+    let pixel_count = (width as usize) * (height as usize);
+    let gray_slice = unsafe { std::slice::from_raw_parts(gray_data, pixel_count) };
+    let Some(gray) = GrayImage::from_raw(width, height, gray_slice.to_vec()) else {
+        return ERR_INVALID_DATA;
+    };
+
+    let spec = match config::get_default_spec() {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Failed to get default firmware spec: {e}");
+            return error_to_code(&e);
+        },
+    };
+
+    let dithered = ImageProcessor::new(spec).dither(&gray, mode, depth);
+    if dithered.len() > max_len {
+        return ERR_INVALID_DATA;
+    }
+    unsafe {
+        ptr::copy_nonoverlapping(dithered.as_ptr(), output_data, dithered.len());
+        *out_len = dithered.len();
+    }
+    SUCCESS
+}
+
+/// Register (or, with `None`, clear) a callback invoked on display state
+/// transitions and errors: `INITIALIZED`, `BUSY_WAIT_STARTED`,
+/// `BUSY_WAIT_TIMEOUT`, `ENTERED_SLEEP`, `CLEANED_UP` (see
+/// [`crate::notify::event`] for the numeric codes), and `ERROR` alongside
+/// the same error code the failing call itself returned.
+///
+/// # Safety
+///
+/// `cb`, if provided, must be a valid function pointer for as long as it
+/// stays registered and must not panic across the FFI boundary. The
+/// callback is never invoked while this library's internal display mutex
+/// is held, so it may safely call back into this library (for example, to
+/// retry after a `BUSY_WAIT_TIMEOUT`); see [`crate::notify::register`] for
+/// the full re-entrancy and threading contract.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn display_register_callback(cb: Option<notify::Callback>) {
+    notify::register(cb);
+}
+
 /// Initialize the Rust logger for this library.
 ///
 /// # Safety
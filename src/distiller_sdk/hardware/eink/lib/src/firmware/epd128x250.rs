@@ -12,8 +12,50 @@
 //! issues and produces garbled output.
 //!
 //! **Do NOT change these dimensions** - the 128×250 specification is correct and required.
+//!
+//! This firmware's spec sets [`crate::firmware::Rotation::Rotate90`], so
+//! [`crate::image`]/[`crate::image_processing`] callers draw in the
+//! mounted 250×128 landscape orientation and the SDK transposes into this
+//! native 128×250 layout before bit-packing — no manual pixel rotation
+//! needed.
+
+use crate::error::DisplayError;
+use crate::firmware::{CommandSequence, DisplayFirmware, DisplaySpec, RefreshSpeed, Rotation};
+
+/// Expected length of the waveform LUT register, in bytes (the
+/// "Write LUT register" command `0x32` on this SSD1680-family controller).
+pub const LUT_LEN: usize = 159;
+
+/// Placeholder default waveform LUT. This controller's init sequence never
+/// issues `get_lut_sequence`/`load_custom_lut` on its own, so this all-zero
+/// table is never sent to the panel unless a caller opts in; real
+/// deployments should call
+/// [`EPD128x250Firmware::register_temperature_band`] or
+/// [`EPD128x250Firmware::with_lut`] with vendor-measured tables first.
+fn default_lut() -> [u8; LUT_LEN] {
+    [0u8; LUT_LEN]
+}
+
+fn copy_exact(lut: &[u8]) -> Result<[u8; LUT_LEN], DisplayError> {
+    if lut.len() != LUT_LEN {
+        return Err(DisplayError::InvalidDataSize {
+            expected: LUT_LEN,
+            actual: lut.len(),
+        });
+    }
+    let mut out = [0u8; LUT_LEN];
+    out.copy_from_slice(lut);
+    Ok(out)
+}
 
-use crate::firmware::{CommandSequence, DisplayFirmware, DisplaySpec};
+/// A waveform LUT registered for a temperature band, selected by
+/// [`EPD128x250Firmware::get_lut_sequence`].
+#[derive(Debug, Clone)]
+struct TemperatureBand {
+    min_c: i8,
+    max_c: i8,
+    lut: [u8; LUT_LEN],
+}
 
 /// Firmware configuration for EPD128x250 E-ink display.
 ///
@@ -27,6 +69,11 @@ use crate::firmware::{CommandSequence, DisplayFirmware, DisplaySpec};
 /// register values for different display variants of the same controller family.
 pub struct EPD128x250Firmware {
     spec: DisplaySpec,
+    lut: [u8; LUT_LEN],
+    temperature_bands: Vec<TemperatureBand>,
+    speed: RefreshSpeed,
+    medium_speed_lut: Option<[u8; LUT_LEN]>,
+    fast_speed_lut: Option<[u8; LUT_LEN]>,
 }
 
 impl EPD128x250Firmware {
@@ -45,9 +92,150 @@ impl EPD128x250Firmware {
                 height: 250,  // Native orientation height (vendor firmware requirement)
                 name: "EPD128x250".to_string(),
                 description: "EPD128x250 E-ink display (native: 128×250 portrait, mounted: 250×128 landscape)".to_string(),
+                // Mounted rotated 90° from native wiring; see the module
+                // doc comment's "Mounted Orientation" note.
+                rotation: Rotation::Rotate90,
             },
+            lut: default_lut(),
+            temperature_bands: Vec::new(),
+            speed: RefreshSpeed::Normal,
+            medium_speed_lut: None,
+            fast_speed_lut: None,
+        }
+    }
+
+    /// Override the active waveform LUT.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut` is not [`LUT_LEN`]
+    /// bytes.
+    pub fn with_lut(mut self, lut: &[u8]) -> Result<Self, DisplayError> {
+        self.lut = copy_exact(lut)?;
+        Ok(self)
+    }
+
+    /// Register the waveform LUT to use when refreshing at `speed`.
+    /// [`RefreshSpeed::Normal`] overrides the same LUT [`Self::with_lut`]
+    /// sets; `Medium`/`Fast` fall back to it until registered here.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut` is not [`LUT_LEN`]
+    /// bytes.
+    pub fn with_speed_lut(mut self, speed: RefreshSpeed, lut: &[u8]) -> Result<Self, DisplayError> {
+        let lut = copy_exact(lut)?;
+        match speed {
+            RefreshSpeed::Normal => self.lut = lut,
+            RefreshSpeed::Medium => self.medium_speed_lut = Some(lut),
+            RefreshSpeed::Fast => self.fast_speed_lut = Some(lut),
+        }
+        Ok(self)
+    }
+
+    /// The waveform LUT active for `speed`, falling back to the default
+    /// (or [`Self::with_lut`]-overridden) LUT if no speed-specific table
+    /// was registered via [`Self::with_speed_lut`].
+    fn lut_for_speed(&self, speed: RefreshSpeed) -> &[u8; LUT_LEN] {
+        match speed {
+            RefreshSpeed::Normal => &self.lut,
+            RefreshSpeed::Medium => self.medium_speed_lut.as_ref().unwrap_or(&self.lut),
+            RefreshSpeed::Fast => self.fast_speed_lut.as_ref().unwrap_or(&self.lut),
         }
     }
+
+    /// Register a waveform LUT to use when the panel temperature falls in
+    /// `min_c..=max_c`, so [`Self::get_lut_sequence`] can later pick it
+    /// automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut` is not [`LUT_LEN`]
+    /// bytes.
+    pub fn register_temperature_band(
+        &mut self,
+        min_c: i8,
+        max_c: i8,
+        lut: &[u8],
+    ) -> Result<(), DisplayError> {
+        let lut = copy_exact(lut)?;
+        self.temperature_bands.push(TemperatureBand { min_c, max_c, lut });
+        Ok(())
+    }
+
+    /// Build the sequence that selects the on-chip temperature sensor and
+    /// reads it back via command `0x18`, the same register the init
+    /// sequence already uses to select the internal sensor.
+    ///
+    /// Reading the response byte back over SPI is the caller's
+    /// responsibility — this crate's `SpiController` is write-only, so the
+    /// raw reading must come from a lower-level path outside this firmware
+    /// type; pass the resulting byte to [`Self::parse_temperature_reading`].
+    #[must_use]
+    pub fn get_temperature_sequence(&self) -> CommandSequence {
+        CommandSequence::new()
+            .cmd(0x18) // Temperature Sensor Selection
+            .data(0x80) // Use the internal sensor
+            .check_status()
+    }
+
+    /// Convert a raw temperature-sensor byte (as read back after
+    /// [`Self::get_temperature_sequence`]) to degrees Celsius.
+    #[must_use]
+    pub const fn parse_temperature_reading(raw: u8) -> i8 {
+        raw as i8
+    }
+
+    /// Build the sequence that writes the waveform LUT appropriate for
+    /// `temp_c` via the "Write LUT register" command `0x32`. Falls back to
+    /// the active (default or manually-overridden) LUT if no temperature
+    /// band registered via [`Self::register_temperature_band`] covers
+    /// `temp_c`.
+    #[must_use]
+    pub fn get_lut_sequence(&self, temp_c: i8) -> CommandSequence {
+        let lut = self
+            .temperature_bands
+            .iter()
+            .find(|band| (band.min_c..=band.max_c).contains(&temp_c))
+            .map_or(&self.lut, |band| &band.lut);
+
+        let mut seq = CommandSequence::new().cmd(0x32);
+        for &byte in lut {
+            seq = seq.data(byte);
+        }
+        seq
+    }
+
+    /// Recommended SPI bus tuning for this panel: this controller tolerates
+    /// the full 40MHz `spidev` clock reliably at the cable lengths this
+    /// variant ships with.
+    #[must_use]
+    pub const fn recommended_spi_config() -> crate::hardware::SpiTuning {
+        crate::hardware::SpiTuning {
+            speed_hz: 40_000_000,
+            mode: 0,
+            max_chunk: 4096,
+            chunk_delay_us: 100,
+        }
+    }
+
+    /// Build the sequence that uploads a caller-supplied waveform LUT
+    /// verbatim via the "Write LUT register" command `0x32`, for
+    /// integrators who want to tune fast-partial vs. high-quality refresh
+    /// profiles without touching the init sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut` is not [`LUT_LEN`]
+    /// bytes.
+    pub fn load_custom_lut(&self, lut: &[u8]) -> Result<CommandSequence, DisplayError> {
+        let lut = copy_exact(lut)?;
+        let mut seq = CommandSequence::new().cmd(0x32);
+        for byte in lut {
+            seq = seq.data(byte);
+        }
+        Ok(seq)
+    }
 }
 
 impl DisplayFirmware for EPD128x250Firmware {
@@ -110,7 +298,9 @@ impl DisplayFirmware for EPD128x250Firmware {
 
     fn get_update_sequence(&self, is_partial: bool) -> CommandSequence {
         if is_partial {
-            CommandSequence::new()
+            // Fast partial refreshes for UI updates select a leaner
+            // waveform; full refreshes always use the normal-quality LUT.
+            self.get_speed_lut_sequence(self.speed)
                 .cmd(0x22) // Display Update Control
                 .data(0xFF)
                 .cmd(0x20) // Activate Display Update Sequence
@@ -134,6 +324,22 @@ impl DisplayFirmware for EPD128x250Firmware {
     fn get_write_ram_command(&self) -> u8 {
         0x24 // Write RAM command
     }
+
+    fn set_refresh_speed(&mut self, speed: RefreshSpeed) {
+        self.speed = speed;
+    }
+
+    fn get_speed_lut_sequence(&self, speed: RefreshSpeed) -> CommandSequence {
+        let lut = self.lut_for_speed(speed);
+        if *lut == default_lut() {
+            return CommandSequence::new();
+        }
+        let mut seq = CommandSequence::new().cmd(0x32);
+        for &byte in lut {
+            seq = seq.data(byte);
+        }
+        seq
+    }
 }
 
 impl Default for EPD128x250Firmware {
@@ -0,0 +1,472 @@
+//! Firmware implementation for the 240x416 e-ink display.
+
+use crate::error::DisplayError;
+use crate::firmware::{CommandSequence, DisplayFirmware, DisplaySpec, RefreshSpeed, Rotation};
+
+/// Expected length of the 4-gray waveform LUT, in bytes.
+pub const LUT_4G_LEN: usize = 216;
+/// Expected length of each partial-update waveform LUT, in bytes.
+pub const LUT_PARTIAL_LEN: usize = 42;
+
+/// A full set of waveform LUTs: one 4-gray LUT plus the five partial-update
+/// LUTs (VCOM, WW, BW, WB, BB), so a whole waveform can be swapped in one
+/// call instead of one register at a time.
+#[derive(Debug, Clone)]
+pub struct WaveformLuts {
+    lut_4g: [u8; LUT_4G_LEN],
+    lut_vcom: [u8; LUT_PARTIAL_LEN],
+    lut_ww: [u8; LUT_PARTIAL_LEN],
+    lut_bw: [u8; LUT_PARTIAL_LEN],
+    lut_wb: [u8; LUT_PARTIAL_LEN],
+    lut_bb: [u8; LUT_PARTIAL_LEN],
+}
+
+impl WaveformLuts {
+    /// Build a LUT set from slices, validating that each is exactly the
+    /// length the controller expects.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut_4g` is not
+    /// [`LUT_4G_LEN`] bytes, or any partial LUT is not [`LUT_PARTIAL_LEN`]
+    /// bytes.
+    pub fn new(
+        lut_4g: &[u8],
+        lut_vcom: &[u8],
+        lut_ww: &[u8],
+        lut_bw: &[u8],
+        lut_wb: &[u8],
+        lut_bb: &[u8],
+    ) -> Result<Self, DisplayError> {
+        Ok(Self {
+            lut_4g: copy_exact(lut_4g, LUT_4G_LEN)?,
+            lut_vcom: copy_exact(lut_vcom, LUT_PARTIAL_LEN)?,
+            lut_ww: copy_exact(lut_ww, LUT_PARTIAL_LEN)?,
+            lut_bw: copy_exact(lut_bw, LUT_PARTIAL_LEN)?,
+            lut_wb: copy_exact(lut_wb, LUT_PARTIAL_LEN)?,
+            lut_bb: copy_exact(lut_bb, LUT_PARTIAL_LEN)?,
+        })
+    }
+}
+
+fn copy_exact<const N: usize>(slice: &[u8], expected: usize) -> Result<[u8; N], DisplayError> {
+    if slice.len() != expected {
+        return Err(DisplayError::InvalidDataSize {
+            expected,
+            actual: slice.len(),
+        });
+    }
+    let mut out = [0u8; N];
+    out.copy_from_slice(slice);
+    Ok(out)
+}
+
+/// Build the sequence that writes `luts`' five partial-update LUTs (VCOM,
+/// WW, BW, WB, BB) to registers `0x20`-`0x24`, shared by
+/// [`EPD240x416Firmware::get_lut_sequence`] and `get_speed_lut_sequence`.
+fn write_luts_sequence(luts: &WaveformLuts) -> CommandSequence {
+    let mut seq = CommandSequence::new().cmd(0x20); // VCOM LUT
+    for &byte in &luts.lut_vcom {
+        seq = seq.data(byte);
+    }
+
+    seq = seq.cmd(0x21); // WW LUT
+    for &byte in &luts.lut_ww {
+        seq = seq.data(byte);
+    }
+
+    seq = seq.cmd(0x22); // BW LUT
+    for &byte in &luts.lut_bw {
+        seq = seq.data(byte);
+    }
+
+    seq = seq.cmd(0x23); // WB LUT
+    for &byte in &luts.lut_wb {
+        seq = seq.data(byte);
+    }
+
+    seq = seq.cmd(0x24); // BB LUT
+    for &byte in &luts.lut_bb {
+        seq = seq.data(byte);
+    }
+
+    seq
+}
+
+/// A waveform registered for a temperature band, selected by
+/// [`EPD240x416Firmware::set_waveform_for_temperature`].
+#[derive(Debug, Clone)]
+struct TemperatureBand {
+    min_c: i8,
+    max_c: i8,
+    luts: WaveformLuts,
+}
+
+/// Firmware configuration for the 240x416 E-ink display.
+pub struct EPD240x416Firmware {
+    spec: DisplaySpec,
+    luts: WaveformLuts,
+    temperature_bands: Vec<TemperatureBand>,
+    speed: RefreshSpeed,
+    medium_speed_luts: Option<WaveformLuts>,
+    fast_speed_luts: Option<WaveformLuts>,
+}
+
+impl EPD240x416Firmware {
+    /// Create a new `EPD240x416` firmware instance with the built-in
+    /// room-temperature waveform.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            spec: DisplaySpec {
+                width: 240,
+                height: 416,
+                name: "EPD240x416".to_string(),
+                description: "EPD240x416 E-ink display (240x416)".to_string(),
+                rotation: Rotation::Rotate0,
+            },
+            luts: default_luts(),
+            temperature_bands: Vec::new(),
+            speed: RefreshSpeed::Normal,
+            medium_speed_luts: None,
+            fast_speed_luts: None,
+        }
+    }
+
+    /// Override the active 4-gray waveform LUT.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut` is not
+    /// [`LUT_4G_LEN`] bytes.
+    pub fn with_lut_4g(mut self, lut: &[u8]) -> Result<Self, DisplayError> {
+        self.luts.lut_4g = copy_exact(lut, LUT_4G_LEN)?;
+        Ok(self)
+    }
+
+    /// Override the active set of partial-update waveform LUTs (VCOM, WW,
+    /// BW, WB, BB, in that order).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if any LUT is not
+    /// [`LUT_PARTIAL_LEN`] bytes.
+    pub fn with_partial_luts(
+        mut self,
+        vcom: &[u8],
+        ww: &[u8],
+        bw: &[u8],
+        wb: &[u8],
+        bb: &[u8],
+    ) -> Result<Self, DisplayError> {
+        self.luts.lut_vcom = copy_exact(vcom, LUT_PARTIAL_LEN)?;
+        self.luts.lut_ww = copy_exact(ww, LUT_PARTIAL_LEN)?;
+        self.luts.lut_bw = copy_exact(bw, LUT_PARTIAL_LEN)?;
+        self.luts.lut_wb = copy_exact(wb, LUT_PARTIAL_LEN)?;
+        self.luts.lut_bb = copy_exact(bb, LUT_PARTIAL_LEN)?;
+        Ok(self)
+    }
+
+    /// Register a waveform to use when the panel temperature falls in
+    /// `min_c..=max_c`, so [`Self::set_waveform_for_temperature`] can later
+    /// pick it automatically.
+    pub fn register_temperature_band(&mut self, min_c: i8, max_c: i8, luts: WaveformLuts) {
+        self.temperature_bands.push(TemperatureBand { min_c, max_c, luts });
+    }
+
+    /// Activate the waveform registered for the band containing `temp_c`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if no registered band covers `temp_c`.
+    pub fn set_waveform_for_temperature(&mut self, temp_c: i8) -> Result<(), DisplayError> {
+        let band = self
+            .temperature_bands
+            .iter()
+            .find(|band| (band.min_c..=band.max_c).contains(&temp_c))
+            .ok_or_else(|| {
+                DisplayError::Config(format!("No waveform registered for {temp_c}C"))
+            })?;
+
+        self.luts = band.luts.clone();
+        Ok(())
+    }
+
+    /// Build the sequence that enables the controller's on-chip temperature
+    /// sensor (TSE) and reads it back via command `0x40`.
+    ///
+    /// Reading the response byte back over SPI is the caller's
+    /// responsibility — this crate's `SpiController` is write-only, so the
+    /// raw reading must come from a lower-level path outside this firmware
+    /// type; pass the resulting byte to [`Self::parse_temperature_reading`].
+    #[must_use]
+    pub fn get_temperature_sequence(&self) -> CommandSequence {
+        CommandSequence::new()
+            .cmd(0x40) // Temperature Sensor Selection
+            .data(0x80) // TSE: enable the on-chip sensor
+            .check_status()
+    }
+
+    /// Convert a raw temperature-sensor byte (as read back after
+    /// [`Self::get_temperature_sequence`]) to degrees Celsius.
+    #[must_use]
+    pub const fn parse_temperature_reading(raw: u8) -> i8 {
+        raw as i8
+    }
+
+    /// Build the partial-window command (`0x90`) sequence that restricts the
+    /// next RAM write and refresh to the rectangle `(x, y, w, h)`, instead of
+    /// touching the full 240x416 area.
+    ///
+    /// `x` and `w` are rounded to the panel's 8-pixel byte boundary, since
+    /// the controller addresses RAM in whole bytes along the X axis:
+    /// `x` is rounded down, and the window's right edge is rounded up.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if the requested rectangle
+    /// falls outside the panel's `DisplaySpec` bounds.
+    pub fn get_partial_window_sequence(
+        &self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<CommandSequence, DisplayError> {
+        let width = self.spec.width as u16;
+        let height = self.spec.height as u16;
+
+        if w == 0 || h == 0 || x >= width || y >= height {
+            return Err(DisplayError::InvalidDataSize {
+                expected: 0,
+                actual: 0,
+            });
+        }
+
+        let x_end = x.saturating_add(w);
+        let y_end = y.saturating_add(h);
+        if x_end > width || y_end > height {
+            return Err(DisplayError::InvalidDataSize {
+                expected: 0,
+                actual: 0,
+            });
+        }
+
+        // Byte-align X to the panel's 8-pixel addressing granularity: start
+        // rounds down, end rounds up, matching how `validate_image_size`
+        // elsewhere in this crate treats `width / 8` RAM rows.
+        let x_start_byte = (x / 8) as u8;
+        let x_end_byte = ((x_end - 1) / 8) as u8;
+        let y_start = y;
+        let y_end_inclusive = y_end - 1;
+
+        Ok(CommandSequence::new()
+            .cmd(0x90)
+            .data(x_start_byte)
+            .data(x_end_byte)
+            .data((y_start & 0xFF) as u8)
+            .data((y_start >> 8) as u8)
+            .data((y_end_inclusive & 0xFF) as u8)
+            .data((y_end_inclusive >> 8) as u8)
+            .data(0x01)) // Gates scan both inside and outside of the window
+    }
+
+    /// Build the sequence that writes the partial-update LUTs (VCOM, WW,
+    /// BW, WB, BB) appropriate for `temp_c` to registers `0x20`-`0x24`.
+    /// Falls back to the active (default or manually-overridden) waveform
+    /// if no temperature band registered via
+    /// [`Self::register_temperature_band`] covers `temp_c`.
+    #[must_use]
+    pub fn get_lut_sequence(&self, temp_c: i8) -> CommandSequence {
+        let luts = self
+            .temperature_bands
+            .iter()
+            .find(|band| (band.min_c..=band.max_c).contains(&temp_c))
+            .map_or(&self.luts, |band| &band.luts);
+
+        write_luts_sequence(luts)
+    }
+
+    /// Register the waveform to use when refreshing at `speed`.
+    /// [`RefreshSpeed::Normal`] overrides the same waveform
+    /// [`Self::with_lut_4g`]/[`Self::with_partial_luts`] set; `Medium`/`Fast`
+    /// fall back to it until registered here.
+    pub fn with_speed_waveform(mut self, speed: RefreshSpeed, luts: WaveformLuts) -> Self {
+        match speed {
+            RefreshSpeed::Normal => self.luts = luts,
+            RefreshSpeed::Medium => self.medium_speed_luts = Some(luts),
+            RefreshSpeed::Fast => self.fast_speed_luts = Some(luts),
+        }
+        self
+    }
+
+    /// The waveform active for `speed`, falling back to the default (or
+    /// temperature-compensated) waveform if no speed-specific one was
+    /// registered via [`Self::with_speed_waveform`].
+    fn luts_for_speed(&self, speed: RefreshSpeed) -> &WaveformLuts {
+        match speed {
+            RefreshSpeed::Normal => &self.luts,
+            RefreshSpeed::Medium => self.medium_speed_luts.as_ref().unwrap_or(&self.luts),
+            RefreshSpeed::Fast => self.fast_speed_luts.as_ref().unwrap_or(&self.luts),
+        }
+    }
+
+    /// Build the sequence that uploads a caller-supplied 4-gray waveform
+    /// LUT verbatim via command `0x20`, for integrators who want to tune
+    /// fast-partial vs. high-quality refresh profiles without touching the
+    /// init sequence or going through [`Self::register_temperature_band`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if `lut` is not
+    /// [`LUT_4G_LEN`] bytes.
+    pub fn load_custom_lut(&self, lut: &[u8]) -> Result<CommandSequence, DisplayError> {
+        let lut = copy_exact::<LUT_4G_LEN>(lut, LUT_4G_LEN)?;
+        let mut seq = CommandSequence::new().cmd(0x20);
+        for byte in lut {
+            seq = seq.data(byte);
+        }
+        Ok(seq)
+    }
+
+    /// Build the sequence that writes the active 4-gray waveform LUT.
+    #[must_use]
+    pub fn get_gray4_lut_sequence(&self) -> CommandSequence {
+        let mut seq = CommandSequence::new().cmd(0x20);
+        for &byte in &self.luts.lut_4g {
+            seq = seq.data(byte);
+        }
+        seq
+    }
+
+    /// Recommended SPI bus tuning for this panel: the larger 240x416 RAM
+    /// write is more sensitive to signal integrity over longer cable runs,
+    /// so this variant recommends a lower clock than the 128x250's full
+    /// 40MHz.
+    #[must_use]
+    pub const fn recommended_spi_config() -> crate::hardware::SpiTuning {
+        crate::hardware::SpiTuning {
+            speed_hz: 20_000_000,
+            mode: 0,
+            max_chunk: 4096,
+            chunk_delay_us: 100,
+        }
+    }
+}
+
+/// Built-in room-temperature waveform, baked in as the default before any
+/// `with_lut_4g`/`with_partial_luts` override or `set_waveform_for_temperature`
+/// selection.
+fn default_luts() -> WaveformLuts {
+    WaveformLuts {
+        lut_4g: [
+            0x01, 0x05, 0x20, 0x19, 0x0A, 0x01, 0x01, 0x05, 0x0A, 0x01, 0x0A, 0x01, 0x01, 0x01,
+            0x05, 0x09, 0x02, 0x03, 0x04, 0x01, 0x01, 0x01, 0x04, 0x04, 0x02, 0x00, 0x01, 0x01,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x01, 0x05, 0x20, 0x19, 0x0A, 0x01, 0x01, 0x05, 0x4A, 0x01, 0x8A, 0x01, 0x01, 0x01,
+            0x05, 0x49, 0x02, 0x83, 0x84, 0x01, 0x01, 0x01, 0x84, 0x84, 0x82, 0x00, 0x01, 0x01,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x01, 0x05, 0x20, 0x99, 0x8A, 0x01, 0x01, 0x05, 0x4A, 0x01, 0x8A, 0x01, 0x01, 0x01,
+            0x05, 0x49, 0x82, 0x03, 0x04, 0x01, 0x01, 0x01, 0x04, 0x04, 0x02, 0x00, 0x01, 0x01,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x01, 0x85, 0x20, 0x99, 0x0A, 0x01, 0x01, 0x05, 0x4A, 0x01, 0x8A, 0x01, 0x01, 0x01,
+            0x05, 0x49, 0x02, 0x83, 0x04, 0x01, 0x01, 0x01, 0x04, 0x04, 0x02, 0x00, 0x01, 0x01,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x01, 0x85, 0xA0, 0x99, 0x0A, 0x01, 0x01, 0x05, 0x4A, 0x01, 0x8A, 0x01, 0x01, 0x01,
+            0x05, 0x49, 0x02, 0x43, 0x04, 0x01, 0x01, 0x01, 0x04, 0x04, 0x42, 0x00, 0x01, 0x01,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x09, 0x10, 0x3F, 0x3F, 0x00, 0x0B,
+        ],
+        lut_vcom: [
+            0x01, 0x0a, 0x0a, 0x0a, 0x0a, 0x01, 0x01, 0x02, 0x0f, 0x01, 0x0f, 0x01, 0x01, 0x01,
+            0x01, 0x0a, 0x00, 0x0a, 0x00, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        lut_ww: [
+            0x01, 0x4a, 0x4a, 0x0a, 0x0a, 0x01, 0x01, 0x02, 0x8f, 0x01, 0x4f, 0x01, 0x01, 0x01,
+            0x01, 0x8a, 0x00, 0x8a, 0x00, 0x01, 0x01, 0x01, 0x80, 0x00, 0x80, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        lut_bw: [
+            0x01, 0x4a, 0x4a, 0x0a, 0x0a, 0x01, 0x01, 0x02, 0x8f, 0x01, 0x4f, 0x01, 0x01, 0x01,
+            0x01, 0x8a, 0x00, 0x8a, 0x00, 0x01, 0x01, 0x01, 0x80, 0x00, 0x80, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        lut_wb: [
+            0x01, 0x0a, 0x0a, 0x8a, 0x8a, 0x01, 0x01, 0x02, 0x8f, 0x01, 0x4f, 0x01, 0x01, 0x01,
+            0x01, 0x4a, 0x00, 0x4a, 0x00, 0x01, 0x01, 0x01, 0x40, 0x00, 0x40, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        lut_bb: [
+            0x01, 0x0a, 0x0a, 0x8a, 0x8a, 0x01, 0x01, 0x02, 0x8f, 0x01, 0x4f, 0x01, 0x01, 0x01,
+            0x01, 0x4a, 0x00, 0x4a, 0x00, 0x01, 0x01, 0x01, 0x40, 0x00, 0x40, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+    }
+}
+
+impl DisplayFirmware for EPD240x416Firmware {
+    fn get_spec(&self) -> &DisplaySpec {
+        &self.spec
+    }
+
+    fn get_init_sequence(&self) -> CommandSequence {
+        CommandSequence::new()
+            .cmd(0x04) // Power on
+            .check_status()
+            .cmd(0x50) // VCOM and data interval setting
+            .data(0x97)
+    }
+
+    fn get_partial_init_sequence(&self) -> CommandSequence {
+        CommandSequence::new()
+            .cmd(0x04) // Power on
+            .check_status()
+            .cmd(0xE0)
+            .data(0x02)
+            .cmd(0xE5)
+            .data(0x6E)
+            .cmd(0x50)
+            .data(0xD7)
+    }
+
+    fn get_update_sequence(&self, is_partial: bool) -> CommandSequence {
+        // Fast partial refreshes for UI updates select a leaner waveform;
+        // full refreshes always use the normal-quality LUT.
+        let seq = if is_partial {
+            self.get_speed_lut_sequence(self.speed)
+        } else {
+            CommandSequence::new()
+        };
+
+        seq.cmd(0x12) // Display refresh
+            .delay(1)
+            .check_status()
+    }
+
+    fn get_sleep_sequence(&self) -> CommandSequence {
+        CommandSequence::new()
+            .cmd(0x02) // Power off
+            .check_status()
+            .cmd(0x07) // Deep sleep
+            .data(0xA5)
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        0x13
+    }
+
+    fn set_refresh_speed(&mut self, speed: RefreshSpeed) {
+        self.speed = speed;
+    }
+
+    fn get_speed_lut_sequence(&self, speed: RefreshSpeed) -> CommandSequence {
+        write_luts_sequence(self.luts_for_speed(speed))
+    }
+}
+
+impl Default for EPD240x416Firmware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
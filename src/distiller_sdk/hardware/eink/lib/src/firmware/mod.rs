@@ -0,0 +1,303 @@
+//! Display firmware module containing hardware-specific implementations for
+//! various e-ink displays.
+
+use crate::error::DisplayError;
+
+pub mod epd128x250;
+pub mod epd240x416;
+
+pub use epd128x250::EPD128x250Firmware;
+pub use epd240x416::EPD240x416Firmware;
+
+/// Display specifications
+#[derive(Debug, Clone)]
+pub struct DisplaySpec {
+    /// Native panel width in pixels, as wired to the controller's RAM X/Y
+    /// address registers. Bit-packing (see [`Self::array_size`]) always
+    /// targets this orientation, regardless of [`Self::rotation`].
+    pub width: u32,
+    /// Native panel height in pixels. See [`Self::width`].
+    pub height: u32,
+    /// Display model name
+    pub name: String,
+    /// Display description
+    pub description: String,
+    /// How the native panel is physically mounted relative to the
+    /// orientation callers draw in. [`Self::logical_width`]/
+    /// [`Self::logical_height`] report the rotated, user-facing
+    /// dimensions; `width`/`height` and [`Self::array_size`] always stay
+    /// in the native, unrotated orientation the controller expects.
+    pub rotation: Rotation,
+}
+
+impl DisplaySpec {
+    /// Calculate the required array size in bytes for 1-bit image data,
+    /// in the native (unrotated) orientation.
+    #[must_use]
+    pub fn array_size(&self) -> usize {
+        ((self.width * self.height) / 8) as usize
+    }
+
+    /// Width an app should draw in, after accounting for [`Self::rotation`].
+    #[must_use]
+    pub const fn logical_width(&self) -> u32 {
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => self.width,
+            Rotation::Rotate90 | Rotation::Rotate270 => self.height,
+        }
+    }
+
+    /// Height an app should draw in, after accounting for [`Self::rotation`].
+    #[must_use]
+    pub const fn logical_height(&self) -> u32 {
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => self.height,
+            Rotation::Rotate90 | Rotation::Rotate270 => self.width,
+        }
+    }
+}
+
+/// Command sequence for display operations
+#[derive(Debug, Clone, Default)]
+pub struct CommandSequence {
+    /// List of commands to execute
+    pub commands: Vec<Command>,
+}
+
+/// Display command types
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Write a command byte to the display
+    WriteCommand(u8),
+    /// Write a data byte to the display
+    WriteData(u8),
+    /// Delay for specified milliseconds
+    Delay(u64),
+    /// Check the display busy status
+    CheckStatus,
+    /// Reset the display hardware
+    Reset,
+}
+
+impl CommandSequence {
+    /// Create a new empty command sequence
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a command byte to the sequence
+    #[must_use]
+    pub fn cmd(mut self, command: u8) -> Self {
+        self.commands.push(Command::WriteCommand(command));
+        self
+    }
+
+    /// Add a data byte to the sequence
+    #[must_use]
+    pub fn data(mut self, data: u8) -> Self {
+        self.commands.push(Command::WriteData(data));
+        self
+    }
+
+    /// Add a delay to the sequence
+    #[must_use]
+    pub fn delay(mut self, ms: u64) -> Self {
+        self.commands.push(Command::Delay(ms));
+        self
+    }
+
+    /// Add a status check to the sequence
+    #[must_use]
+    pub fn check_status(mut self) -> Self {
+        self.commands.push(Command::CheckStatus);
+        self
+    }
+
+    /// Add a reset command to the sequence
+    #[must_use]
+    pub fn reset(mut self) -> Self {
+        self.commands.push(Command::Reset);
+        self
+    }
+}
+
+/// How a panel's native (wired) orientation is rotated to reach the
+/// orientation an app actually draws in — mirrors ili9341's `Orientation`
+/// enum. For example, `EPD128x250Firmware` is wired native-portrait
+/// (128×250) but mounted rotated 90° so users see 250×128 landscape;
+/// [`DisplaySpec::logical_width`]/[`DisplaySpec::logical_height`] report
+/// the latter, while bit-packing still targets the former.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// No rotation: logical and native orientation match.
+    #[default]
+    Rotate0,
+    /// Native panel rotated 90° clockwise to reach the logical orientation.
+    Rotate90,
+    /// Native panel rotated 180° to reach the logical orientation.
+    Rotate180,
+    /// Native panel rotated 270° clockwise (90° counter-clockwise) to
+    /// reach the logical orientation.
+    Rotate270,
+}
+
+/// Panel refresh-speed preset, trading image quality/ghosting for speed —
+/// mirrors the uc8151 driver's `LUT` enum (`Normal`/`Medium`/`Fast`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RefreshSpeed {
+    /// Full-quality waveform, heaviest ghosting suppression, slowest refresh.
+    #[default]
+    Normal,
+    /// Shorter waveform; some visible ghosting, noticeably faster.
+    Medium,
+    /// Shortest waveform; most ghosting — best for high-frequency UI updates.
+    Fast,
+}
+
+/// Firmware interface trait - implement this for different display variants
+///
+/// Requires `Send + Sync` so firmware values can be boxed into a
+/// [`crate::display::DisplayDriver`] behind the global display state's
+/// mutex.
+pub trait DisplayFirmware: Send + Sync {
+    /// Get the display specifications
+    fn get_spec(&self) -> &DisplaySpec;
+    /// Get the initialization command sequence
+    fn get_init_sequence(&self) -> CommandSequence;
+    /// Get the partial update initialization sequence
+    fn get_partial_init_sequence(&self) -> CommandSequence;
+    /// Get the display update sequence
+    fn get_update_sequence(&self, is_partial: bool) -> CommandSequence;
+    /// Get the sleep mode sequence
+    fn get_sleep_sequence(&self) -> CommandSequence;
+    /// Get the write RAM command byte
+    fn get_write_ram_command(&self) -> u8;
+
+    /// Get the hardware reset sequence
+    fn get_reset_sequence(&self) -> CommandSequence {
+        CommandSequence::new().reset().delay(10)
+    }
+
+    /// Get the command sequence that programs the controller's RAM X/Y
+    /// address window and cursor to a sub-rectangle, ahead of a windowed
+    /// partial update.
+    ///
+    /// `x` and `w` are in pixels but must already be aligned to 8-pixel
+    /// (one byte) boundaries, matching the SSD16xx-family register layout
+    /// used by the built-in firmware variants.
+    fn get_window_sequence(&self, x: u32, y: u32, w: u32, h: u32) -> CommandSequence {
+        let x_start_byte = (x / 8) as u8;
+        let x_end_byte = ((x + w) / 8 - 1) as u8;
+        let y_start = y;
+        let y_end = y + h - 1;
+
+        CommandSequence::new()
+            // Set Ram-X address start/end position
+            .cmd(0x44)
+            .data(x_start_byte)
+            .data(x_end_byte)
+            // Set Ram-Y address start/end position
+            .cmd(0x45)
+            .data((y_start % 256) as u8)
+            .data((y_start / 256) as u8)
+            .data((y_end % 256) as u8)
+            .data((y_end / 256) as u8)
+            // Set RAM x address count
+            .cmd(0x4E)
+            .data(x_start_byte)
+            // Set RAM y address count
+            .cmd(0x4F)
+            .data((y_start % 256) as u8)
+            .data((y_start / 256) as u8)
+    }
+
+    /// Get the initialization sequence for 4-gray (2bpp) mode, if this
+    /// firmware supports it.
+    fn get_gray4_init_sequence(&self) -> Option<CommandSequence> {
+        None
+    }
+
+    /// Get the waveform LUT sequence for 4-gray mode, if this firmware
+    /// supports it.
+    fn get_gray4_lut_sequence(&self) -> Option<CommandSequence> {
+        None
+    }
+
+    /// Get the `(old_plane_command, new_plane_command)` RAM write command
+    /// pair used to stream the two 1bpp bitplanes of a 4-gray frame, if this
+    /// firmware supports it.
+    fn get_gray4_plane_commands(&self) -> Option<(u8, u8)> {
+        None
+    }
+
+    /// Select the waveform LUT [`Self::get_speed_lut_sequence`] writes for
+    /// subsequent updates. Firmware variants that only ship one waveform
+    /// may ignore this and always refresh at [`RefreshSpeed::Normal`].
+    fn set_refresh_speed(&mut self, speed: RefreshSpeed) {
+        let _ = speed;
+    }
+
+    /// Get the command sequence that writes the waveform LUT for `speed`
+    /// to the controller's "Write LUT register" (command `0x32` on the
+    /// SSD16xx family used here). Returns an empty sequence if this
+    /// firmware has no per-speed waveform data.
+    fn get_speed_lut_sequence(&self, speed: RefreshSpeed) -> CommandSequence {
+        let _ = speed;
+        CommandSequence::new()
+    }
+
+    /// Validate that image data is the correct size
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::InvalidDataSize` if the data size doesn't match
+    fn validate_image_size(&self, data: &[u8]) -> Result<(), DisplayError> {
+        let expected_size = self.get_spec().array_size();
+        if data.len() != expected_size {
+            return Err(DisplayError::InvalidDataSize {
+                expected: expected_size,
+                actual: data.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+// Object-safe erasure so a firmware variant registered at runtime (see
+// crate::firmware_descriptor::register_descriptor) can be held generically by
+// GenericEinkProtocol, instead of requiring a concrete, compile-time type
+// added to ConfigurableProtocol and all its match arms.
+impl DisplayFirmware for Box<dyn DisplayFirmware> {
+    fn get_spec(&self) -> &DisplaySpec {
+        (**self).get_spec()
+    }
+
+    fn get_init_sequence(&self) -> CommandSequence {
+        (**self).get_init_sequence()
+    }
+
+    fn get_partial_init_sequence(&self) -> CommandSequence {
+        (**self).get_partial_init_sequence()
+    }
+
+    fn get_update_sequence(&self, is_partial: bool) -> CommandSequence {
+        (**self).get_update_sequence(is_partial)
+    }
+
+    fn get_sleep_sequence(&self) -> CommandSequence {
+        (**self).get_sleep_sequence()
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        (**self).get_write_ram_command()
+    }
+
+    fn set_refresh_speed(&mut self, speed: RefreshSpeed) {
+        (**self).set_refresh_speed(speed);
+    }
+
+    fn get_speed_lut_sequence(&self, speed: RefreshSpeed) -> CommandSequence {
+        (**self).get_speed_lut_sequence(speed)
+    }
+}
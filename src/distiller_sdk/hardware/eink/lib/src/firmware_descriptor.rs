@@ -0,0 +1,462 @@
+//! Data-driven firmware descriptor format, parser, and runtime registry.
+//!
+//! Every built-in display variant (e.g. [`crate::firmware::EPD128x250Firmware`])
+//! is a hand-written Rust struct implementing [`DisplayFirmware`], which
+//! means adding a new panel in the same controller family requires
+//! recompiling. This module lets a panel's spec and command sequences be
+//! described in an external binary file instead, parsed at runtime into a
+//! [`FirmwareDescriptor`] and registered under a name so
+//! [`crate::config::set_default_firmware_from_str`] can resolve it
+//! alongside the compiled-in [`crate::config::FirmwareType`] variants.
+//!
+//! # Binary format
+//!
+//! ```text
+//! magic:       4 bytes, b"DEFW"
+//! version:     u8
+//! width:       u16 (LE, must be a multiple of 8)
+//! height:      u16 (LE)
+//! name_len:    u8
+//! name:        [u8; name_len], UTF-8
+//! records:     zero or more Record, see below, followed by a terminator
+//!              record (section_id = 0xFF, record_type = 0xFF, len = 0)
+//! checksum:    u16 (LE), the wrapping sum of every preceding byte in the
+//!              file (header + records + terminator)
+//! ```
+//!
+//! Each `Record` is `{ section_id: u8, record_type: u8, len: u16, payload: [u8; len] }`.
+//! `section_id` selects which command sequence the record belongs to (see
+//! [`Section`]); `record_type` is one of:
+//!
+//! - `0x00` command byte — payload is exactly 1 byte
+//! - `0x01` data bytes — payload is `len` bytes, each pushed individually
+//! - `0x02` check-status marker — payload must be empty
+//! - `0x03` delay in milliseconds — payload is exactly 2 bytes (u16 LE)
+//!
+//! [`Section::WriteRam`] is a reserved section carrying a single command
+//! record for [`DisplayFirmware::get_write_ram_command`]; descriptors that
+//! omit it fall back to [`DEFAULT_WRITE_RAM_COMMAND`].
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::{
+    error::DisplayError,
+    firmware::{CommandSequence, DisplayFirmware, DisplaySpec},
+};
+
+/// Magic bytes identifying a firmware descriptor file.
+const MAGIC: &[u8; 4] = b"DEFW";
+/// Only format version currently understood by [`parse`].
+const FORMAT_VERSION: u8 = 1;
+/// Write-RAM command assumed when a descriptor has no [`Section::WriteRam`]
+/// record — 0x24 is the EPD128x250 controller's write-RAM command
+/// (EPD240x416 uses 0x13).
+const DEFAULT_WRITE_RAM_COMMAND: u8 = 0x24;
+
+/// Which command sequence a record belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Init,
+    PartialInit,
+    UpdateFull,
+    UpdatePartial,
+    Sleep,
+    WriteRam,
+}
+
+impl Section {
+    const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Init),
+            1 => Some(Self::PartialInit),
+            2 => Some(Self::UpdateFull),
+            3 => Some(Self::UpdatePartial),
+            4 => Some(Self::Sleep),
+            5 => Some(Self::WriteRam),
+            _ => None,
+        }
+    }
+}
+
+/// One decoded record, ready to be replayed into a [`CommandSequence`].
+#[derive(Debug, Clone)]
+enum RecordOp {
+    Cmd(u8),
+    Data(Vec<u8>),
+    CheckStatus,
+    Delay(u16),
+}
+
+/// A firmware descriptor parsed from the binary format documented in the
+/// module doc-comment: the panel's [`DisplaySpec`] plus the per-section
+/// command records [`DescriptorFirmware`] replays to implement
+/// [`DisplayFirmware`].
+#[derive(Debug, Clone)]
+pub struct FirmwareDescriptor {
+    spec: DisplaySpec,
+    init: Vec<RecordOp>,
+    partial_init: Vec<RecordOp>,
+    update_full: Vec<RecordOp>,
+    update_partial: Vec<RecordOp>,
+    sleep: Vec<RecordOp>,
+    write_ram_command: u8,
+}
+
+/// Parse a firmware descriptor from `bytes`.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` with a descriptive message if `bytes`
+/// is too short, the magic or version don't match, `width` isn't a
+/// multiple of 8, the trailing checksum doesn't match, a record is
+/// malformed, or the terminator record is missing.
+pub fn parse(bytes: &[u8]) -> Result<FirmwareDescriptor, DisplayError> {
+    const HEADER_LEN: usize = 4 + 1 + 2 + 2 + 1;
+    if bytes.len() < HEADER_LEN {
+        return Err(DisplayError::Config(
+            "firmware descriptor is too short for its header".to_string(),
+        ));
+    }
+
+    if &bytes[0..4] != MAGIC {
+        return Err(DisplayError::Config(format!(
+            "bad firmware descriptor magic: expected {MAGIC:?}, found {:?}",
+            &bytes[0..4]
+        )));
+    }
+
+    let version = bytes[4];
+    if version != FORMAT_VERSION {
+        return Err(DisplayError::Config(format!(
+            "unsupported firmware descriptor version {version} (expected {FORMAT_VERSION})"
+        )));
+    }
+
+    let width = u16::from_le_bytes([bytes[5], bytes[6]]);
+    let height = u16::from_le_bytes([bytes[7], bytes[8]]);
+    if !width.is_multiple_of(8) {
+        return Err(DisplayError::Config(format!(
+            "firmware descriptor width {width} is not a multiple of 8"
+        )));
+    }
+
+    let name_len = bytes[9] as usize;
+    let mut offset = 10;
+    if bytes.len() < offset + name_len {
+        return Err(DisplayError::Config(
+            "firmware descriptor is truncated in its name field".to_string(),
+        ));
+    }
+    let name = std::str::from_utf8(&bytes[offset..offset + name_len])
+        .map_err(|e| DisplayError::Config(format!("firmware descriptor name is not valid UTF-8: {e}")))?
+        .to_string();
+    offset += name_len;
+
+    if bytes.len() < offset + 2 {
+        return Err(DisplayError::Config(
+            "firmware descriptor is missing its trailing checksum".to_string(),
+        ));
+    }
+    let checksum_offset = bytes.len() - 2;
+    let expected_checksum = u16::from_le_bytes([bytes[checksum_offset], bytes[checksum_offset + 1]]);
+    let actual_checksum = bytes[..checksum_offset]
+        .iter()
+        .fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+    if actual_checksum != expected_checksum {
+        return Err(DisplayError::Config(format!(
+            "firmware descriptor checksum mismatch: expected {expected_checksum:#06x}, computed {actual_checksum:#06x}"
+        )));
+    }
+
+    let mut sections: [Vec<RecordOp>; 6] = [
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    ];
+    let mut write_ram_command = DEFAULT_WRITE_RAM_COMMAND;
+    let mut terminated = false;
+
+    while offset < checksum_offset {
+        if offset + 4 > checksum_offset {
+            return Err(DisplayError::Config(
+                "firmware descriptor is truncated in a record header".to_string(),
+            ));
+        }
+        let section_id = bytes[offset];
+        let record_type = bytes[offset + 1];
+        let len = u16::from_le_bytes([bytes[offset + 2], bytes[offset + 3]]) as usize;
+        offset += 4;
+
+        if section_id == 0xFF && record_type == 0xFF {
+            terminated = true;
+            break;
+        }
+
+        if offset + len > checksum_offset {
+            return Err(DisplayError::Config(
+                "firmware descriptor record payload runs past the end of the file".to_string(),
+            ));
+        }
+        let payload = &bytes[offset..offset + len];
+        offset += len;
+
+        let section = Section::from_id(section_id).ok_or_else(|| {
+            DisplayError::Config(format!(
+                "firmware descriptor references unknown section id {section_id}"
+            ))
+        })?;
+
+        let op = match record_type {
+            0x00 => {
+                if payload.len() != 1 {
+                    return Err(DisplayError::Config(
+                        "firmware descriptor cmd record must carry exactly 1 byte".to_string(),
+                    ));
+                }
+                RecordOp::Cmd(payload[0])
+            },
+            0x01 => RecordOp::Data(payload.to_vec()),
+            0x02 => {
+                if !payload.is_empty() {
+                    return Err(DisplayError::Config(
+                        "firmware descriptor check-status record must carry no payload".to_string(),
+                    ));
+                }
+                RecordOp::CheckStatus
+            },
+            0x03 => {
+                if payload.len() != 2 {
+                    return Err(DisplayError::Config(
+                        "firmware descriptor delay record must carry exactly 2 bytes".to_string(),
+                    ));
+                }
+                RecordOp::Delay(u16::from_le_bytes([payload[0], payload[1]]))
+            },
+            other => {
+                return Err(DisplayError::Config(format!(
+                    "firmware descriptor references unknown record type {other}"
+                )));
+            },
+        };
+
+        if section == Section::WriteRam {
+            let RecordOp::Cmd(cmd) = op else {
+                return Err(DisplayError::Config(
+                    "firmware descriptor write-ram section must contain a single cmd record".to_string(),
+                ));
+            };
+            write_ram_command = cmd;
+        } else {
+            sections[section as usize].push(op);
+        }
+    }
+
+    if !terminated {
+        return Err(DisplayError::Config(
+            "firmware descriptor is missing its terminator record".to_string(),
+        ));
+    }
+
+    let [init, partial_init, update_full, update_partial, sleep, _write_ram] = sections;
+
+    Ok(FirmwareDescriptor {
+        spec: DisplaySpec {
+            width: u32::from(width),
+            height: u32::from(height),
+            name: name.clone(),
+            description: format!("Runtime-loaded firmware descriptor '{name}'"),
+            rotation: crate::firmware::Rotation::Rotate0,
+        },
+        init,
+        partial_init,
+        update_full,
+        update_partial,
+        sleep,
+        write_ram_command,
+    })
+}
+
+/// A [`DisplayFirmware`] implementation backed by a parsed
+/// [`FirmwareDescriptor`] rather than a hand-written Rust struct.
+pub struct DescriptorFirmware {
+    descriptor: FirmwareDescriptor,
+}
+
+impl DescriptorFirmware {
+    /// Wrap an already-parsed descriptor as a [`DisplayFirmware`].
+    #[must_use]
+    pub const fn new(descriptor: FirmwareDescriptor) -> Self {
+        Self { descriptor }
+    }
+
+    fn build(ops: &[RecordOp]) -> CommandSequence {
+        ops.iter().fold(CommandSequence::new(), |seq, op| match op {
+            RecordOp::Cmd(byte) => seq.cmd(*byte),
+            RecordOp::Data(bytes) => bytes.iter().fold(seq, |s, &b| s.data(b)),
+            RecordOp::CheckStatus => seq.check_status(),
+            RecordOp::Delay(ms) => seq.delay(u64::from(*ms)),
+        })
+    }
+}
+
+impl DisplayFirmware for DescriptorFirmware {
+    fn get_spec(&self) -> &DisplaySpec {
+        &self.descriptor.spec
+    }
+
+    fn get_init_sequence(&self) -> CommandSequence {
+        Self::build(&self.descriptor.init)
+    }
+
+    fn get_partial_init_sequence(&self) -> CommandSequence {
+        Self::build(&self.descriptor.partial_init)
+    }
+
+    fn get_update_sequence(&self, is_partial: bool) -> CommandSequence {
+        if is_partial {
+            Self::build(&self.descriptor.update_partial)
+        } else {
+            Self::build(&self.descriptor.update_full)
+        }
+    }
+
+    fn get_sleep_sequence(&self) -> CommandSequence {
+        Self::build(&self.descriptor.sleep)
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        self.descriptor.write_ram_command
+    }
+}
+
+/// Global registry of descriptors loaded at runtime, keyed by the name
+/// they were registered under.
+static REGISTRY: OnceLock<Mutex<HashMap<String, FirmwareDescriptor>>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<String, FirmwareDescriptor>> {
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Parse `bytes` as a firmware descriptor and register it under `name`,
+/// so later [`create_firmware_from_registry`] calls (and
+/// [`crate::config::set_default_firmware_from_str`]) can resolve it
+/// without a rebuild.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if `bytes` fails to parse, or if the
+/// registry lock cannot be acquired.
+pub fn register_descriptor(name: &str, bytes: &[u8]) -> Result<(), DisplayError> {
+    let descriptor = parse(bytes)?;
+    let mut guard = registry().lock().map_err(|e| {
+        DisplayError::Config(format!("Failed to acquire firmware descriptor registry lock: {e}"))
+    })?;
+    guard.insert(name.to_string(), descriptor);
+    Ok(())
+}
+
+/// Look up `name` in the descriptor registry and build a
+/// [`DisplayFirmware`] instance from it.
+///
+/// Returns `None` if no descriptor is registered under `name`, or if the
+/// registry lock is poisoned.
+#[must_use]
+pub fn create_firmware_from_registry(name: &str) -> Option<Box<dyn DisplayFirmware>> {
+    let guard = registry().lock().ok()?;
+    guard
+        .get(name)
+        .cloned()
+        .map(|descriptor| Box::new(DescriptorFirmware::new(descriptor)) as Box<dyn DisplayFirmware>)
+}
+
+/// Whether a descriptor is currently registered under `name`.
+#[must_use]
+pub fn is_registered(name: &str) -> bool {
+    registry()
+        .lock()
+        .map(|guard| guard.contains_key(name))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assemble a minimal but valid descriptor: one init record (a single
+    /// command byte) and nothing else, with a correct checksum.
+    fn minimal_descriptor_bytes(name: &str, width: u16, height: u16) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+        bytes.push(u8::try_from(name.len()).unwrap());
+        bytes.extend_from_slice(name.as_bytes());
+
+        // One init-section cmd record (section 0, type 0x00, len 1).
+        bytes.push(0);
+        bytes.push(0x00);
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.push(0x12);
+
+        // Terminator record.
+        bytes.push(0xFF);
+        bytes.push(0xFF);
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+
+        let checksum = bytes.iter().fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parses_a_well_formed_descriptor() {
+        let bytes = minimal_descriptor_bytes("TestPanel", 128, 64);
+        let descriptor = parse(&bytes).unwrap();
+        assert_eq!(descriptor.spec.width, 128);
+        assert_eq!(descriptor.spec.height, 64);
+        assert_eq!(descriptor.spec.name, "TestPanel");
+        assert_eq!(descriptor.write_ram_command, DEFAULT_WRITE_RAM_COMMAND);
+        assert_eq!(descriptor.init.len(), 1);
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let mut bytes = minimal_descriptor_bytes("TestPanel", 128, 64);
+        bytes[0] = b'X';
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_corrupted_checksum() {
+        let mut bytes = minimal_descriptor_bytes("TestPanel", 128, 64);
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_width_not_a_multiple_of_8() {
+        let bytes = minimal_descriptor_bytes("TestPanel", 127, 64);
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn registry_round_trip() {
+        let bytes = minimal_descriptor_bytes("RegistryPanel", 256, 128);
+        register_descriptor("registry-panel-test", &bytes).unwrap();
+        assert!(is_registered("registry-panel-test"));
+
+        let firmware = create_firmware_from_registry("registry-panel-test").unwrap();
+        assert_eq!(firmware.get_spec().width, 256);
+        assert_eq!(firmware.get_write_ram_command(), DEFAULT_WRITE_RAM_COMMAND);
+
+        assert!(create_firmware_from_registry("not-registered-at-all").is_none());
+    }
+}
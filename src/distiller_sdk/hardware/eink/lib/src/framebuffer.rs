@@ -0,0 +1,126 @@
+//! `embedded-graphics` `DrawTarget` backed by the same MSB-first 1bpp
+//! buffer layout `convert_png_to_1bit_with_spec` produces, so callers can
+//! draw shapes, fonts, and images in-memory with `embedded-graphics` and
+//! flush once via [`crate::display::DisplayDriver::display_framebuffer`]
+//! instead of round-tripping through a PNG file.
+//!
+//! `BinaryColor::On` packs as ink (bit clear, `0`); `BinaryColor::Off`
+//! packs as background (bit set, `1`), matching
+//! `create_white_image_with_spec`/`create_black_image_with_spec`.
+
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::Pixel;
+
+use crate::firmware::DisplaySpec;
+
+/// A 1bpp framebuffer sized from a [`DisplaySpec`], packed MSB-first and
+/// row-major the same way [`crate::image::convert_png_to_1bit_with_spec`]
+/// packs its output, ready to pass straight to `display_image_raw`.
+pub struct Framebuffer {
+    spec: DisplaySpec,
+    buffer: Vec<u8>,
+}
+
+impl Framebuffer {
+    /// Create a blank (all-white) framebuffer sized for `spec`.
+    #[must_use]
+    pub fn new(spec: DisplaySpec) -> Self {
+        let buffer = vec![0xFF; spec.array_size()];
+        Self { spec, buffer }
+    }
+
+    /// The display spec this framebuffer was sized for.
+    #[must_use]
+    pub fn spec(&self) -> &DisplaySpec {
+        &self.spec
+    }
+
+    /// The packed 1bpp buffer, ready for `display_image_raw`/`display_region`.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Set a single pixel. Out-of-bounds coordinates are ignored.
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: BinaryColor) {
+        if x >= self.spec.width || y >= self.spec.height {
+            return;
+        }
+
+        let idx = (y * self.spec.width + x) as usize;
+        let byte_idx = idx / 8;
+        let mask = 1u8 << (7 - idx % 8);
+
+        if color == BinaryColor::Off {
+            self.buffer[byte_idx] |= mask;
+        } else {
+            self.buffer[byte_idx] &= !mask;
+        }
+    }
+}
+
+impl OriginDimensions for Framebuffer {
+    fn size(&self) -> Size {
+        Size::new(self.spec.width, self.spec.height)
+    }
+}
+
+impl DrawTarget for Framebuffer {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.y < 0 {
+                continue;
+            }
+            self.set_pixel(point.x as u32, point.y as u32, color);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::firmware::DisplaySpec;
+
+    fn test_spec() -> DisplaySpec {
+        DisplaySpec {
+            width: 16,
+            height: 1,
+            name: "test".to_string(),
+            description: String::new(),
+            rotation: crate::firmware::Rotation::Rotate0,
+        }
+    }
+
+    #[test]
+    fn test_new_is_all_white() {
+        let fb = Framebuffer::new(test_spec());
+        assert!(fb.as_bytes().iter().all(|&b| b == 0xFF));
+    }
+
+    #[test]
+    fn test_pack_known_pattern() {
+        let mut fb = Framebuffer::new(test_spec());
+        fb.set_pixel(0, 0, BinaryColor::On);
+        fb.set_pixel(1, 0, BinaryColor::On);
+        fb.set_pixel(2, 0, BinaryColor::Off);
+
+        assert_eq!(fb.as_bytes()[0], 0b0011_1111);
+    }
+
+    #[test]
+    fn test_out_of_bounds_is_ignored() {
+        let mut fb = Framebuffer::new(test_spec());
+        fb.set_pixel(100, 100, BinaryColor::On);
+        assert!(fb.as_bytes().iter().all(|&b| b == 0xFF));
+    }
+}
@@ -0,0 +1,158 @@
+//! `embedded-graphics` `DrawTarget` backed by a 4-gray (2bpp) framebuffer for
+//! the 240x416 panel, so callers can draw shapes, images, and text with
+//! `embedded-graphics` and flush true 4-gray output instead of only 1bpp
+//! black/white.
+//!
+//! Each pixel's 2-bit gray value is split into two bitplanes the controller
+//! expects as separate RAM writes: the most-significant bit stream goes out
+//! after command `0x13`, the least-significant bit stream after command
+//! `0x10`, both packed 8 pixels per byte, MSB-first within the byte,
+//! row-major.
+
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Size};
+use embedded_graphics_core::pixelcolor::{Gray2, GrayColor};
+use embedded_graphics_core::Pixel;
+
+use crate::error::DisplayError;
+use crate::protocol::EinkProtocol;
+
+/// Panel width the 4-gray bitplane layout is sized to.
+pub const GRAY4_WIDTH: u32 = 240;
+/// Panel height the 4-gray bitplane layout is sized to.
+pub const GRAY4_HEIGHT: u32 = 416;
+
+/// Command that selects the most-significant bitplane's RAM bank.
+const MSB_PLANE_COMMAND: u8 = 0x13;
+/// Command that selects the least-significant bitplane's RAM bank.
+const LSB_PLANE_COMMAND: u8 = 0x10;
+
+/// A 240x416, 4-gray-level (`Gray2`) framebuffer, packed as two 1bpp
+/// bitplanes ready to stream to an `EPD240x416`-family controller.
+pub struct Gray4Framebuffer {
+    msb_plane: Vec<u8>,
+    lsb_plane: Vec<u8>,
+}
+
+impl Gray4Framebuffer {
+    /// Create a blank (all-white, i.e. gray level 3) framebuffer.
+    #[must_use]
+    pub fn new() -> Self {
+        let plane_bytes = (GRAY4_WIDTH as usize * GRAY4_HEIGHT as usize).div_ceil(8);
+        Self {
+            msb_plane: vec![0xFF; plane_bytes],
+            lsb_plane: vec![0xFF; plane_bytes],
+        }
+    }
+
+    /// Set a single pixel's 2-bit gray level directly. Out-of-bounds
+    /// coordinates are ignored.
+    pub fn set_pixel(&mut self, x: u32, y: u32, gray: Gray2) {
+        if x >= GRAY4_WIDTH || y >= GRAY4_HEIGHT {
+            return;
+        }
+
+        let pixel_idx = (y * GRAY4_WIDTH + x) as usize;
+        let byte_idx = pixel_idx / 8;
+        let bit_idx = pixel_idx % 8;
+        let mask = 1u8 << (7 - bit_idx);
+
+        let luma = gray.luma();
+        set_bit(&mut self.msb_plane[byte_idx], mask, (luma >> 1) & 1 == 1);
+        set_bit(&mut self.lsb_plane[byte_idx], mask, luma & 1 == 1);
+    }
+
+    /// The packed most-significant bitplane, sent after command `0x13`.
+    #[must_use]
+    pub fn msb_plane(&self) -> &[u8] {
+        &self.msb_plane
+    }
+
+    /// The packed least-significant bitplane, sent after command `0x10`.
+    #[must_use]
+    pub fn lsb_plane(&self) -> &[u8] {
+        &self.lsb_plane
+    }
+
+    /// Stream both bitplanes to the panel: command `0x13` + the
+    /// most-significant plane, then command `0x10` + the least-significant
+    /// plane. Loading the 4-gray init/LUT sequences and triggering the
+    /// refresh is the caller's responsibility.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command or data write fails
+    pub fn flush<P: EinkProtocol>(&self, protocol: &mut P) -> Result<(), DisplayError> {
+        protocol.write_cmd(MSB_PLANE_COMMAND)?;
+        protocol.write_image_data(&self.msb_plane)?;
+        protocol.write_cmd(LSB_PLANE_COMMAND)?;
+        protocol.write_image_data(&self.lsb_plane)
+    }
+}
+
+impl Default for Gray4Framebuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn set_bit(byte: &mut u8, mask: u8, value: bool) {
+    if value {
+        *byte |= mask;
+    } else {
+        *byte &= !mask;
+    }
+}
+
+impl OriginDimensions for Gray4Framebuffer {
+    fn size(&self) -> Size {
+        Size::new(GRAY4_WIDTH, GRAY4_HEIGHT)
+    }
+}
+
+impl DrawTarget for Gray4Framebuffer {
+    type Color = Gray2;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if point.x < 0 || point.y < 0 {
+                continue;
+            }
+            self.set_pixel(point.x as u32, point.y as u32, color);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_known_pattern() {
+        let mut fb = Gray4Framebuffer::new();
+
+        // Gray2 levels 0..=3 across the first 4 pixels of row 0: the MSB
+        // plane should read 0,0,1,1 and the LSB plane 0,1,0,1 in the top
+        // nibble of byte 0.
+        fb.set_pixel(0, 0, Gray2::new(0));
+        fb.set_pixel(1, 0, Gray2::new(1));
+        fb.set_pixel(2, 0, Gray2::new(2));
+        fb.set_pixel(3, 0, Gray2::new(3));
+
+        assert_eq!(fb.msb_plane()[0] & 0b1111_0000, 0b0011_0000);
+        assert_eq!(fb.lsb_plane()[0] & 0b1111_0000, 0b0101_0000);
+    }
+
+    #[test]
+    fn test_new_is_all_white() {
+        let fb = Gray4Framebuffer::new();
+        assert!(fb.msb_plane().iter().all(|&b| b == 0xFF));
+        assert!(fb.lsb_plane().iter().all(|&b| b == 0xFF));
+    }
+}
@@ -1,11 +1,29 @@
 //! Hardware abstraction layer for e-ink display control via SPI and GPIO.
-
-use std::{thread, time::Duration};
-
+//!
+//! [`GpioController`]/[`SpiController`] are the portable seam: the Linux
+//! `spidev`/`gpiod` backend ([`DefaultGpioController`],
+//! [`DefaultSpiController`], [`SharedSpiController`]) lives behind the
+//! `linux` feature, alongside the `embedded_hal_backend` and `cp2130`
+//! adapters that target other peripherals. [`HardwareInterface::from_parts`]
+//! accepts any pair of controllers, so [`crate::protocol::GenericEinkProtocol`]
+//! and the firmware sequences built on it are reusable on bare-metal targets
+//! that never enable `linux`.
+
+#[cfg(feature = "linux")]
+use std::{cell::RefCell, rc::Rc};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "linux")]
 use gpiod::{Chip, Input, Lines, Options, Output};
+#[cfg(feature = "linux")]
 use spidev::{SpiModeFlags, Spidev, SpidevOptions};
 
-use crate::{config::get_hardware_config, error::DisplayError};
+use crate::error::DisplayError;
+#[cfg(feature = "linux")]
+use crate::config::get_hardware_config;
 
 /// GPIO Controller trait for different hardware variants
 pub trait GpioController {
@@ -35,17 +53,83 @@ pub trait GpioController {
     ///
     /// Returns `DisplayError::Gpio` if the operation fails
     fn read_busy(&self) -> Result<bool, DisplayError>;
+
+    /// Block until the Busy pin goes low (the panel is done with the
+    /// current operation), or `timeout_ms` elapses.
+    ///
+    /// Polls [`Self::read_busy`] every 10ms. `gpiod` 0.2's `Lines` has no
+    /// way to race an edge-triggered wait against a timeout (no raw fd to
+    /// poll(2) on, and `read_event()` blocks with no cancellation), so
+    /// [`DefaultGpioController`] relies on this default rather than
+    /// overriding it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if reading the pin fails, or
+    /// `DisplayError::Timeout` if it is still high after `timeout_ms`.
+    fn wait_busy_low(&self, timeout_ms: u64) -> Result<(), DisplayError> {
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+        while self.read_busy()? {
+            if Instant::now() >= deadline {
+                return Err(DisplayError::Timeout);
+            }
+            delay_ms(10);
+        }
+        Ok(())
+    }
+
+    /// Set the chip-select line state, for panels sharing one SPI bus with
+    /// other devices.
+    ///
+    /// The default implementation is a no-op, for the common single-device
+    /// case where the bus controller's own hardware/soft CS (or a CP2130's
+    /// bundled CS handling) already takes care of it.
+    /// [`DefaultGpioController`] overrides this when constructed with a CS
+    /// pin via [`DefaultGpioController::with_cs_pin`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if the operation fails
+    fn write_cs(&self, _value: bool) -> Result<(), DisplayError> {
+        Ok(())
+    }
 }
 
-/// Default GPIO controller implementation using gpiod
+/// Default GPIO controller implementation using gpiod, for Linux SBCs with
+/// a `gpiochip` character device. Gated behind the `linux` feature; ports
+/// without it build [`GpioController`] from [`embedded_hal_backend`] or a
+/// board-specific adapter instead.
+#[cfg(feature = "linux")]
 pub struct DefaultGpioController {
     dc: Lines<Output>,
     rst: Lines<Output>,
     busy: Lines<Input>,
+    /// Chip-select line, present only when the panel shares its SPI bus
+    /// with other devices (see [`Self::with_cs_pin`]).
+    cs: Option<Lines<Output>>,
 }
 
-impl GpioController for DefaultGpioController {
-    fn new() -> Result<Self, DisplayError> {
+#[cfg(feature = "linux")]
+impl DefaultGpioController {
+    /// Build a `DefaultGpioController` using the configured DC/RST/BUSY
+    /// pins, but driving chip-select on `cs_pin` (overriding
+    /// `HardwareConfig::cs_pin`, if set) instead of leaving it to the bus
+    /// controller.
+    ///
+    /// Used to bind each panel to its own CS line when several displays
+    /// share one [`DefaultSpiController`] — see
+    /// [`crate::protocol::MultiPanelProtocol`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if GPIO initialization fails
+    pub fn with_cs_pin(cs_pin: u32) -> Result<Self, DisplayError> {
+        Self::build(Some(cs_pin))
+    }
+
+    /// Shared construction path for [`GpioController::new`] (no CS line
+    /// override) and [`Self::with_cs_pin`] (explicit override).
+    fn build(cs_pin_override: Option<u32>) -> Result<Self, DisplayError> {
         let hw_config = get_hardware_config().map_err(|e| {
             log::error!("Failed to get hardware config: {e}");
             e
@@ -83,7 +167,9 @@ impl GpioController for DefaultGpioController {
             DisplayError::Gpio(err_msg)
         })?;
 
-        // Configure BUSY pin as input
+        // Configure BUSY pin as a plain input. wait_busy_low() polls the
+        // line's level rather than waiting on an edge event - see its doc
+        // comment for why.
         let busy_opts = Options::input([hw_config.busy_pin]).consumer("distiller-display-busy");
         let busy_lines = chip.request_lines(busy_opts).map_err(|e| {
             let err_msg = format!(
@@ -94,20 +180,46 @@ impl GpioController for DefaultGpioController {
             DisplayError::Gpio(err_msg)
         })?;
 
+        // Configure CS pin as output (initially deselected/high), only if
+        // this panel needs its own dedicated chip-select.
+        let cs_pin = cs_pin_override.or(hw_config.cs_pin);
+        let cs_lines = cs_pin
+            .map(|pin| -> Result<Lines<Output>, DisplayError> {
+                let cs_opts = Options::output([pin])
+                    .values([true])
+                    .consumer("distiller-display-cs");
+                chip.request_lines(cs_opts).map_err(|e| {
+                    let err_msg =
+                        format!("Failed to request CS pin {pin} on {}: {}", hw_config.gpio_chip, e);
+                    log::error!("{err_msg}");
+                    DisplayError::Gpio(err_msg)
+                })
+            })
+            .transpose()?;
+
         log::info!(
-            "GPIO initialized on {} with pins DC={}, RST={}, BUSY={}",
+            "GPIO initialized on {} with pins DC={}, RST={}, BUSY={}, CS={:?}",
             hw_config.gpio_chip,
             hw_config.dc_pin,
             hw_config.rst_pin,
-            hw_config.busy_pin
+            hw_config.busy_pin,
+            cs_pin
         );
 
         Ok(Self {
             dc: dc_lines,
             rst: rst_lines,
             busy: busy_lines,
+            cs: cs_lines,
         })
     }
+}
+
+#[cfg(feature = "linux")]
+impl GpioController for DefaultGpioController {
+    fn new() -> Result<Self, DisplayError> {
+        Self::build(None)
+    }
 
     fn write_dc(&self, value: bool) -> Result<(), DisplayError> {
         self.dc
@@ -128,6 +240,26 @@ impl GpioController for DefaultGpioController {
             .map_err(|e| DisplayError::Gpio(format!("Failed to read BUSY pin: {e}")))?;
         Ok(values[0])
     }
+
+    // `wait_busy_low` uses the trait's default polling implementation.
+    // `Lines<Input>::read_event()` blocks on the falling edge with no
+    // timeout of its own, and gpiod 0.2 doesn't expose a raw fd to race it
+    // against one via poll(2) - a prior version of this method ran
+    // `read_event()` on a background thread and raced it with a channel
+    // timeout instead, but that doesn't actually bound the wait: on
+    // timeout the thread is still blocked inside `read_event()` holding
+    // the line's lock, so the *next* call hangs until the edge that timed
+    // out this call finally fires. Polling `read_busy()` every 10ms is
+    // less elegant but every call genuinely returns within `timeout_ms`.
+
+    fn write_cs(&self, value: bool) -> Result<(), DisplayError> {
+        match &self.cs {
+            Some(cs) => cs
+                .set_values([value])
+                .map_err(|e| DisplayError::Gpio(format!("Failed to set CS pin: {e}"))),
+            None => Ok(()),
+        }
+    }
 }
 
 /// SPI Controller trait for different hardware variants
@@ -148,11 +280,80 @@ pub trait SpiController {
     fn write_all(&mut self, data: &[u8]) -> Result<(), DisplayError>;
 }
 
-/// Default SPI controller implementation using spidev
+/// Per-panel SPI bus tuning: clock speed, mode, and the chunking used to
+/// split large transfers. [`DefaultSpiController::new`] fills in whichever
+/// of `HardwareConfig`'s `spi_speed_hz`/`spi_mode`/`spi_max_chunk`/
+/// `spi_chunk_delay_us` the user hasn't set explicitly with the firmware's
+/// [recommended defaults](crate::config::FirmwareType::recommended_spi_config),
+/// falling back to [`Self::default`] for a runtime-loaded descriptor.
+#[derive(Debug, Clone, Copy)]
+pub struct SpiTuning {
+    /// SPI clock speed, in Hz.
+    pub speed_hz: u32,
+    /// SPI mode (0-3), selecting clock polarity/phase.
+    pub mode: u8,
+    /// Largest single transfer sent to the bus before splitting into
+    /// chunks, to stay under Linux SPI drivers' `~4KB` message-size limits.
+    pub max_chunk: usize,
+    /// Delay between chunks of a split transfer, to avoid overwhelming the
+    /// SPI bus.
+    pub chunk_delay_us: u64,
+}
+
+impl Default for SpiTuning {
+    fn default() -> Self {
+        Self {
+            speed_hz: 40_000_000,
+            mode: 0,
+            max_chunk: 4096,
+            chunk_delay_us: 100,
+        }
+    }
+}
+
+/// Convert a validated SPI mode number (0-3) to its `spidev` flags,
+/// defaulting to mode 0 for any other value.
+#[cfg(feature = "linux")]
+fn spi_mode_flags(mode: u8) -> SpiModeFlags {
+    match mode {
+        1 => SpiModeFlags::SPI_MODE_1,
+        2 => SpiModeFlags::SPI_MODE_2,
+        3 => SpiModeFlags::SPI_MODE_3,
+        _ => SpiModeFlags::SPI_MODE_0,
+    }
+}
+
+/// Resolve the effective SPI tuning for the configured display: explicit
+/// `HardwareConfig` overrides take precedence, falling back to the default
+/// firmware's recommendation, and finally to [`SpiTuning::default`] if the
+/// default firmware is a runtime-loaded descriptor with no tuning opinion.
+#[cfg(feature = "linux")]
+fn resolve_spi_tuning(hw_config: &crate::config::HardwareConfig) -> SpiTuning {
+    let recommended = match crate::config::get_default_firmware_selection() {
+        Ok(crate::config::FirmwareSelection::Builtin(firmware_type)) => {
+            firmware_type.recommended_spi_config()
+        },
+        _ => SpiTuning::default(),
+    };
+
+    SpiTuning {
+        speed_hz: hw_config.spi_speed_hz.unwrap_or(recommended.speed_hz),
+        mode: hw_config.spi_mode.unwrap_or(recommended.mode),
+        max_chunk: hw_config.spi_max_chunk.unwrap_or(recommended.max_chunk),
+        chunk_delay_us: hw_config.spi_chunk_delay_us.unwrap_or(recommended.chunk_delay_us),
+    }
+}
+
+/// Default SPI controller implementation using spidev. Gated behind the
+/// `linux` feature; see [`DefaultGpioController`].
+#[cfg(feature = "linux")]
 pub struct DefaultSpiController {
     spi: Spidev,
+    max_chunk: usize,
+    chunk_delay_us: u64,
 }
 
+#[cfg(feature = "linux")]
 impl SpiController for DefaultSpiController {
     fn new() -> Result<Self, DisplayError> {
         let hw_config = get_hardware_config().map_err(|e| {
@@ -166,10 +367,12 @@ impl SpiController for DefaultSpiController {
             DisplayError::Spi(err_msg)
         })?;
 
+        let tuning = resolve_spi_tuning(&hw_config);
+
         let options = SpidevOptions::new()
             .bits_per_word(8)
-            .max_speed_hz(40_000_000)
-            .mode(SpiModeFlags::SPI_MODE_0)
+            .max_speed_hz(tuning.speed_hz)
+            .mode(spi_mode_flags(tuning.mode))
             .build();
 
         spi.configure(&options).map_err(|e| {
@@ -178,38 +381,78 @@ impl SpiController for DefaultSpiController {
             DisplayError::Spi(err_msg)
         })?;
 
-        log::info!("SPI initialized on {}", hw_config.spi_device);
+        log::info!(
+            "SPI initialized on {} at {}Hz mode {}",
+            hw_config.spi_device,
+            tuning.speed_hz,
+            tuning.mode
+        );
 
-        Ok(Self { spi })
+        Ok(Self {
+            spi,
+            max_chunk: tuning.max_chunk,
+            chunk_delay_us: tuning.chunk_delay_us,
+        })
     }
 
     fn write_all(&mut self, data: &[u8]) -> Result<(), DisplayError> {
         use std::io::Write;
 
-        // Linux SPI drivers typically have transfer size limits around 4KB
-        // Split large transfers into smaller chunks to avoid "Message too long" errors
-        const MAX_CHUNK_SIZE: usize = 4096;
-
-        if data.len() <= MAX_CHUNK_SIZE {
+        if data.len() <= self.max_chunk {
             // Small transfer, send directly
             self.spi
                 .write_all(data)
                 .map_err(|e| DisplayError::Spi(format!("Failed to write data: {e}")))
         } else {
             // Large transfer, send in chunks
-            for chunk in data.chunks(MAX_CHUNK_SIZE) {
+            for chunk in data.chunks(self.max_chunk) {
                 self.spi
                     .write_all(chunk)
                     .map_err(|e| DisplayError::Spi(format!("Failed to write data chunk: {e}")))?;
 
                 // Small delay between chunks to avoid overwhelming the SPI bus
-                std::thread::sleep(std::time::Duration::from_micros(100));
+                std::thread::sleep(std::time::Duration::from_micros(self.chunk_delay_us));
             }
             Ok(())
         }
     }
 }
 
+/// `SpiController` wrapper letting several panels share one physical SPI
+/// bus: clone it into each panel's [`HardwareInterface`] and every clone
+/// writes through the same underlying [`DefaultSpiController`]. Each panel
+/// must still be given its own chip-select line (via
+/// [`DefaultGpioController::with_cs_pin`]) and the two must never write
+/// concurrently — see [`crate::protocol::MultiPanelProtocol`], which
+/// asserts/releases CS around each transaction to keep that guarantee.
+#[cfg(feature = "linux")]
+#[derive(Clone)]
+pub struct SharedSpiController {
+    inner: Rc<RefCell<DefaultSpiController>>,
+}
+
+#[cfg(feature = "linux")]
+impl SharedSpiController {
+    /// Wrap an already-constructed [`DefaultSpiController`] for sharing.
+    #[must_use]
+    pub fn from_controller(spi: DefaultSpiController) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(spi)),
+        }
+    }
+}
+
+#[cfg(feature = "linux")]
+impl SpiController for SharedSpiController {
+    fn new() -> Result<Self, DisplayError> {
+        Ok(Self::from_controller(DefaultSpiController::new()?))
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        self.inner.borrow_mut().write_all(data)
+    }
+}
+
 /// Hardware abstraction layer combining GPIO and SPI controllers
 pub struct HardwareInterface<G: GpioController, S: SpiController> {
     gpio: G,
@@ -228,6 +471,15 @@ impl<G: GpioController, S: SpiController> HardwareInterface<G, S> {
         Ok(Self { gpio, spi })
     }
 
+    /// Build a hardware interface from already-constructed controllers,
+    /// bypassing `G::new()`/`S::new()`. Needed by backends like
+    /// [`embedded_hal_backend`] whose controllers wrap board-specific pin
+    /// and bus objects that can't be conjured from a no-argument
+    /// constructor.
+    pub fn from_parts(gpio: G, spi: S) -> Self {
+        Self { gpio, spi }
+    }
+
     /// Set the Data/Command pin state
     ///
     /// # Errors
@@ -255,6 +507,27 @@ impl<G: GpioController, S: SpiController> HardwareInterface<G, S> {
         self.gpio.read_busy()
     }
 
+    /// Block until the Busy pin goes low, or `timeout_ms` elapses.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if reading the pin fails, or
+    /// `DisplayError::Timeout` if it is still high after `timeout_ms`.
+    pub fn wait_busy_low(&self, timeout_ms: u64) -> Result<(), DisplayError> {
+        self.gpio.wait_busy_low(timeout_ms)
+    }
+
+    /// Set the chip-select line state, for panels sharing one SPI bus with
+    /// other devices. A no-op unless the underlying `GpioController` has a
+    /// dedicated CS line (see [`DefaultGpioController::with_cs_pin`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Gpio` if the operation fails
+    pub fn write_cs(&self, value: bool) -> Result<(), DisplayError> {
+        self.gpio.write_cs(value)
+    }
+
     /// Write data to the SPI bus
     ///
     /// # Errors
@@ -276,4 +549,340 @@ pub fn delay_us(us: u64) {
 }
 
 /// Default hardware interface type using default GPIO and SPI controllers
+#[cfg(feature = "linux")]
 pub type DefaultHardwareInterface = HardwareInterface<DefaultGpioController, DefaultSpiController>;
+
+/// USB-to-SPI bridge backend built on a Silicon Labs CP2130, enabled by the
+/// `usb-spi` feature. Lets a display be driven straight from a host PC's
+/// USB port — no GPIO header or SBC required — by mapping
+/// [`GpioController`]/[`SpiController`] onto the CP2130's bulk SPI transfer
+/// and GPIO vendor commands instead of `spidev`/`gpiod`. The display's
+/// configured `dc_pin`/`rst_pin`/`busy_pin` are reused as CP2130 GPIO
+/// indices (0-10) rather than Linux GPIO chip line offsets.
+#[cfg(feature = "usb-spi")]
+pub mod cp2130 {
+    use std::time::Duration;
+
+    use rusb::{Context, DeviceHandle, Direction, Recipient, RequestType, TransferType, UsbContext};
+
+    use super::{GpioController, SpiController};
+    use crate::{config::get_hardware_config, error::DisplayError};
+
+    /// Silicon Labs CP2130 default USB vendor ID.
+    const CP2130_VID: u16 = 0x10C4;
+    /// Silicon Labs CP2130 default USB product ID.
+    const CP2130_PID: u16 = 0x87A0;
+
+    /// CP2130 vendor-specific control requests (Silicon Labs AN792).
+    const REQ_GET_GPIO_MODE_AND_LEVEL: u8 = 0x22;
+    const REQ_SET_GPIO_MODE_AND_LEVEL: u8 = 0x23;
+
+    /// GPIO push-pull output mode byte for `REQ_SET_GPIO_MODE_AND_LEVEL`.
+    const GPIO_MODE_PUSH_PULL_OUTPUT: u8 = 0x01;
+    /// GPIO input mode byte for `REQ_SET_GPIO_MODE_AND_LEVEL`.
+    const GPIO_MODE_INPUT: u8 = 0x00;
+
+    /// USB control-transfer timeout for GPIO commands.
+    const CONTROL_TIMEOUT: Duration = Duration::from_millis(500);
+    /// USB bulk-transfer timeout for SPI writes.
+    const BULK_TIMEOUT: Duration = Duration::from_millis(1000);
+    /// Largest single SPI bulk-write the CP2130 accepts in one transfer.
+    const MAX_CHUNK_SIZE: usize = 4096;
+
+    /// Open the (first) CP2130 on the bus and claim its single interface.
+    fn open_device() -> Result<DeviceHandle<Context>, DisplayError> {
+        let context = Context::new()
+            .map_err(|e| DisplayError::Spi(format!("Failed to create USB context: {e}")))?;
+        let handle = context
+            .open_device_with_vid_pid(CP2130_VID, CP2130_PID)
+            .ok_or_else(|| {
+                DisplayError::Spi(format!(
+                    "No CP2130 found (looking for USB {CP2130_VID:04x}:{CP2130_PID:04x})"
+                ))
+            })?;
+        handle
+            .claim_interface(0)
+            .map_err(|e| DisplayError::Spi(format!("Failed to claim CP2130 interface: {e}")))?;
+        Ok(handle)
+    }
+
+    /// Find the CP2130's bulk-OUT endpoint address for SPI writes.
+    fn find_bulk_out_endpoint(handle: &DeviceHandle<Context>) -> Result<u8, DisplayError> {
+        let device = handle.device();
+        let config_desc = device
+            .active_config_descriptor()
+            .map_err(|e| DisplayError::Spi(format!("Failed to read USB config: {e}")))?;
+
+        for interface in config_desc.interfaces() {
+            for descriptor in interface.descriptors() {
+                for endpoint in descriptor.endpoint_descriptors() {
+                    if endpoint.direction() == Direction::Out
+                        && endpoint.transfer_type() == TransferType::Bulk
+                    {
+                        return Ok(endpoint.address());
+                    }
+                }
+            }
+        }
+
+        Err(DisplayError::Spi(
+            "CP2130 has no bulk-OUT endpoint".to_string(),
+        ))
+    }
+
+    /// Set a single CP2130 GPIO pin to push-pull output and drive it high or low.
+    fn set_gpio_output(
+        handle: &DeviceHandle<Context>,
+        pin: u8,
+        value: bool,
+    ) -> Result<(), DisplayError> {
+        let data = [pin, u8::from(value), GPIO_MODE_PUSH_PULL_OUTPUT];
+        handle
+            .write_control(
+                rusb::request_type(Direction::Out, RequestType::Vendor, Recipient::Device),
+                REQ_SET_GPIO_MODE_AND_LEVEL,
+                0,
+                0,
+                &data,
+                CONTROL_TIMEOUT,
+            )
+            .map_err(|e| DisplayError::Gpio(format!("Failed to set CP2130 GPIO {pin}: {e}")))?;
+        Ok(())
+    }
+
+    /// Read a single CP2130 GPIO pin, configuring it as an input first.
+    fn read_gpio_input(handle: &DeviceHandle<Context>, pin: u8) -> Result<bool, DisplayError> {
+        let data = [pin, 0, GPIO_MODE_INPUT];
+        handle
+            .write_control(
+                rusb::request_type(Direction::Out, RequestType::Vendor, Recipient::Device),
+                REQ_SET_GPIO_MODE_AND_LEVEL,
+                0,
+                0,
+                &data,
+                CONTROL_TIMEOUT,
+            )
+            .map_err(|e| DisplayError::Gpio(format!("Failed to configure CP2130 GPIO {pin}: {e}")))?;
+
+        let mut levels = [0u8; 2];
+        handle
+            .read_control(
+                rusb::request_type(Direction::In, RequestType::Vendor, Recipient::Device),
+                REQ_GET_GPIO_MODE_AND_LEVEL,
+                0,
+                0,
+                &mut levels,
+                CONTROL_TIMEOUT,
+            )
+            .map_err(|e| DisplayError::Gpio(format!("Failed to read CP2130 GPIO {pin}: {e}")))?;
+
+        Ok((levels[0] & (1 << pin)) != 0)
+    }
+
+    /// `GpioController` implementation over a CP2130's GPIO pins, addressed
+    /// by index instead of a Linux `gpiochip` line offset.
+    pub struct Cp2130GpioController {
+        handle: DeviceHandle<Context>,
+        dc_pin: u8,
+        rst_pin: u8,
+        busy_pin: u8,
+    }
+
+    impl GpioController for Cp2130GpioController {
+        fn new() -> Result<Self, DisplayError> {
+            let hw_config = get_hardware_config()?;
+            let handle = open_device()?;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let (dc_pin, rst_pin, busy_pin) = (
+                hw_config.dc_pin as u8,
+                hw_config.rst_pin as u8,
+                hw_config.busy_pin as u8,
+            );
+
+            set_gpio_output(&handle, dc_pin, false)?;
+            set_gpio_output(&handle, rst_pin, true)?;
+
+            log::info!(
+                "CP2130 GPIO initialized with pins DC={dc_pin}, RST={rst_pin}, BUSY={busy_pin}"
+            );
+
+            Ok(Self {
+                handle,
+                dc_pin,
+                rst_pin,
+                busy_pin,
+            })
+        }
+
+        fn write_dc(&self, value: bool) -> Result<(), DisplayError> {
+            set_gpio_output(&self.handle, self.dc_pin, value)
+        }
+
+        fn write_rst(&self, value: bool) -> Result<(), DisplayError> {
+            set_gpio_output(&self.handle, self.rst_pin, value)
+        }
+
+        fn read_busy(&self) -> Result<bool, DisplayError> {
+            read_gpio_input(&self.handle, self.busy_pin)
+        }
+    }
+
+    /// `SpiController` implementation over a CP2130's bulk SPI-write endpoint.
+    pub struct Cp2130SpiController {
+        handle: DeviceHandle<Context>,
+        bulk_out_endpoint: u8,
+    }
+
+    impl SpiController for Cp2130SpiController {
+        fn new() -> Result<Self, DisplayError> {
+            let handle = open_device()?;
+            let bulk_out_endpoint = find_bulk_out_endpoint(&handle)?;
+
+            log::info!("CP2130 SPI initialized on endpoint {bulk_out_endpoint:#04x}");
+
+            Ok(Self {
+                handle,
+                bulk_out_endpoint,
+            })
+        }
+
+        fn write_all(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+            for chunk in data.chunks(MAX_CHUNK_SIZE) {
+                self.handle
+                    .write_bulk(self.bulk_out_endpoint, chunk, BULK_TIMEOUT)
+                    .map_err(|e| DisplayError::Spi(format!("CP2130 SPI write failed: {e}")))?;
+            }
+            Ok(())
+        }
+    }
+
+    /// CP2130-backed hardware interface, for driving a panel over USB
+    /// instead of the SBC's native SPI/GPIO.
+    pub type Cp2130HardwareInterface = super::HardwareInterface<Cp2130GpioController, Cp2130SpiController>;
+}
+
+/// `GpioController`/`SpiController` adapters over `embedded-hal` 1.0
+/// traits, so [`HardwareInterface`] (and, through it,
+/// [`GenericEinkProtocol`](crate::protocol::GenericEinkProtocol)) can run
+/// on any MCU HAL or mock pin set instead of only Linux `spidev`/`gpiod` —
+/// the same `SpiDevice`/`OutputPin`/`InputPin` seam ecosystem drivers
+/// build on.
+///
+/// Unlike [`DisplayDriver`](crate::embedded_driver::DisplayDriver), which
+/// interprets a [`crate::firmware::CommandSequence`] directly, these types
+/// slot into the existing `HardwareInterface<G, S>` generic stack so
+/// firmware implementations and `GenericEinkProtocol` don't need to change
+/// at all to run on embedded-hal hardware.
+pub mod embedded_hal_backend {
+    use std::cell::RefCell;
+
+    use embedded_hal::digital::{InputPin, OutputPin};
+    use embedded_hal::spi::SpiDevice;
+
+    use super::{GpioController, SpiController};
+    use crate::error::DisplayError;
+
+    /// `GpioController` over `embedded-hal` `OutputPin`/`InputPin` DC, RST,
+    /// and BUSY pins, constructed from already-initialized board pins via
+    /// [`Self::from_pins`] rather than the parameterless
+    /// [`GpioController::new`].
+    ///
+    /// Pins are held in `RefCell`s: `embedded-hal`'s `OutputPin`/`InputPin`
+    /// methods take `&mut self`, while this crate's `GpioController` takes
+    /// `&self` throughout (mirroring `DefaultGpioController`, whose `gpiod`
+    /// lines are likewise shared by reference).
+    pub struct EmbeddedHalGpioController<DC, RST, BUSY> {
+        dc: RefCell<DC>,
+        rst: RefCell<RST>,
+        busy: RefCell<BUSY>,
+    }
+
+    impl<DC, RST, BUSY> EmbeddedHalGpioController<DC, RST, BUSY>
+    where
+        DC: OutputPin,
+        RST: OutputPin,
+        BUSY: InputPin,
+    {
+        /// Wrap already-configured DC/RST/BUSY pins.
+        pub fn from_pins(dc: DC, rst: RST, busy: BUSY) -> Self {
+            Self {
+                dc: RefCell::new(dc),
+                rst: RefCell::new(rst),
+                busy: RefCell::new(busy),
+            }
+        }
+    }
+
+    impl<DC, RST, BUSY> GpioController for EmbeddedHalGpioController<DC, RST, BUSY>
+    where
+        DC: OutputPin,
+        RST: OutputPin,
+        BUSY: InputPin,
+    {
+        fn new() -> Result<Self, DisplayError> {
+            Err(DisplayError::Gpio(
+                "EmbeddedHalGpioController has no board-specific pins to construct itself with; \
+                 build it with EmbeddedHalGpioController::from_pins(dc, rst, busy) and pass it to \
+                 HardwareInterface::from_parts instead of HardwareInterface::new"
+                    .to_string(),
+            ))
+        }
+
+        fn write_dc(&self, value: bool) -> Result<(), DisplayError> {
+            let mut dc = self.dc.borrow_mut();
+            let result = if value { dc.set_high() } else { dc.set_low() };
+            result.map_err(|_| DisplayError::Gpio("Failed to set DC pin".to_string()))
+        }
+
+        fn write_rst(&self, value: bool) -> Result<(), DisplayError> {
+            let mut rst = self.rst.borrow_mut();
+            let result = if value { rst.set_high() } else { rst.set_low() };
+            result.map_err(|_| DisplayError::Gpio("Failed to set RST pin".to_string()))
+        }
+
+        fn read_busy(&self) -> Result<bool, DisplayError> {
+            self.busy
+                .borrow_mut()
+                .is_high()
+                .map_err(|_| DisplayError::Gpio("Failed to read BUSY pin".to_string()))
+        }
+    }
+
+    /// `SpiController` over an `embedded-hal` `SpiDevice`, constructed from
+    /// an already-initialized bus handle via [`Self::from_device`] rather
+    /// than the parameterless [`SpiController::new`].
+    pub struct EmbeddedHalSpiController<SPI> {
+        spi: SPI,
+    }
+
+    impl<SPI: SpiDevice> EmbeddedHalSpiController<SPI> {
+        /// Wrap an already-configured `SpiDevice` handle.
+        pub fn from_device(spi: SPI) -> Self {
+            Self { spi }
+        }
+    }
+
+    impl<SPI: SpiDevice> SpiController for EmbeddedHalSpiController<SPI> {
+        fn new() -> Result<Self, DisplayError> {
+            Err(DisplayError::Spi(
+                "EmbeddedHalSpiController has no board-specific bus to construct itself with; \
+                 build it with EmbeddedHalSpiController::from_device(spi) and pass it to \
+                 HardwareInterface::from_parts instead of HardwareInterface::new"
+                    .to_string(),
+            ))
+        }
+
+        fn write_all(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+            self.spi
+                .write(data)
+                .map_err(|e| DisplayError::Spi(format!("embedded-hal SPI write failed: {e:?}")))
+        }
+    }
+
+    /// Embedded-hal-backed hardware interface type, built via
+    /// [`super::HardwareInterface::from_parts`] since its controllers
+    /// require already-initialized board pins and bus handles.
+    pub type EmbeddedHalHardwareInterface<SPI, DC, RST, BUSY> =
+        super::HardwareInterface<EmbeddedHalGpioController<DC, RST, BUSY>, EmbeddedHalSpiController<SPI>>;
+}
@@ -1,8 +1,24 @@
 //! Image processing utilities for converting images to e-ink display format.
 
-use image::Pixel;
+use crate::{
+    config,
+    error::DisplayError,
+    firmware::DisplaySpec,
+    image_processing::{DitherMode, ImageProcessor, PixelDepth},
+};
 
-use crate::{config, error::DisplayError, firmware::DisplaySpec};
+/// Which algorithm turns a decoded image's grayscale channel into 1-bit
+/// pixels.
+#[derive(Debug, Clone, Copy)]
+pub enum ConversionMode {
+    /// Flat threshold at gray > 128 — cheap, but destroys photographs and
+    /// gradients on a 1-bit panel.
+    Threshold,
+    /// Floyd-Steinberg error diffusion: each pixel's quantization error is
+    /// carried forward into its not-yet-visited neighbours, preserving
+    /// perceived gradients at the cost of one extra pass over the image.
+    FloydSteinberg,
+}
 
 /// Convert any supported image format to 1-bit format for a specific display
 /// spec
@@ -18,45 +34,81 @@ pub fn convert_image_to_1bit_with_spec(
     let img = image::open(filename)
         .map_err(|e| DisplayError::Png(format!("Failed to load image: {e}")))?;
 
+    encode_image_to_1bit(&img, spec, ConversionMode::Threshold)
+}
+
+/// Convert any supported image format to 1-bit format for a specific
+/// display spec using Floyd-Steinberg error-diffusion dithering, instead
+/// of [`convert_image_to_1bit_with_spec`]'s flat threshold
+///
+/// # Errors
+///
+/// Returns `DisplayError::Png` if the file cannot be read or conversion fails
+pub fn convert_image_to_1bit_dithered_with_spec(
+    filename: &str,
+    spec: &DisplaySpec,
+    mode: ConversionMode,
+) -> Result<Vec<u8>, DisplayError> {
+    let img = image::open(filename)
+        .map_err(|e| DisplayError::Png(format!("Failed to load image: {e}")))?;
+
+    encode_image_to_1bit(&img, spec, mode)
+}
+
+/// Convert an in-memory image buffer (e.g. a zip archive entry) to 1-bit
+/// format for a specific display spec
+///
+/// # Errors
+///
+/// Returns `DisplayError::Png` if the buffer cannot be decoded or its
+/// dimensions don't match `spec`
+pub fn convert_image_bytes_to_1bit_with_spec(
+    data: &[u8],
+    spec: &DisplaySpec,
+) -> Result<Vec<u8>, DisplayError> {
+    let img = image::load_from_memory(data)
+        .map_err(|e| DisplayError::Png(format!("Failed to load image: {e}")))?;
+
+    encode_image_to_1bit(&img, spec, ConversionMode::Threshold)
+}
+
+/// Pack a decoded image into 1-bit format for a specific display spec,
+/// shared by the file-path and in-memory-buffer entry points above
+fn encode_image_to_1bit(
+    img: &image::DynamicImage,
+    spec: &DisplaySpec,
+    mode: ConversionMode,
+) -> Result<Vec<u8>, DisplayError> {
+    // Callers draw in the panel's logical (rotated) orientation; only the
+    // native buffer bit-packing below cares about `spec.width`/`height`.
+    let logical_width = spec.logical_width();
+    let logical_height = spec.logical_height();
+
     // Check dimensions
-    if img.width() != spec.width || img.height() != spec.height {
+    if img.width() != logical_width || img.height() != logical_height {
         return Err(DisplayError::Png(format!(
             "Invalid image size: {}x{}, expected {}x{}",
             img.width(),
             img.height(),
-            spec.width,
-            spec.height
+            logical_width,
+            logical_height
         )));
     }
 
-    let mut output = vec![0u8; spec.array_size()];
-
-    // Convert to RGBA and process
-    let rgba = img.to_rgba8();
-
-    for y in 0..spec.height {
-        for x in 0..spec.width {
-            let pixel = rgba.get_pixel(x, y);
-            let channels = pixel.channels();
-
-            // Convert RGBA to grayscale
-            let gray =
-                (u16::from(channels[0]) + u16::from(channels[1]) + u16::from(channels[2])) / 3;
-
-            // Convert to 1-bit (threshold at 128)
-            let bit_value = u8::from(gray > 128);
-
-            // Pack into output buffer
-            let byte_idx = (y * spec.width + x) / 8;
-            let bit_idx = (y * spec.width + x) % 8;
+    // Dither and bit-pack through the same `Kernel`-driven engine
+    // `display_image_auto` uses, so this crate only ever maintains one
+    // dithering implementation.
+    let dither_mode = match mode {
+        ConversionMode::Threshold => DitherMode::Threshold,
+        ConversionMode::FloydSteinberg => DitherMode::FloydSteinberg,
+    };
+    let processor = ImageProcessor::new(spec.clone());
+    let gray = processor.to_grayscale(img);
+    let packed = processor.dither(&gray, dither_mode, PixelDepth::One);
 
-            if bit_value == 1 {
-                output[byte_idx as usize] |= 1 << (7 - bit_idx);
-            }
-        }
-    }
-
-    Ok(output)
+    // Transpose from the logical orientation callers drew in into the
+    // native orientation the panel's RAM is wired for.
+    Ok(processor.rotate_to_native(&packed))
 }
 
 /// Convert a PNG image to 1-bit format for a specific display spec (legacy
@@ -73,6 +125,21 @@ pub fn convert_png_to_1bit_with_spec(
     convert_image_to_1bit_with_spec(filename, spec)
 }
 
+/// Convert a PNG image to 1-bit format for a specific display spec, using
+/// [`ConversionMode`] to pick between a flat threshold and Floyd-Steinberg
+/// dithering (legacy-name sibling of [`convert_image_to_1bit_dithered_with_spec`])
+///
+/// # Errors
+///
+/// Returns `DisplayError::Png` if the file cannot be read or conversion fails
+pub fn convert_png_to_1bit_dithered_with_spec(
+    filename: &str,
+    spec: &DisplaySpec,
+    mode: ConversionMode,
+) -> Result<Vec<u8>, DisplayError> {
+    convert_image_to_1bit_dithered_with_spec(filename, spec, mode)
+}
+
 /// Convert any supported image format to 1-bit format using the default
 /// firmware spec
 ///
@@ -94,10 +161,141 @@ pub fn convert_png_to_1bit(filename: &str) -> Result<Vec<u8>, DisplayError> {
     convert_image_to_1bit(filename)
 }
 
-/// Get display dimensions from a display spec
+/// Convert a PNG image to 1-bit format using the default firmware spec,
+/// using [`ConversionMode`] to pick between a flat threshold and
+/// Floyd-Steinberg dithering
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the file cannot be read or conversion fails
+pub fn convert_png_to_1bit_dithered(
+    filename: &str,
+    mode: ConversionMode,
+) -> Result<Vec<u8>, DisplayError> {
+    let spec = config::get_default_spec()?;
+    convert_png_to_1bit_dithered_with_spec(filename, &spec, mode)
+}
+
+/// Run-length encode `data` with a simple byte-oriented scheme: a control
+/// byte with the high bit set means "repeat the next single byte
+/// `(control & 0x7F) + 1` times", and a control byte with the high bit
+/// clear means "copy the next `control + 1` bytes verbatim".
+#[must_use]
+pub fn rle_encode(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut i = 0;
+
+    while i < data.len() {
+        // Count a repeat run starting at `i`.
+        let mut run_len = 1;
+        while run_len < 128 && i + run_len < data.len() && data[i + run_len] == data[i] {
+            run_len += 1;
+        }
+
+        if run_len >= 2 {
+            output.push(0x80 | (run_len - 1) as u8);
+            output.push(data[i]);
+            i += run_len;
+            continue;
+        }
+
+        // No repeat run here; accumulate a literal run until one would
+        // start (or we hit the 128-byte cap).
+        let literal_start = i;
+        i += 1;
+        while i < data.len() && i - literal_start < 128 {
+            let mut next_run_len = 1;
+            while next_run_len < 128 && i + next_run_len < data.len() && data[i + next_run_len] == data[i] {
+                next_run_len += 1;
+            }
+            if next_run_len >= 2 {
+                break;
+            }
+            i += 1;
+        }
+
+        let literal_len = i - literal_start;
+        output.push((literal_len - 1) as u8);
+        output.extend_from_slice(&data[literal_start..i]);
+    }
+
+    output
+}
+
+/// Decode a stream produced by [`rle_encode`], expanding it into exactly
+/// `expected_len` bytes.
+///
+/// # Errors
+///
+/// Returns `DisplayError::InvalidDataSize` if the stream decodes to fewer
+/// or more bytes than `expected_len`, or if a packet's payload runs past
+/// the end of `data`.
+pub fn rle_decode(data: &[u8], expected_len: usize) -> Result<Vec<u8>, DisplayError> {
+    let too_short = |actual: usize| DisplayError::InvalidDataSize { expected: expected_len, actual };
+
+    let mut output = Vec::with_capacity(expected_len);
+    let mut i = 0;
+
+    while i < data.len() {
+        let control = data[i];
+        i += 1;
+
+        if control & 0x80 == 0 {
+            let count = usize::from(control) + 1;
+            let end = i + count;
+            let bytes = data.get(i..end).ok_or_else(|| too_short(output.len()))?;
+            output.extend_from_slice(bytes);
+            i = end;
+        } else {
+            let count = usize::from(control & 0x7F) + 1;
+            let &byte = data.get(i).ok_or_else(|| too_short(output.len()))?;
+            i += 1;
+            output.extend(std::iter::repeat_n(byte, count));
+        }
+
+        if output.len() > expected_len {
+            return Err(too_short(output.len()));
+        }
+    }
+
+    if output.len() != expected_len {
+        return Err(too_short(output.len()));
+    }
+
+    Ok(output)
+}
+
+/// Convert any supported image format to 1-bit format for a specific
+/// display spec, then RLE-compress it with [`rle_encode`]
+///
+/// # Errors
+///
+/// Returns `DisplayError::Png` if the file cannot be read or conversion
+/// fails
+pub fn convert_image_to_1bit_rle_with_spec(
+    filename: &str,
+    spec: &DisplaySpec,
+) -> Result<Vec<u8>, DisplayError> {
+    let raw = convert_image_to_1bit_with_spec(filename, spec)?;
+    Ok(rle_encode(&raw))
+}
+
+/// Convert a PNG image to 1-bit format using the default firmware spec,
+/// then RLE-compress it with [`rle_encode`]
+///
+/// # Errors
+///
+/// Returns `DisplayError` if the file cannot be read or conversion fails
+pub fn convert_png_to_1bit_rle(filename: &str) -> Result<Vec<u8>, DisplayError> {
+    let spec = config::get_default_spec()?;
+    convert_image_to_1bit_rle_with_spec(filename, &spec)
+}
+
+/// Get display dimensions from a display spec, in the logical (rotated)
+/// orientation an app should draw in — see [`DisplaySpec::logical_width`].
 #[must_use]
 pub const fn get_dimensions_from_spec(spec: &DisplaySpec) -> (u32, u32) {
-    (spec.width, spec.height)
+    (spec.logical_width(), spec.logical_height())
 }
 
 /// Get display dimensions using the default firmware
@@ -110,7 +308,7 @@ pub fn get_dimensions() -> (u32, u32) {
             log::error!(
                 "Failed to get default firmware spec: {e}. Using EPD128x250 dimensions as default"
             );
-            (128, 250) // Default dimensions for compatibility
+            (250, 128) // EPD128x250's logical (mounted) dimensions, for compatibility
         },
     }
 }
@@ -8,7 +8,10 @@
 
 use image::{DynamicImage, GrayImage, Luma};
 
-use crate::{error::DisplayError, firmware::DisplaySpec};
+use crate::{
+    error::DisplayError,
+    firmware::{DisplaySpec, Rotation},
+};
 
 // Include the font data directly
 include!("font_6x8.rs");
@@ -43,6 +46,88 @@ pub enum ScaleMode {
     Stretch,
 }
 
+/// Resampling quality for [`ImageProcessor::scale`], trading sharpness for
+/// latency. With the `fast-resize` feature enabled, resizing is delegated
+/// to a SIMD-accelerated resizer instead of `image`'s CPU-bound path; with
+/// it off, this only selects the resampling filter.
+#[derive(Debug, Clone, Copy)]
+pub enum ResizeQuality {
+    /// Nearest-neighbor resampling (fastest, blocky).
+    Nearest,
+    /// Bilinear resampling (fast, smooth).
+    Bilinear,
+    /// Lanczos3 resampling (sharpest, slowest) — the original fixed
+    /// behavior of `scale`.
+    Lanczos3,
+}
+
+impl ResizeQuality {
+    /// The `image`-crate filter used when the `fast-resize` feature is off
+    /// (or as a fallback if the fast path can't handle the input).
+    const fn filter_type(self) -> image::imageops::FilterType {
+        match self {
+            Self::Nearest => image::imageops::FilterType::Nearest,
+            Self::Bilinear => image::imageops::FilterType::Triangle,
+            Self::Lanczos3 => image::imageops::FilterType::Lanczos3,
+        }
+    }
+}
+
+/// Resize `img` to exactly `width` x `height`. Routes through the
+/// SIMD-accelerated `fast_image_resize` backend when the `fast-resize`
+/// feature is enabled, falling back to `image`'s resampling otherwise (or
+/// if the fast path declines the input).
+fn resize_to(img: &DynamicImage, width: u32, height: u32, quality: ResizeQuality) -> DynamicImage {
+    #[cfg(feature = "fast-resize")]
+    {
+        if let Some(resized) = fast_resize::try_resize(img, width, height, quality) {
+            return resized;
+        }
+    }
+
+    img.resize_exact(width, height, quality.filter_type())
+}
+
+/// SIMD-accelerated resizing backend, enabled by the `fast-resize` feature.
+#[cfg(feature = "fast-resize")]
+mod fast_resize {
+    use fast_image_resize::{
+        images::Image as FrImage, FilterType as FrFilterType, PixelType, ResizeAlg,
+        ResizeOptions, Resizer,
+    };
+    use image::{DynamicImage, RgbaImage};
+
+    use super::ResizeQuality;
+
+    /// Resize `img` via `fast_image_resize`, returning `None` if the
+    /// source can't be converted to its expected pixel format so the
+    /// caller can fall back to the `image`-based path.
+    pub(super) fn try_resize(
+        img: &DynamicImage,
+        width: u32,
+        height: u32,
+        quality: ResizeQuality,
+    ) -> Option<DynamicImage> {
+        let rgba = img.to_rgba8();
+        let src = FrImage::from_vec_u8(rgba.width(), rgba.height(), rgba.into_raw(), PixelType::U8x4)
+            .ok()?;
+        let mut dst = FrImage::new(width, height, PixelType::U8x4);
+
+        let alg = match quality {
+            ResizeQuality::Nearest => ResizeAlg::Nearest,
+            ResizeQuality::Bilinear => ResizeAlg::Convolution(FrFilterType::Bilinear),
+            ResizeQuality::Lanczos3 => ResizeAlg::Convolution(FrFilterType::Lanczos3),
+        };
+
+        let mut resizer = Resizer::new();
+        resizer
+            .resize(&src, &mut dst, &ResizeOptions::new().resize_alg(alg))
+            .ok()?;
+
+        RgbaImage::from_raw(width, height, dst.into_vec()).map(DynamicImage::ImageRgba8)
+    }
+}
+
 /// Dithering algorithms for converting grayscale to 1-bit
 #[derive(Debug, Clone, Copy)]
 pub enum DitherMode {
@@ -52,6 +137,180 @@ pub enum DitherMode {
     FloydSteinberg,
     /// Ordered dithering with Bayer matrix
     Ordered,
+    /// Atkinson error diffusion (the canonical 1-bit Mac/e-ink look; does
+    /// not conserve all error, giving higher local contrast)
+    Atkinson,
+    /// Jarvis-Judice-Ninke error diffusion, spread over two rows
+    JarvisJudiceNinke,
+    /// Stucki error diffusion, spread over two rows
+    Stucki,
+    /// Sierra error diffusion, spread over two rows
+    Sierra,
+    /// User-supplied error-diffusion kernel
+    Custom(&'static Kernel),
+}
+
+/// An error-diffusion kernel: a divisor plus a list of `(dx, dy, weight)`
+/// offsets relative to the current pixel, each neighbor receiving
+/// `error * weight / divisor` of the quantization error.
+#[derive(Debug, Clone, Copy)]
+pub struct Kernel {
+    /// Shared divisor applied to every offset's weight.
+    pub divisor: i32,
+    /// `(dx, dy, weight)` offsets relative to the current pixel.
+    pub offsets: &'static [(i32, i32, i32)],
+}
+
+/// Floyd-Steinberg kernel (divisor 16), matching the classic 4-neighbor
+/// weights: right 7/16, bottom-left 3/16, bottom 5/16, bottom-right 1/16.
+pub static FLOYD_STEINBERG_KERNEL: Kernel = Kernel {
+    divisor: 16,
+    offsets: &[(1, 0, 7), (-1, 1, 3), (0, 1, 5), (1, 1, 1)],
+};
+
+/// Atkinson kernel (divisor 8). Deliberately diffuses only 6/8 of the
+/// error, leaving the rest uncorrected for Atkinson's characteristic
+/// higher-contrast look.
+pub static ATKINSON_KERNEL: Kernel = Kernel {
+    divisor: 8,
+    offsets: &[
+        (1, 0, 1),
+        (2, 0, 1),
+        (-1, 1, 1),
+        (0, 1, 1),
+        (1, 1, 1),
+        (0, 2, 1),
+    ],
+};
+
+/// Jarvis-Judice-Ninke kernel (divisor 48), spreading error over the
+/// current and next two rows.
+pub static JARVIS_JUDICE_NINKE_KERNEL: Kernel = Kernel {
+    divisor: 48,
+    offsets: &[
+        (1, 0, 7),
+        (2, 0, 5),
+        (-2, 1, 3),
+        (-1, 1, 5),
+        (0, 1, 7),
+        (1, 1, 5),
+        (2, 1, 3),
+        (-2, 2, 1),
+        (-1, 2, 3),
+        (0, 2, 5),
+        (1, 2, 3),
+        (2, 2, 1),
+    ],
+};
+
+/// Stucki kernel (divisor 42), spreading error over the current and next
+/// two rows.
+pub static STUCKI_KERNEL: Kernel = Kernel {
+    divisor: 42,
+    offsets: &[
+        (1, 0, 8),
+        (2, 0, 4),
+        (-2, 1, 2),
+        (-1, 1, 4),
+        (0, 1, 8),
+        (1, 1, 4),
+        (2, 1, 2),
+        (-2, 2, 1),
+        (-1, 2, 2),
+        (0, 2, 4),
+        (1, 2, 2),
+        (2, 2, 1),
+    ],
+};
+
+/// Sierra kernel (divisor 32), spreading error over the current and next
+/// two rows.
+pub static SIERRA_KERNEL: Kernel = Kernel {
+    divisor: 32,
+    offsets: &[
+        (1, 0, 5),
+        (2, 0, 3),
+        (-2, 1, 2),
+        (-1, 1, 4),
+        (0, 1, 5),
+        (1, 1, 4),
+        (2, 1, 2),
+        (-1, 2, 2),
+        (0, 2, 3),
+        (1, 2, 2),
+    ],
+};
+
+/// Output bit depth per pixel, following the bit-depth-parameterized
+/// storage pattern used by codecs like rav1d's `BitDepth` trait, kept here
+/// as a plain enum since the packed formats are fixed widths rather than a
+/// generic storage type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelDepth {
+    /// 1 bit per pixel (black/white) — the original e-ink behavior.
+    One,
+    /// 2 bits per pixel (4 gray levels).
+    Two,
+    /// 4 bits per pixel (16 gray levels).
+    Four,
+}
+
+impl PixelDepth {
+    /// Bits used to store one pixel.
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Four => 4,
+        }
+    }
+
+    /// Number of distinct gray levels representable at this depth
+    /// (`2^bits`).
+    #[must_use]
+    pub const fn levels(self) -> u32 {
+        1 << self.bits()
+    }
+}
+
+/// Quantize an 8-bit luma value to the nearest of `depth.levels()` evenly
+/// spaced levels, returning the level index (`0..levels()`). At
+/// `PixelDepth::One` this is the 1-bit threshold `value > 128`, so 128
+/// itself maps to level 0.
+fn quantize_to_level(value: u8, depth: PixelDepth) -> u8 {
+    if depth == PixelDepth::One {
+        return u8::from(value > 128);
+    }
+    let levels = depth.levels();
+    let step = 255.0 / (levels - 1) as f32;
+    ((f32::from(value) / step).round() as u32).min(levels - 1) as u8
+}
+
+/// Expand a quantized level index back to an 8-bit luma value, the
+/// inverse of [`quantize_to_level`].
+fn level_to_value(level: u8, depth: PixelDepth) -> u8 {
+    let levels = depth.levels();
+    let step = 255.0 / (levels - 1) as f32;
+    (f32::from(level) * step).round() as u8
+}
+
+/// Pack one level per pixel (each `< depth.levels()`) into bytes,
+/// `8 / depth.bits()` pixels per byte, MSB-first.
+fn pack_levels(levels: &[u8], depth: PixelDepth) -> Vec<u8> {
+    let bits = depth.bits();
+    let per_byte = (8 / bits) as usize;
+
+    let mut output = Vec::with_capacity(levels.len().div_ceil(per_byte));
+    for chunk in levels.chunks(per_byte) {
+        let mut byte = 0u8;
+        for (i, &level) in chunk.iter().enumerate() {
+            let shift = 8 - bits * (i as u32 + 1);
+            byte |= level << shift;
+        }
+        output.push(byte);
+    }
+    output
 }
 
 /// Image processor for e-ink display operations
@@ -75,6 +334,56 @@ impl ImageProcessor {
         image::open(path).map_err(|e| DisplayError::Png(format!("Failed to load image: {e}")))
     }
 
+    /// Load image from an in-memory buffer (e.g. a zip archive entry)
+    /// instead of a file path
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Png` if the image cannot be decoded
+    pub fn load_image_bytes(&self, data: &[u8]) -> Result<DynamicImage, DisplayError> {
+        image::load_from_memory(data)
+            .map_err(|e| DisplayError::Png(format!("Failed to load image: {e}")))
+    }
+
+    /// Write a packed 1-bit buffer (MSB-first, matching this crate's own bit
+    /// order) as a 1-bit-depth grayscale PNG at `path`, for previewing or
+    /// debugging exactly what the panel will show. No repacking is needed:
+    /// the buffer is the PNG's raw scanline data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Io` if the file cannot be written, or
+    /// `DisplayError::InvalidDataSize` if `data` is shorter than one
+    /// scanline's worth of bytes per row for this processor's display spec.
+    pub fn save_1bit_png(&self, data: &[u8], path: &str) -> Result<(), DisplayError> {
+        let width = self.spec.width;
+        let height = self.spec.height;
+        let row_bytes = (width as usize).div_ceil(8);
+        let expected = row_bytes * height as usize;
+        if data.len() < expected {
+            return Err(DisplayError::InvalidDataSize {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        // Each PNG scanline is prefixed with a filter-type byte (0 = None).
+        let mut raw = Vec::with_capacity((row_bytes + 1) * height as usize);
+        for row in data[..expected].chunks(row_bytes) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+
+        let mut png = Vec::new();
+        png.extend_from_slice(&[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n']);
+        png.extend(png_chunk(b"IHDR", &ihdr_data(width, height)));
+        png.extend(png_chunk(b"IDAT", &zlib_stored(&raw)));
+        png.extend(png_chunk(b"IEND", &[]));
+
+        std::fs::write(path, png)?;
+        Ok(())
+    }
+
     /// Load image from memory buffer
     ///
     /// # Errors
@@ -97,20 +406,19 @@ impl ImageProcessor {
         }
     }
 
-    /// Scale image to display dimensions using the specified mode
+    /// Scale image to display dimensions using the specified mode and
+    /// resampling quality. Scales to the panel's logical (rotated)
+    /// dimensions, since that's the orientation callers draw/compose in —
+    /// see [`DisplaySpec::logical_width`].
     #[must_use]
-    pub fn scale(&self, img: &DynamicImage, mode: ScaleMode) -> DynamicImage {
+    pub fn scale(&self, img: &DynamicImage, mode: ScaleMode, quality: ResizeQuality) -> DynamicImage {
         let (img_width, img_height) = (img.width(), img.height());
-        let (disp_width, disp_height) = (self.spec.width, self.spec.height);
+        let (disp_width, disp_height) = (self.spec.logical_width(), self.spec.logical_height());
 
         match mode {
             ScaleMode::Stretch => {
                 // Simply resize to exact display dimensions
-                img.resize_exact(
-                    disp_width,
-                    disp_height,
-                    image::imageops::FilterType::Lanczos3,
-                )
+                resize_to(img, disp_width, disp_height, quality)
             },
             ScaleMode::Letterbox => {
                 // Calculate scale to fit within display while maintaining aspect ratio
@@ -122,8 +430,7 @@ impl ImageProcessor {
                 let new_height = (img_height as f32 * scale) as u32;
 
                 // Resize image
-                let resized =
-                    img.resize_exact(new_width, new_height, image::imageops::FilterType::Lanczos3);
+                let resized = resize_to(img, new_width, new_height, quality);
 
                 // Create black background and paste resized image centered
                 let mut output = DynamicImage::new_luma8(disp_width, disp_height);
@@ -143,8 +450,7 @@ impl ImageProcessor {
                 let new_height = (img_height as f32 * scale) as u32;
 
                 // Resize image
-                let resized =
-                    img.resize_exact(new_width, new_height, image::imageops::FilterType::Lanczos3);
+                let resized = resize_to(img, new_width, new_height, quality);
 
                 // Crop center
                 let x_offset = (new_width.saturating_sub(disp_width)) / 2;
@@ -175,118 +481,99 @@ impl ImageProcessor {
         img.to_luma8()
     }
 
-    /// Apply dithering to convert grayscale to 1-bit
+    /// Apply dithering to convert grayscale to `depth`-bit packed output
+    /// (`PixelDepth::One` reproduces the original 1-bit behavior).
     #[must_use]
-    pub fn dither(&self, gray: &GrayImage, mode: DitherMode) -> Vec<u8> {
+    pub fn dither(&self, gray: &GrayImage, mode: DitherMode, depth: PixelDepth) -> Vec<u8> {
         match mode {
-            DitherMode::Threshold => Self::threshold_dither(gray, 128),
-            DitherMode::FloydSteinberg => Self::floyd_steinberg_dither(gray),
-            DitherMode::Ordered => Self::ordered_dither(gray),
+            DitherMode::Threshold => Self::threshold_dither(gray, depth),
+            DitherMode::FloydSteinberg => {
+                Self::diffuse_dither(gray, &FLOYD_STEINBERG_KERNEL, depth)
+            },
+            DitherMode::Ordered => Self::ordered_dither(gray, depth),
+            DitherMode::Atkinson => Self::diffuse_dither(gray, &ATKINSON_KERNEL, depth),
+            DitherMode::JarvisJudiceNinke => {
+                Self::diffuse_dither(gray, &JARVIS_JUDICE_NINKE_KERNEL, depth)
+            },
+            DitherMode::Stucki => Self::diffuse_dither(gray, &STUCKI_KERNEL, depth),
+            DitherMode::Sierra => Self::diffuse_dither(gray, &SIERRA_KERNEL, depth),
+            DitherMode::Custom(kernel) => Self::diffuse_dither(gray, kernel, depth),
         }
     }
 
-    /// Simple threshold dithering
-    fn threshold_dither(gray: &GrayImage, threshold: u8) -> Vec<u8> {
-        let (width, height) = gray.dimensions();
-        let mut output = vec![0u8; ((width * height) / 8) as usize];
-
-        for (y, row) in gray.rows().enumerate() {
-            for (x, pixel) in row.enumerate() {
-                let gray_value = pixel[0];
-                let bit_value = u8::from(gray_value > threshold);
-
-                let pixel_idx = y * width as usize + x;
-                let byte_idx = pixel_idx / 8;
-                let bit_idx = pixel_idx % 8;
-
-                if bit_value == 1 {
-                    output[byte_idx] |= 1 << (7 - bit_idx);
-                }
-            }
-        }
-
-        output
+    /// Quantize straight to the nearest level, with no error diffusion
+    /// (the `depth`-bit generalization of a 128 threshold).
+    fn threshold_dither(gray: &GrayImage, depth: PixelDepth) -> Vec<u8> {
+        let levels: Vec<u8> = gray
+            .pixels()
+            .map(|pixel| quantize_to_level(pixel[0], depth))
+            .collect();
+        pack_levels(&levels, depth)
     }
 
-    /// Floyd-Steinberg error diffusion dithering
-    fn floyd_steinberg_dither(gray: &GrayImage) -> Vec<u8> {
+    /// Generic error-diffusion dithering driven by a [`Kernel`], covering
+    /// Floyd-Steinberg, Atkinson, Jarvis-Judice-Ninke, Stucki, and any
+    /// caller-supplied kernel. The quantizer snaps each pixel to the
+    /// nearest of `depth.levels()` levels instead of just 0/255, and the
+    /// diffused error is measured against that level's reconstructed
+    /// value.
+    fn diffuse_dither(gray: &GrayImage, kernel: &Kernel, depth: PixelDepth) -> Vec<u8> {
         let (width, height) = gray.dimensions();
         let mut work_image = gray.clone();
-        let mut output = vec![0u8; ((width * height) / 8) as usize];
+        let mut levels = vec![0u8; (width * height) as usize];
 
         for y in 0..height {
             for x in 0..width {
                 let old_pixel = i32::from(work_image.get_pixel(x, y)[0]);
-                let new_pixel = if old_pixel > 128 { 255 } else { 0 };
+                let level = quantize_to_level(old_pixel.clamp(0, 255) as u8, depth);
+                let new_pixel = i32::from(level_to_value(level, depth));
                 let error = old_pixel - new_pixel;
 
-                // Set the output bit
-                if new_pixel == 255 {
-                    let pixel_idx = (y * width + x) as usize;
-                    let byte_idx = pixel_idx / 8;
-                    let bit_idx = pixel_idx % 8;
-                    output[byte_idx] |= 1 << (7 - bit_idx);
-                }
-
-                // Distribute error to neighboring pixels
-                // Right: 7/16
-                if x + 1 < width {
-                    let pixel = i32::from(work_image.get_pixel(x + 1, y)[0]);
-                    let new_val = (pixel + error * 7 / 16).clamp(0, 255) as u8;
-                    work_image.put_pixel(x + 1, y, Luma([new_val]));
-                }
-
-                // Bottom-left: 3/16
-                if y + 1 < height && x > 0 {
-                    let pixel = i32::from(work_image.get_pixel(x - 1, y + 1)[0]);
-                    let new_val = (pixel + error * 3 / 16).clamp(0, 255) as u8;
-                    work_image.put_pixel(x - 1, y + 1, Luma([new_val]));
-                }
-
-                // Bottom: 5/16
-                if y + 1 < height {
-                    let pixel = i32::from(work_image.get_pixel(x, y + 1)[0]);
-                    let new_val = (pixel + error * 5 / 16).clamp(0, 255) as u8;
-                    work_image.put_pixel(x, y + 1, Luma([new_val]));
-                }
+                levels[(y * width + x) as usize] = level;
 
-                // Bottom-right: 1/16
-                if y + 1 < height && x + 1 < width {
-                    let pixel = i32::from(work_image.get_pixel(x + 1, y + 1)[0]);
-                    let new_val = (pixel + error / 16).clamp(0, 255) as u8;
-                    work_image.put_pixel(x + 1, y + 1, Luma([new_val]));
+                // Distribute error to neighboring pixels per the kernel
+                for &(dx, dy, weight) in kernel.offsets {
+                    let nx = x as i32 + dx;
+                    let ny = y as i32 + dy;
+                    if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
+                        continue;
+                    }
+                    let (nx, ny) = (nx as u32, ny as u32);
+                    let pixel = i32::from(work_image.get_pixel(nx, ny)[0]);
+                    let new_val = (pixel + error * weight / kernel.divisor).clamp(0, 255) as u8;
+                    work_image.put_pixel(nx, ny, Luma([new_val]));
                 }
             }
         }
 
-        output
+        pack_levels(&levels, depth)
     }
 
-    /// Ordered dithering using Bayer matrix
-    fn ordered_dither(gray: &GrayImage) -> Vec<u8> {
+    /// Ordered dithering using a 4x4 Bayer matrix, biasing each pixel by a
+    /// fraction of one quantization step before snapping to the nearest
+    /// of `depth.levels()` levels.
+    fn ordered_dither(gray: &GrayImage, depth: PixelDepth) -> Vec<u8> {
         // 4x4 Bayer matrix
         const BAYER_MATRIX: [[u8; 4]; 4] =
             [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
 
         let (width, height) = gray.dimensions();
-        let mut output = vec![0u8; ((width * height) / 8) as usize];
+        let levels_count = depth.levels();
+        let step = 255.0 / (levels_count - 1) as f32;
+        let mut levels = vec![0u8; (width * height) as usize];
 
         for y in 0..height {
             for x in 0..width {
-                let gray_value = gray.get_pixel(x, y)[0];
-                let threshold = BAYER_MATRIX[(y % 4) as usize][(x % 4) as usize] * 16;
-                let bit_value = u8::from(gray_value > threshold);
-
-                if bit_value == 1 {
-                    let pixel_idx = (y * width + x) as usize;
-                    let byte_idx = pixel_idx / 8;
-                    let bit_idx = pixel_idx % 8;
-                    output[byte_idx] |= 1 << (7 - bit_idx);
-                }
+                let gray_value = f32::from(gray.get_pixel(x, y)[0]);
+                let bayer = f32::from(BAYER_MATRIX[(y % 4) as usize][(x % 4) as usize]);
+                let bias = (bayer / 16.0 - 0.5) * step;
+                let biased = (gray_value + bias).clamp(0.0, 255.0);
+                let level = (biased / step).round().clamp(0.0, (levels_count - 1) as f32) as u8;
+                levels[(y * width + x) as usize] = level;
             }
         }
 
-        output
+        pack_levels(&levels, depth)
     }
 
     /// Invert a 1-bit image (swap black and white)
@@ -415,6 +702,122 @@ impl ImageProcessor {
         output
     }
 
+    /// Quantize `gray` to `depth` levels and pack `8 / depth.bits()`
+    /// pixels per byte, MSB-first, row-major — the multi-level analogue of
+    /// [`Self::pack_1bit`].
+    #[must_use]
+    pub fn pack_nbit(&self, gray: &GrayImage, depth: PixelDepth) -> Vec<u8> {
+        let levels: Vec<u8> = gray
+            .pixels()
+            .map(|pixel| quantize_to_level(pixel[0], depth))
+            .collect();
+        pack_levels(&levels, depth)
+    }
+
+    /// Unpack bytes produced by [`Self::pack_nbit`] back to one 8-bit luma
+    /// value per pixel, reading exactly `pixel_count` pixels.
+    #[must_use]
+    pub fn unpack_nbit(&self, data: &[u8], depth: PixelDepth, pixel_count: usize) -> Vec<u8> {
+        let bits = depth.bits();
+        let per_byte = 8 / bits;
+        let mask = (1u8 << bits) - 1;
+
+        let mut output = Vec::with_capacity(pixel_count);
+        'outer: for &byte in data {
+            for i in 0..per_byte {
+                if output.len() >= pixel_count {
+                    break 'outer;
+                }
+                let shift = 8 - bits * (i + 1);
+                let level = (byte >> shift) & mask;
+                output.push(level_to_value(level, depth));
+            }
+        }
+
+        output
+    }
+
+    /// Compress `data` with the classic PackBits run-length scheme (as used
+    /// by TIFF's PackBits compression), so large runs of black/white pixels
+    /// collapse before being sent to firmware.
+    #[must_use]
+    pub fn compress_packbits(&self, data: &[u8]) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut i = 0;
+
+        while i < data.len() {
+            // Count a repeat run starting at `i`.
+            let mut repeat_len = 1;
+            while repeat_len < 128
+                && i + repeat_len < data.len()
+                && data[i + repeat_len] == data[i]
+            {
+                repeat_len += 1;
+            }
+
+            if repeat_len >= 2 {
+                output.push((257 - repeat_len) as u8);
+                output.push(data[i]);
+                i += repeat_len;
+                continue;
+            }
+
+            // No repeat run here; accumulate a literal run until one would
+            // start (or we hit the 128-byte cap).
+            let literal_start = i;
+            i += 1;
+            while i < data.len() && i - literal_start < 128 {
+                let mut next_repeat_len = 1;
+                while next_repeat_len < 128
+                    && i + next_repeat_len < data.len()
+                    && data[i + next_repeat_len] == data[i]
+                {
+                    next_repeat_len += 1;
+                }
+                if next_repeat_len >= 2 {
+                    break;
+                }
+                i += 1;
+            }
+
+            let literal_len = i - literal_start;
+            output.push((literal_len - 1) as u8);
+            output.extend_from_slice(&data[literal_start..i]);
+        }
+
+        output
+    }
+
+    /// Decompress a PackBits-encoded buffer produced by
+    /// [`Self::compress_packbits`], truncating or zero-padding to exactly
+    /// `expected_len` bytes.
+    #[must_use]
+    pub fn decompress_packbits(&self, data: &[u8], expected_len: usize) -> Vec<u8> {
+        let mut output = Vec::with_capacity(expected_len);
+        let mut i = 0;
+
+        while i < data.len() && output.len() < expected_len {
+            let header = data[i];
+            i += 1;
+
+            if header <= 127 {
+                let count = header as usize + 1;
+                let end = (i + count).min(data.len());
+                output.extend_from_slice(&data[i..end]);
+                i = end;
+            } else if header >= 129 {
+                let Some(&byte) = data.get(i) else { break };
+                i += 1;
+                let count = 257 - usize::from(header);
+                output.extend(std::iter::repeat_n(byte, count));
+            }
+            // header == 128 is a no-op.
+        }
+
+        output.resize(expected_len, 0);
+        output
+    }
+
     /// Complete image processing pipeline
     ///
     /// # Errors
@@ -425,15 +828,74 @@ impl ImageProcessor {
         &self,
         path: &str,
         scale_mode: ScaleMode,
+        resize_quality: ResizeQuality,
+        dither_mode: DitherMode,
+        depth: PixelDepth,
+        brightness: Option<i32>,
+        contrast: Option<f32>,
+        transform: Option<Transform>,
+        invert: bool,
+    ) -> Result<Vec<u8>, DisplayError> {
+        let img = self.load_image(path)?;
+        self.process_loaded_image(
+            img,
+            scale_mode,
+            resize_quality,
+            dither_mode,
+            depth,
+            brightness,
+            contrast,
+            transform,
+            invert,
+        )
+    }
+
+    /// Same as [`Self::process_image`], but decodes `data` as an in-memory
+    /// image buffer (e.g. a zip archive entry) instead of reading a file
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Png` if image processing fails
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_image_bytes(
+        &self,
+        data: &[u8],
+        scale_mode: ScaleMode,
+        resize_quality: ResizeQuality,
         dither_mode: DitherMode,
+        depth: PixelDepth,
         brightness: Option<i32>,
         contrast: Option<f32>,
         transform: Option<Transform>,
         invert: bool,
     ) -> Result<Vec<u8>, DisplayError> {
-        // Load image
-        let mut img = self.load_image(path)?;
+        let img = self.load_image_bytes(data)?;
+        self.process_loaded_image(
+            img,
+            scale_mode,
+            resize_quality,
+            dither_mode,
+            depth,
+            brightness,
+            contrast,
+            transform,
+            invert,
+        )
+    }
 
+    #[allow(clippy::too_many_arguments)]
+    fn process_loaded_image(
+        &self,
+        mut img: DynamicImage,
+        scale_mode: ScaleMode,
+        resize_quality: ResizeQuality,
+        dither_mode: DitherMode,
+        depth: PixelDepth,
+        brightness: Option<i32>,
+        contrast: Option<f32>,
+        transform: Option<Transform>,
+        invert: bool,
+    ) -> Result<Vec<u8>, DisplayError> {
         // Apply transformation if specified
         if let Some(t) = transform {
             img = self.transform(&img, t);
@@ -450,21 +912,50 @@ impl ImageProcessor {
         }
 
         // Scale to display dimensions
-        img = self.scale(&img, scale_mode);
+        img = self.scale(&img, scale_mode, resize_quality);
 
         // Convert to grayscale
         let gray = self.to_grayscale(&img);
 
-        // Apply dithering to get 1-bit data
-        let mut data = self.dither(&gray, dither_mode);
+        // Apply dithering to get depth-bit packed data
+        let mut data = self.dither(&gray, dither_mode, depth);
+
+        // Transpose from the logical orientation just scaled/dithered into
+        // into the panel's native RAM layout (bit-level rotation only makes
+        // sense for 1-bit output; multi-level output is unaffected).
+        if depth == PixelDepth::One {
+            data = self.rotate_to_native(&data);
+        }
 
-        // Invert if requested
-        if invert {
+        // Invert if requested (bitwise inversion only makes sense for 1-bit
+        // output; multi-level output is unaffected)
+        if invert && depth == PixelDepth::One {
             data = self.invert_1bit(&data);
         }
 
         Ok(data)
     }
+
+    /// Rotate a 1-bit buffer sized for the panel's logical (rotated)
+    /// dimensions into its native RAM layout, per `self.spec.rotation`.
+    pub(crate) fn rotate_to_native(&self, data: &[u8]) -> Vec<u8> {
+        let (logical_width, logical_height) =
+            (self.spec.logical_width(), self.spec.logical_height());
+
+        match self.spec.rotation {
+            Rotation::Rotate0 => data.to_vec(),
+            Rotation::Rotate90 => self.rotate_1bit_90(data, logical_width, logical_height),
+            Rotation::Rotate180 => {
+                let once = self.rotate_1bit_90(data, logical_width, logical_height);
+                self.rotate_1bit_90(&once, logical_height, logical_width)
+            },
+            Rotation::Rotate270 => {
+                let once = self.rotate_1bit_90(data, logical_width, logical_height);
+                let twice = self.rotate_1bit_90(&once, logical_height, logical_width);
+                self.rotate_1bit_90(&twice, logical_width, logical_height)
+            },
+        }
+    }
 }
 
 /// Text renderer for drawing text on 1-bit images
@@ -670,6 +1161,551 @@ impl ShapeDrawer {
             self.set_pixel(buffer, x, py, value);
         }
     }
+
+    /// Draw an arbitrary-angle line from `(x0, y0)` to `(x1, y1)` using
+    /// Bresenham's integer algorithm.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_line(&self, buffer: &mut [u8], x0: i32, y0: i32, x1: i32, y1: i32, value: bool) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (x0, y0);
+
+        loop {
+            self.set_pixel_signed(buffer, x, y, value);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Draw a circle outline centered at `(cx, cy)` with radius `r` using
+    /// the midpoint circle algorithm (eight-way symmetry).
+    pub fn draw_circle(&self, buffer: &mut [u8], cx: i32, cy: i32, r: i32, value: bool) {
+        let mut x = 0;
+        let mut y = r;
+        let mut d = 1 - r;
+
+        self.plot_circle_points(buffer, cx, cy, x, y, value);
+        while x < y {
+            x += 1;
+            if d < 0 {
+                d += 2 * x + 1;
+            } else {
+                y -= 1;
+                d += 2 * (x - y) + 1;
+            }
+            self.plot_circle_points(buffer, cx, cy, x, y, value);
+        }
+    }
+
+    /// Draw a filled circle centered at `(cx, cy)` with radius `r`.
+    pub fn draw_circle_filled(&self, buffer: &mut [u8], cx: i32, cy: i32, r: i32, value: bool) {
+        let mut x = 0;
+        let mut y = r;
+        let mut d = 1 - r;
+
+        self.fill_circle_row(buffer, cx, cy, x, y, value);
+        while x < y {
+            x += 1;
+            if d < 0 {
+                d += 2 * x + 1;
+            } else {
+                y -= 1;
+                d += 2 * (x - y) + 1;
+            }
+            self.fill_circle_row(buffer, cx, cy, x, y, value);
+        }
+    }
+
+    /// Draw an ellipse outline centered at `(cx, cy)` with radii `rx`/`ry`
+    /// using the midpoint ellipse algorithm (four-way symmetry, two
+    /// decision regions split where the tangent slope crosses -1).
+    pub fn draw_ellipse(&self, buffer: &mut [u8], cx: i32, cy: i32, rx: i32, ry: i32, value: bool) {
+        if rx <= 0 || ry <= 0 {
+            return;
+        }
+
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let mut x = 0i32;
+        let mut y = ry;
+        let mut px = 0i32;
+        let mut py = 2 * rx2 * y;
+
+        self.plot_ellipse_points(buffer, cx, cy, x, y, value);
+
+        // Region 1: tangent slope magnitude < 1
+        let mut p = ry2 as f64 - (rx2 * ry) as f64 + 0.25 * rx2 as f64;
+        while px < py {
+            x += 1;
+            px += 2 * ry2;
+            if p < 0.0 {
+                p += (ry2 + px) as f64;
+            } else {
+                y -= 1;
+                py -= 2 * rx2;
+                p += (ry2 + px - py) as f64;
+            }
+            self.plot_ellipse_points(buffer, cx, cy, x, y, value);
+        }
+
+        // Region 2: tangent slope magnitude >= 1
+        let mut p2 = ry2 as f64 * (x as f64 + 0.5).powi(2) + rx2 as f64 * (y - 1).pow(2) as f64 - (rx2 * ry2) as f64;
+        while y > 0 {
+            y -= 1;
+            py -= 2 * rx2;
+            if p2 > 0.0 {
+                p2 += (rx2 - py) as f64;
+            } else {
+                x += 1;
+                px += 2 * ry2;
+                p2 += (rx2 - py + px) as f64;
+            }
+            self.plot_ellipse_points(buffer, cx, cy, x, y, value);
+        }
+    }
+
+    /// Plot the four symmetric points of a midpoint-ellipse offset.
+    fn plot_ellipse_points(&self, buffer: &mut [u8], cx: i32, cy: i32, x: i32, y: i32, value: bool) {
+        self.set_pixel_signed(buffer, cx + x, cy + y, value);
+        self.set_pixel_signed(buffer, cx - x, cy + y, value);
+        self.set_pixel_signed(buffer, cx + x, cy - y, value);
+        self.set_pixel_signed(buffer, cx - x, cy - y, value);
+    }
+
+    /// Draw a polygon as connected Bresenham segments between consecutive
+    /// `points`, closing back to the first point.
+    pub fn draw_polygon(&self, buffer: &mut [u8], points: &[(u32, u32)], value: bool) {
+        if points.len() < 2 {
+            return;
+        }
+
+        for window in points.windows(2) {
+            let (x0, y0) = window[0];
+            let (x1, y1) = window[1];
+            self.draw_line(buffer, x0 as i32, y0 as i32, x1 as i32, y1 as i32, value);
+        }
+
+        let (last_x, last_y) = points[points.len() - 1];
+        let (first_x, first_y) = points[0];
+        self.draw_line(
+            buffer,
+            last_x as i32,
+            last_y as i32,
+            first_x as i32,
+            first_y as i32,
+            value,
+        );
+    }
+
+    /// Draw a filled polygon by outlining it and then scanline-filling
+    /// interior rows using the even-odd rule.
+    pub fn draw_polygon_filled(&self, buffer: &mut [u8], points: &[(u32, u32)], value: bool) {
+        if points.len() < 3 {
+            return;
+        }
+
+        let y_min = points.iter().map(|p| p.1).min().unwrap_or(0);
+        let y_max = points.iter().map(|p| p.1).max().unwrap_or(0).min(self.height.saturating_sub(1));
+
+        for y in y_min..=y_max {
+            let mut crossings: Vec<u32> = Vec::new();
+            for window_idx in 0..points.len() {
+                let (x0, y0) = points[window_idx];
+                let (x1, y1) = points[(window_idx + 1) % points.len()];
+                let (y0, y1, x0, x1) = (y0 as i64, y1 as i64, x0 as i64, x1 as i64);
+                let y_test = i64::from(y);
+
+                if (y0 <= y_test && y1 > y_test) || (y1 <= y_test && y0 > y_test) {
+                    let x = x0 + (y_test - y0) * (x1 - x0) / (y1 - y0);
+                    crossings.push(x.max(0) as u32);
+                }
+            }
+
+            crossings.sort_unstable();
+            for pair in crossings.chunks(2) {
+                if let [start, end] = *pair {
+                    self.draw_line_horizontal(buffer, start, y, end.saturating_sub(start) + 1, value);
+                }
+            }
+        }
+
+        self.draw_polygon(buffer, points, value);
+    }
+
+    /// Set a pixel, ignoring coordinates that are negative or out of bounds.
+    fn set_pixel_signed(&self, buffer: &mut [u8], x: i32, y: i32, value: bool) {
+        if x < 0 || y < 0 {
+            return;
+        }
+        self.set_pixel(buffer, x as u32, y as u32, value);
+    }
+
+    /// Plot the eight symmetric points of a midpoint-circle octant offset.
+    fn plot_circle_points(&self, buffer: &mut [u8], cx: i32, cy: i32, x: i32, y: i32, value: bool) {
+        self.set_pixel_signed(buffer, cx + x, cy + y, value);
+        self.set_pixel_signed(buffer, cx - x, cy + y, value);
+        self.set_pixel_signed(buffer, cx + x, cy - y, value);
+        self.set_pixel_signed(buffer, cx - x, cy - y, value);
+        self.set_pixel_signed(buffer, cx + y, cy + x, value);
+        self.set_pixel_signed(buffer, cx - y, cy + x, value);
+        self.set_pixel_signed(buffer, cx + y, cy - x, value);
+        self.set_pixel_signed(buffer, cx - y, cy - x, value);
+    }
+
+    /// Fill the four symmetric horizontal spans of a midpoint-circle octant
+    /// offset, used to rasterize a filled circle row by row.
+    fn fill_circle_row(&self, buffer: &mut [u8], cx: i32, cy: i32, x: i32, y: i32, value: bool) {
+        self.fill_span(buffer, cx - x, cx + x, cy + y, value);
+        self.fill_span(buffer, cx - x, cx + x, cy - y, value);
+        self.fill_span(buffer, cx - y, cx + y, cy + x, value);
+        self.fill_span(buffer, cx - y, cx + y, cy - x, value);
+    }
+
+    /// Fill a horizontal span `[x_start, x_end]` at row `y`, clipped to the
+    /// buffer bounds.
+    fn fill_span(&self, buffer: &mut [u8], x_start: i32, x_end: i32, y: i32, value: bool) {
+        if y < 0 {
+            return;
+        }
+        for x in x_start.max(0)..=x_end {
+            self.set_pixel_signed(buffer, x, y, value);
+        }
+    }
+}
+
+/// QR code rendering onto 1-bit buffers, following the same
+/// construction/usage pattern as [`TextRenderer`] and [`ShapeDrawer`]: a
+/// renderer is sized to a panel, then either builds a fresh full-panel
+/// buffer ([`QrRenderer::render_qr`]) or stamps onto a caller-owned one
+/// ([`QrRenderer::overlay_qr`]).
+///
+/// The actual symbol encoding (mode selection, Reed-Solomon error
+/// correction, mask selection) lives in [`crate::qr`]; this type is
+/// responsible for expanding the resulting modules into
+/// `module_size`-by-`module_size` pixel blocks via [`ShapeDrawer`].
+pub struct QrRenderer {
+    width: u32,
+    height: u32,
+}
+
+impl QrRenderer {
+    /// Create a new QR renderer for the given panel dimensions.
+    #[must_use]
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Render `text` as a QR code into a new full-panel 1-bit buffer,
+    /// positioned with its top-left quiet-zone corner at `(x, y)`.
+    ///
+    /// Returns `Ok(None)` if `text` is too large for the smallest QR
+    /// version that fits this panel at the given `module_size` and
+    /// `quiet_zone`, rather than an error — the caller asked for a QR
+    /// code that simply cannot be drawn here.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if the underlying symbol encoding fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_qr(
+        &self,
+        text: &str,
+        x: u32,
+        y: u32,
+        module_size: u32,
+        quiet_zone: u32,
+        ec_level: crate::qr::EcLevel,
+    ) -> Result<Option<Vec<u8>>, DisplayError> {
+        let buffer_size = ((self.width * self.height) / 8) as usize;
+        let mut buffer = vec![0u8; buffer_size];
+
+        if self.overlay_qr(&mut buffer, text, x, y, module_size, quiet_zone, ec_level)? {
+            Ok(Some(buffer))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Render `text` as a QR code into `buffer`, positioned with its
+    /// top-left quiet-zone corner at `(x, y)`.
+    ///
+    /// Returns `Ok(false)` without modifying `buffer` if `text` is too
+    /// large for the smallest QR version that fits this panel at the
+    /// given `module_size` and `quiet_zone`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if the underlying symbol encoding fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn overlay_qr(
+        &self,
+        buffer: &mut [u8],
+        text: &str,
+        x: u32,
+        y: u32,
+        module_size: u32,
+        quiet_zone: u32,
+        ec_level: crate::qr::EcLevel,
+    ) -> Result<bool, DisplayError> {
+        let Some(matrix) = crate::qr::encode(text, ec_level)? else {
+            return Ok(false);
+        };
+
+        let total_modules = matrix.size as u32 + quiet_zone * 2;
+        let pixel_size = total_modules * module_size;
+        if x + pixel_size > self.width || y + pixel_size > self.height {
+            return Ok(false);
+        }
+
+        let shapes = ShapeDrawer::new(self.width, self.height);
+        let origin_x = x + quiet_zone * module_size;
+        let origin_y = y + quiet_zone * module_size;
+
+        for row in 0..matrix.size {
+            for col in 0..matrix.size {
+                if matrix.get(col, row) {
+                    shapes.draw_rect_filled(
+                        buffer,
+                        origin_x + col as u32 * module_size,
+                        origin_y + row as u32 * module_size,
+                        module_size,
+                        module_size,
+                        true,
+                    );
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Boolean raster operations for combining two packed 1-bit buffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasterOp {
+    /// Replace `dst` with `src`.
+    Copy,
+    /// `dst &= src`
+    And,
+    /// `dst |= src`
+    Or,
+    /// `dst ^= src`
+    Xor,
+    /// `dst &= !src`
+    AndNot,
+    /// `dst = !(dst | src)`
+    Nor,
+}
+
+/// Byte-wise compositor for combining packed 1-bit framebuffers, useful for
+/// building sprites, cursors, and partial-refresh dirty regions out of
+/// separately rendered layers.
+pub struct Compositor;
+
+impl Compositor {
+    /// Apply `op` byte-wise to combine `src` into `dst`. Both buffers must
+    /// have identical length.
+    pub fn combine(dst: &mut [u8], src: &[u8], op: RasterOp) {
+        let len = dst.len().min(src.len());
+        for i in 0..len {
+            dst[i] = Self::apply(dst[i], src[i], op);
+        }
+    }
+
+    /// Apply `op` to combine `src` into `dst`, but only where the
+    /// corresponding `mask` byte is nonzero, so a region can be stamped
+    /// without touching surrounding bits.
+    pub fn combine_masked(dst: &mut [u8], src: &[u8], mask: &[u8], op: RasterOp) {
+        let len = dst.len().min(src.len()).min(mask.len());
+        for i in 0..len {
+            if mask[i] == 0 {
+                continue;
+            }
+            dst[i] = Self::apply(dst[i], src[i], op);
+        }
+    }
+
+    fn apply(dst: u8, src: u8, op: RasterOp) -> u8 {
+        match op {
+            RasterOp::Copy => src,
+            RasterOp::And => dst & src,
+            RasterOp::Or => dst | src,
+            RasterOp::Xor => dst ^ src,
+            RasterOp::AndNot => dst & !src,
+            RasterOp::Nor => !(dst | src),
+        }
+    }
+
+    /// Composite a `src_width`x`src_height` 1-bit bitmap onto `dst` (a
+    /// `dst_width`x`dst_height` 1-bit buffer) at `(x, y)`, applying `op`
+    /// pixel by pixel. Unlike [`Self::combine`], `src` and `dst` need not
+    /// be the same size — this is how an independently-rendered sprite or
+    /// icon gets stamped onto a cached background, clipping wherever
+    /// `src` would overhang `dst`'s edges.
+    #[allow(clippy::too_many_arguments)]
+    pub fn composite_at(
+        dst: &mut [u8],
+        dst_width: u32,
+        dst_height: u32,
+        src: &[u8],
+        src_width: u32,
+        src_height: u32,
+        x: i32,
+        y: i32,
+        op: RasterOp,
+    ) {
+        for sy in 0..src_height {
+            let Some(dy) = y.checked_add_unsigned(sy).filter(|&dy| dy >= 0 && (dy as u32) < dst_height) else {
+                continue;
+            };
+
+            for sx in 0..src_width {
+                let Some(dx) = x.checked_add_unsigned(sx).filter(|&dx| dx >= 0 && (dx as u32) < dst_width) else {
+                    continue;
+                };
+
+                let src_bit = get_bit(src, src_width, sx, sy);
+                let dst_idx = (dy as u32 * dst_width + dx as u32) as usize;
+                let byte_idx = dst_idx / 8;
+                let bit_idx = dst_idx % 8;
+                let Some(byte) = dst.get_mut(byte_idx) else {
+                    continue;
+                };
+
+                let dst_bit = (*byte >> (7 - bit_idx)) & 1 == 1;
+                let new_bit = match op {
+                    RasterOp::Copy => src_bit,
+                    RasterOp::And => dst_bit && src_bit,
+                    RasterOp::Or => dst_bit || src_bit,
+                    RasterOp::Xor => dst_bit ^ src_bit,
+                    RasterOp::AndNot => dst_bit && !src_bit,
+                    RasterOp::Nor => !(dst_bit || src_bit),
+                };
+
+                if new_bit {
+                    *byte |= 1 << (7 - bit_idx);
+                } else {
+                    *byte &= !(1 << (7 - bit_idx));
+                }
+            }
+        }
+    }
+}
+
+/// Read a single bit from a packed 1-bit buffer at `(x, y)` for a bitmap
+/// of the given `width`, out-of-bounds reads treated as `false`.
+fn get_bit(buffer: &[u8], width: u32, x: u32, y: u32) -> bool {
+    let idx = (y * width + x) as usize;
+    let byte_idx = idx / 8;
+    let bit_idx = idx % 8;
+    buffer.get(byte_idx).is_some_and(|&b| (b >> (7 - bit_idx)) & 1 == 1)
+}
+
+/// Build a PNG chunk: 4-byte big-endian length, 4-byte type, data, then a
+/// CRC-32 over type+data.
+fn png_chunk(chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut chunk = Vec::with_capacity(data.len() + 12);
+    chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let mut type_and_data = Vec::with_capacity(chunk_type.len() + data.len());
+    type_and_data.extend_from_slice(chunk_type);
+    type_and_data.extend_from_slice(data);
+
+    chunk.extend_from_slice(&type_and_data);
+    chunk.extend_from_slice(&crc32(&type_and_data).to_be_bytes());
+    chunk
+}
+
+/// Build the IHDR chunk body for a 1-bit-depth grayscale image.
+fn ihdr_data(width: u32, height: u32) -> Vec<u8> {
+    let mut data = Vec::with_capacity(13);
+    data.extend_from_slice(&width.to_be_bytes());
+    data.extend_from_slice(&height.to_be_bytes());
+    data.push(1); // Bit depth
+    data.push(0); // Color type: grayscale
+    data.push(0); // Compression method: deflate
+    data.push(0); // Filter method: adaptive (per-scanline filter byte)
+    data.push(0); // Interlace method: none
+    data
+}
+
+/// Wrap `raw` in a zlib stream using stored (uncompressed) DEFLATE blocks,
+/// so no compression library is needed.
+fn zlib_stored(raw: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(raw.len() + raw.len() / 65535 * 5 + 11);
+    out.push(0x78); // CMF: deflate, 32K window
+    out.push(0x01); // FLG: check bits for 0x78 (fastest compression level)
+
+    const MAX_BLOCK: usize = 65535;
+    let mut offset = 0;
+    if raw.is_empty() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while offset < raw.len() {
+        let end = (offset + MAX_BLOCK).min(raw.len());
+        let is_final = end == raw.len();
+        let block = &raw[offset..end];
+
+        out.push(u8::from(is_final)); // BFINAL/BTYPE=00 (stored)
+        let len = block.len() as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+
+        offset = end;
+    }
+
+    out.extend_from_slice(&adler32(raw).to_be_bytes());
+    out
+}
+
+/// Compute the Adler-32 checksum of `data`.
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+
+    for &byte in data {
+        a = (a + u32::from(byte)) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+
+    (b << 16) | a
+}
+
+/// Compute the standard CRC-32 (reflected `0xEDB88320` polynomial) of `data`.
+fn crc32(data: &[u8]) -> u32 {
+    fn table() -> [u32; 256] {
+        let mut table = [0u32; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut c = i as u32;
+            for _ in 0..8 {
+                c = if c & 1 != 0 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+            }
+            *entry = c;
+        }
+        table
+    }
+
+    let table = table();
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        let idx = ((crc ^ u32::from(byte)) & 0xFF) as usize;
+        crc = table[idx] ^ (crc >> 8);
+    }
+    crc ^ 0xFFFF_FFFF
 }
 
 #[cfg(test)]
@@ -683,6 +1719,7 @@ mod tests {
             height: 250,
             name: "Test".to_string(),
             description: "Test display".to_string(),
+            rotation: crate::firmware::Rotation::Rotate0,
         };
         let processor = ImageProcessor::new(spec);
 
@@ -700,6 +1737,7 @@ mod tests {
             height: 250,
             name: "Test".to_string(),
             description: "Test display".to_string(),
+            rotation: crate::firmware::Rotation::Rotate0,
         };
         let processor = ImageProcessor::new(spec);
 
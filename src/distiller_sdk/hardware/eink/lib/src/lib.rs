@@ -0,0 +1,90 @@
+//! Rust SDK for controlling e-ink displays via SPI interface on ARM64 Linux
+//! systems.
+//!
+//! This library provides a comprehensive interface for e-ink display control
+//! including hardware abstraction, firmware variants, image processing, and
+//! configuration management.
+
+#![warn(clippy::all)]
+#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::cast_possible_truncation)]
+#![allow(clippy::cast_sign_loss)]
+
+/// Zip-archive-backed image sources (`display_image_from_archive*`)
+pub mod archive;
+/// Binary command-stream format for batching display operations
+pub mod command_stream;
+pub mod config;
+/// Busy-wait timing stats surfaced via `display_stats`
+pub mod diagnostics;
+pub mod display;
+/// `embedded-hal`-backed `DisplayDriver` for bare-metal/non-Linux targets
+pub mod embedded_driver;
+pub mod error;
+/// FFI bindings for C interoperability
+pub mod ffi;
+pub mod firmware;
+/// Data-driven firmware descriptor format, parser, and runtime registry
+pub mod firmware_descriptor;
+pub mod framebuffer;
+/// `embedded-graphics` `DrawTarget` for the 4-gray (2bpp) panel variants
+pub mod gray4_canvas;
+pub mod hardware;
+pub mod image;
+pub mod image_processing;
+/// Event notification callbacks for display state changes
+pub mod notify;
+pub mod protocol;
+/// QR code matrix generation
+pub mod qr;
+/// Boot splash image display
+pub mod splash;
+/// Text console rendering on top of an `EinkProtocol`
+pub mod text_console;
+/// Parser for vendor waveform files
+pub mod waveform;
+
+// Re-export public API
+pub use config::{
+    FirmwareType,
+    create_default_firmware,
+    get_default_firmware,
+    initialize_config,
+    set_default_firmware,
+    set_default_firmware_from_str,
+};
+pub use display::{DisplayDriver, GenericDisplay};
+// Re-export the main functions for backwards compatibility
+pub use display::{
+    display_cleanup,
+    display_clear,
+    display_framebuffer,
+    display_get_dimensions,
+    display_image_auto,
+    display_image_bytes,
+    display_image_file,
+    display_image_png,
+    display_image_raw,
+    display_image_region,
+    display_init,
+    display_sleep,
+    set_refresh_speed,
+};
+pub use error::DisplayError;
+pub use firmware::{Command, CommandSequence, DisplayFirmware, DisplaySpec, RefreshSpeed};
+pub use framebuffer::Framebuffer;
+#[cfg(feature = "linux")]
+pub use hardware::DefaultHardwareInterface;
+pub use hardware::{GpioController, HardwareInterface, SpiController};
+pub use image::{
+    convert_image_to_1bit,
+    convert_image_to_1bit_with_spec,
+    convert_png_to_1bit,
+    create_black_image,
+    create_white_image,
+    get_dimensions,
+};
+pub use image_processing::{DitherMode, ImageProcessor, ResizeQuality, ScaleMode, Transform};
+pub use protocol::{DisplayMode, EinkProtocol};
+
+// C FFI is automatically available through the ffi module
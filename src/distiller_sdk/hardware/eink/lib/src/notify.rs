@@ -0,0 +1,99 @@
+//! C callback registration for display lifecycle and error notifications.
+//!
+//! Failures used to only reach the Rust logger via `log::error!`, which a C
+//! host application can't observe structurally. This module mirrors a
+//! device-notifier pattern: a host registers a single callback via
+//! [`register`] and gets told about display state transitions and errors
+//! (e.g. a `BUSY_WAIT_TIMEOUT`) without scraping logs.
+
+use std::{cell::RefCell, os::raw::c_int, sync::Mutex};
+
+use crate::error::DisplayError;
+
+/// Event codes passed as a registered callback's `event` parameter.
+pub mod event {
+    /// The display was successfully initialized.
+    pub const INITIALIZED: i32 = 0;
+    /// A busy-wait loop on the BUSY line has started.
+    pub const BUSY_WAIT_STARTED: i32 = 1;
+    /// A busy-wait loop on the BUSY line timed out.
+    pub const BUSY_WAIT_TIMEOUT: i32 = 2;
+    /// The display entered sleep mode.
+    pub const ENTERED_SLEEP: i32 = 3;
+    /// Display resources were cleaned up.
+    pub const CLEANED_UP: i32 = 4;
+    /// An operation failed; `code` carries the FFI error code.
+    pub const ERROR: i32 = 5;
+}
+
+/// Function pointer type for a registered notification callback.
+pub type Callback = extern "C" fn(event: c_int, code: c_int);
+
+static CALLBACK: Mutex<Option<Callback>> = Mutex::new(None);
+
+thread_local! {
+    // Events raised from deep inside a call that is still holding the
+    // display mutex (e.g. a busy-wait loop in `protocol::check_status`) are
+    // queued here and only delivered once the caller in `display.rs` has
+    // released that mutex, via `flush`.
+    static PENDING: RefCell<Vec<(i32, i32)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Register (or, with `None`, clear) the callback invoked on display state
+/// transitions and errors.
+///
+/// # Re-entrancy and threading
+///
+/// The callback is never invoked while this crate's internal display mutex
+/// is held, so it is safe for the callback to call back into the display
+/// API — for example, retrying after a `BUSY_WAIT_TIMEOUT` event. It may
+/// run on whichever thread triggered the event. Registering a new callback
+/// replaces any previously registered one; the replacement takes effect for
+/// events raised after this call returns, and this function itself never
+/// holds the display mutex.
+pub fn register(cb: Option<Callback>) {
+    if let Ok(mut slot) = CALLBACK.lock() {
+        *slot = cb;
+    }
+}
+
+/// Queue `event`/`code` for delivery on the next [`flush`] on this thread.
+pub fn queue(event: i32, code: i32) {
+    PENDING.with(|pending| pending.borrow_mut().push((event, code)));
+}
+
+/// Deliver every event queued on this thread since the last flush, followed
+/// by `trailing` if given. Callers must invoke this only after releasing
+/// the display mutex.
+pub fn flush(trailing: Option<(i32, i32)>) {
+    let mut events = PENDING.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    events.extend(trailing);
+    if events.is_empty() {
+        return;
+    }
+
+    let Ok(cb) = CALLBACK.lock() else {
+        return;
+    };
+    if let Some(cb) = *cb {
+        for (event, code) in events {
+            cb(event as c_int, code as c_int);
+        }
+    }
+}
+
+/// Map a `DisplayError` to the FFI error code a C caller would see from the
+/// failing entry point (kept in sync with `ffi.rs`'s `ERR_*` constants).
+#[must_use]
+pub fn ffi_code(err: &DisplayError) -> i32 {
+    match err {
+        DisplayError::Gpio(_) => -1,
+        DisplayError::Spi(_) => -2,
+        DisplayError::Config(_) => -3,
+        DisplayError::Timeout => -4,
+        DisplayError::NotInitialized => -5,
+        DisplayError::InvalidDataSize { .. } => -6,
+        DisplayError::Png(_) => -7,
+        DisplayError::Io(_) => -8,
+    }
+}
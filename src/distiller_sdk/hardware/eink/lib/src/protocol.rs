@@ -17,6 +17,36 @@ pub enum DisplayMode {
     Partial = 1,
 }
 
+/// Which high-level firmware operation a [`CommandSequence`] belongs to, so
+/// [`GenericEinkProtocol::execute_sequence`] can tag its log lines with the
+/// right name — mirrors how firmware-loader subsystems centralize
+/// success/failure logging so individual drivers don't each roll their own
+/// messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Reset and main initialization sequence
+    Init,
+    /// Partial-refresh initialization sequence
+    PartialInit,
+    /// Display update (full or partial refresh)
+    Update,
+    /// Deep-sleep sequence
+    Sleep,
+}
+
+impl Operation {
+    /// Short, log-line-friendly name for this operation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Init => "init",
+            Self::PartialInit => "partial_init",
+            Self::Update => "update",
+            Self::Sleep => "sleep",
+        }
+    }
+}
+
 /// E-ink display protocol trait
 pub trait EinkProtocol {
     /// Initialize the display hardware
@@ -71,6 +101,20 @@ pub trait EinkProtocol {
     fn get_spec(&self) -> &crate::firmware::DisplaySpec;
     /// Get the write RAM command byte
     fn get_write_ram_command(&self) -> u8;
+    /// Install an externally-loaded waveform (see [`crate::waveform`]) by
+    /// replaying its command sequence, tagged as [`Operation::Update`] for
+    /// logging — the same structured per-step trace
+    /// [`GenericEinkProtocol::execute_sequence`] already produces for the
+    /// built-in update sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if any command/data write in the sequence fails
+    fn load_waveform(&mut self, sequence: CommandSequence) -> Result<(), DisplayError>;
+    /// Select the waveform LUT subsequent partial updates use, trading
+    /// image quality/ghosting for speed (see
+    /// [`crate::firmware::RefreshSpeed`]). Full refreshes are unaffected.
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed);
 }
 
 /// Generic E-ink protocol implementation using firmware abstraction
@@ -86,95 +130,156 @@ impl<G: GpioController, S: SpiController, F: DisplayFirmware> GenericEinkProtoco
         Self { hardware, firmware }
     }
 
-    /// Execute a command sequence
-    fn execute_sequence(&mut self, sequence: CommandSequence) -> Result<(), DisplayError> {
+    /// Execute a command sequence belonging to high-level `op`, logging one
+    /// structured trace line per `check_status`/`cmd`/`data`/`delay` step
+    /// (tagged with the firmware name, the operation, the command opcode
+    /// where relevant, and the outcome) plus one summary line for the
+    /// operation as a whole, so a support engineer can pinpoint exactly
+    /// which opcode or status poll failed on which panel.
+    fn execute_sequence(
+        &mut self,
+        sequence: CommandSequence,
+        op: Operation,
+    ) -> Result<(), DisplayError> {
+        let firmware_name = self.firmware.get_spec().name.clone();
+        let op_name = op.as_str();
+
         for command in sequence.commands {
-            match command {
-                Command::WriteCommand(cmd) => self.write_cmd(cmd)?,
-                Command::WriteData(data) => self.write_data(data)?,
-                Command::Delay(ms) => delay_ms(ms),
-                Command::CheckStatus => self.check_status()?,
+            let (step, opcode, result) = match command {
+                Command::WriteCommand(cmd) => ("cmd", Some(cmd), self.write_cmd(cmd)),
+                Command::WriteData(data) => ("data", Some(data), self.write_data(data)),
+                Command::Delay(ms) => {
+                    delay_ms(ms);
+                    ("delay", None, Ok(()))
+                },
+                Command::CheckStatus => ("check_status", None, self.check_status()),
                 Command::Reset => {
-                    self.hardware.write_rst(false)?;
-                    delay_ms(10);
-                    self.hardware.write_rst(true)?;
-                    delay_ms(10);
+                    let result = self.hardware.write_rst(false).and_then(|()| {
+                        delay_ms(10);
+                        self.hardware.write_rst(true)
+                    });
+                    if result.is_ok() {
+                        delay_ms(10);
+                    }
+                    ("reset", None, result)
                 },
+            };
+
+            log_step(&firmware_name, op_name, step, opcode, &result);
+            if let Err(e) = result {
+                log::error!("firmware={firmware_name} op={op_name} outcome=error error={e}");
+                return Err(e);
             }
         }
+
+        log::info!("firmware={firmware_name} op={op_name} outcome=ok");
         Ok(())
     }
 }
 
+/// Log a single structured trace line for one [`GenericEinkProtocol::execute_sequence`]
+/// step.
+fn log_step(
+    firmware_name: &str,
+    op: &str,
+    step: &str,
+    opcode: Option<u8>,
+    result: &Result<(), DisplayError>,
+) {
+    let opcode = opcode.map_or_else(String::new, |c| format!(" opcode=0x{c:02X}"));
+    match result {
+        Ok(()) => log::debug!("firmware={firmware_name} op={op} step={step}{opcode} outcome=ok"),
+        Err(DisplayError::Timeout) => log::warn!(
+            "firmware={firmware_name} op={op} step={step}{opcode} outcome=busy-timeout"
+        ),
+        Err(e) => log::warn!(
+            "firmware={firmware_name} op={op} step={step}{opcode} outcome=error error={e}"
+        ),
+    }
+}
+
 impl<G: GpioController, S: SpiController, F: DisplayFirmware> EinkProtocol
     for GenericEinkProtocol<G, S, F>
 {
     fn init_hardware(&mut self) -> Result<(), DisplayError> {
         // Execute reset sequence first
         let reset_sequence = self.firmware.get_reset_sequence();
-        self.execute_sequence(reset_sequence)?;
+        self.execute_sequence(reset_sequence, Operation::Init)?;
 
         // Execute main initialization sequence
         let init_sequence = self.firmware.get_init_sequence();
-        self.execute_sequence(init_sequence)?;
+        self.execute_sequence(init_sequence, Operation::Init)?;
 
         Ok(())
     }
 
     fn init_partial(&mut self) -> Result<(), DisplayError> {
         let partial_sequence = self.firmware.get_partial_init_sequence();
-        self.execute_sequence(partial_sequence)?;
+        self.execute_sequence(partial_sequence, Operation::PartialInit)?;
         Ok(())
     }
 
     fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
         delay_us(10);
-        self.hardware.write_dc(false)?;
-        self.hardware.spi_write_all(&[cmd])?;
-        Ok(())
+        self.hardware.write_cs(false)?;
+        let result = self
+            .hardware
+            .write_dc(false)
+            .and_then(|()| self.hardware.spi_write_all(&[cmd]));
+        self.hardware.write_cs(true)?;
+        result
     }
 
     fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
         delay_us(10);
-        self.hardware.write_dc(true)?;
-        self.hardware.spi_write_all(&[data])?;
-        Ok(())
+        self.hardware.write_cs(false)?;
+        let result = self
+            .hardware
+            .write_dc(true)
+            .and_then(|()| self.hardware.spi_write_all(&[data]));
+        self.hardware.write_cs(true)?;
+        result
     }
 
     fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
         // Validate image size using firmware
         self.firmware.validate_image_size(data)?;
 
-        self.hardware.write_dc(true)?;
-        self.hardware.spi_write_all(data)?;
-        Ok(())
+        self.hardware.write_cs(false)?;
+        let result = self
+            .hardware
+            .write_dc(true)
+            .and_then(|()| self.hardware.spi_write_all(data));
+        self.hardware.write_cs(true)?;
+        result
     }
 
     fn check_status(&mut self) -> Result<(), DisplayError> {
-        let mut watchdog_counter = 0;
-        while self.hardware.read_busy()? && watchdog_counter < 1000 {
-            delay_ms(10);
-            watchdog_counter += 1;
-        }
+        crate::notify::queue(crate::notify::event::BUSY_WAIT_STARTED, 0);
 
-        if watchdog_counter >= 1000 {
+        let timeout_ms = crate::config::get_hardware_config()?.busy_timeout_ms;
+        let start = std::time::Instant::now();
+        let result = self.hardware.wait_busy_low(timeout_ms);
+        crate::diagnostics::record_busy_wait(start.elapsed());
+
+        if let Err(DisplayError::Timeout) = result {
             log::warn!("Display busy timeout");
-            return Err(DisplayError::Timeout);
+            crate::notify::queue(crate::notify::event::BUSY_WAIT_TIMEOUT, crate::notify::ffi_code(&DisplayError::Timeout));
         }
 
-        Ok(())
+        result
     }
 
     fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
         let is_partial = matches!(mode, DisplayMode::Partial);
         let update_sequence = self.firmware.get_update_sequence(is_partial);
-        self.execute_sequence(update_sequence)?;
+        self.execute_sequence(update_sequence, Operation::Update)?;
         Ok(())
     }
 
     fn sleep(&mut self) -> Result<(), DisplayError> {
         let sleep_sequence = self.firmware.get_sleep_sequence();
-        self.execute_sequence(sleep_sequence)?;
+        self.execute_sequence(sleep_sequence, Operation::Sleep)?;
         Ok(())
     }
 
@@ -185,11 +290,136 @@ impl<G: GpioController, S: SpiController, F: DisplayFirmware> EinkProtocol
     fn get_write_ram_command(&self) -> u8 {
         self.firmware.get_write_ram_command()
     }
+
+    fn load_waveform(&mut self, sequence: CommandSequence) -> Result<(), DisplayError> {
+        self.execute_sequence(sequence, Operation::Update)
+    }
+
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed) {
+        self.firmware.set_refresh_speed(speed);
+    }
+}
+
+impl<G: GpioController, S: SpiController>
+    GenericEinkProtocol<G, S, crate::firmware::EPD128x250Firmware>
+{
+    /// Program the partial-update window to `(x, y, w, h)` via the Ram-X
+    /// address (`0x44`), Ram-Y address (`0x45`), and address counter
+    /// (`0x4E`/`0x4F`) commands, stream `data` into it, and trigger a
+    /// partial refresh — instead of writing and refreshing the full panel.
+    ///
+    /// `x` and `w` must already be 8-pixel (one byte) aligned; callers
+    /// validate this ahead of time (see
+    /// [`crate::display::GenericDisplay::display_image_region`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if programming the window, writing `data`,
+    /// or triggering the refresh fails.
+    pub fn write_window(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        data: &[u8],
+    ) -> Result<(), DisplayError> {
+        let window_sequence = self
+            .firmware
+            .get_window_sequence(u32::from(x), u32::from(y), u32::from(w), u32::from(h));
+        self.execute_sequence(window_sequence, Operation::Update)?;
+
+        let write_ram_cmd = self.firmware.get_write_ram_command();
+        self.write_cmd(write_ram_cmd)?;
+
+        self.hardware.write_cs(false)?;
+        let result = self
+            .hardware
+            .write_dc(true)
+            .and_then(|()| self.hardware.spi_write_all(data));
+        self.hardware.write_cs(true)?;
+        result?;
+
+        let update_sequence = self.firmware.get_update_sequence(true);
+        self.execute_sequence(update_sequence, Operation::Update)
+    }
+}
+
+impl<G: GpioController, S: SpiController>
+    GenericEinkProtocol<G, S, crate::firmware::EPD240x416Firmware>
+{
+    /// Program the partial-update window to `(x, y, w, h)` and stream
+    /// `data` into it, instead of writing and refreshing the full panel.
+    ///
+    /// `data` must already be tightly packed for the byte-aligned window
+    /// (no per-row padding), and is written directly rather than through
+    /// [`EinkProtocol::write_image_data`], since that method validates
+    /// against the full-panel size from the firmware's `DisplaySpec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if the window falls outside the panel bounds,
+    /// or if programming the window or writing `data` fails.
+    pub fn write_window(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        data: &[u8],
+    ) -> Result<(), DisplayError> {
+        let window_sequence = self.firmware.get_partial_window_sequence(x, y, w, h)?;
+        self.execute_sequence(window_sequence, Operation::Update)?;
+
+        let write_ram_cmd = self.firmware.get_write_ram_command();
+        self.write_cmd(write_ram_cmd)?;
+
+        self.hardware.write_cs(false)?;
+        let result = self
+            .hardware
+            .write_dc(true)
+            .and_then(|()| self.hardware.spi_write_all(data));
+        self.hardware.write_cs(true)?;
+        result
+    }
+}
+
+impl ConfigurableProtocol {
+    /// Program the partial-update window to `(x, y, w, h)` and stream
+    /// `data` into it, for firmware variants that support RAM-window
+    /// addressing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if programming the window or writing `data`
+    /// fails.
+    pub fn write_window(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        data: &[u8],
+    ) -> Result<(), DisplayError> {
+        match self {
+            #[cfg(feature = "linux")]
+            Self::EPD128x250(p) => p.write_window(x, y, w, h, data),
+            #[cfg(feature = "linux")]
+            Self::EPD240x416(p) => p.write_window(x, y, w, h, data),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.write_window(x, y, w, h, data),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.write_window(x, y, w, h, data),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
+        }
+    }
 }
 
 /// Runtime firmware selection supporting multiple display types
 pub enum ConfigurableProtocol {
     /// 128x250 display protocol
+    #[cfg(feature = "linux")]
     EPD128x250(
         Box<
             GenericEinkProtocol<
@@ -200,6 +430,7 @@ pub enum ConfigurableProtocol {
         >,
     ),
     /// 240x416 display protocol
+    #[cfg(feature = "linux")]
     EPD240x416(
         Box<
             GenericEinkProtocol<
@@ -209,81 +440,225 @@ pub enum ConfigurableProtocol {
             >,
         >,
     ),
+    /// 128x250 display protocol driven over a CP2130 USB-SPI bridge
+    #[cfg(feature = "usb-spi")]
+    EPD128x250Usb(
+        Box<
+            GenericEinkProtocol<
+                crate::hardware::cp2130::Cp2130GpioController,
+                crate::hardware::cp2130::Cp2130SpiController,
+                crate::firmware::EPD128x250Firmware,
+            >,
+        >,
+    ),
+    /// 240x416 display protocol driven over a CP2130 USB-SPI bridge
+    #[cfg(feature = "usb-spi")]
+    EPD240x416Usb(
+        Box<
+            GenericEinkProtocol<
+                crate::hardware::cp2130::Cp2130GpioController,
+                crate::hardware::cp2130::Cp2130SpiController,
+                crate::firmware::EPD240x416Firmware,
+            >,
+        >,
+    ),
+    /// Keeps the enum non-empty (so matches on `&ConfigurableProtocol`
+    /// type-check) when neither `linux` nor `usb-spi` is enabled; never
+    /// actually constructed, since nothing can produce an
+    /// [`std::convert::Infallible`] value.
+    #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+    Unreachable(std::convert::Infallible),
 }
 
 impl EinkProtocol for ConfigurableProtocol {
     fn init_hardware(&mut self) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.init_hardware(),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.init_hardware(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.init_hardware(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.init_hardware(),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn init_partial(&mut self) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.init_partial(),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.init_partial(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.init_partial(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.init_partial(),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.write_cmd(cmd),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.write_cmd(cmd),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.write_cmd(cmd),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.write_cmd(cmd),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.write_data(data),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.write_data(data),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.write_data(data),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.write_data(data),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.write_image_data(data),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.write_image_data(data),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.write_image_data(data),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.write_image_data(data),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn check_status(&mut self) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.check_status(),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.check_status(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.check_status(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.check_status(),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.update_display(mode),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.update_display(mode),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.update_display(mode),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.update_display(mode),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn sleep(&mut self) -> Result<(), DisplayError> {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.sleep(),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.sleep(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.sleep(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.sleep(),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn get_spec(&self) -> &crate::firmware::DisplaySpec {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.get_spec(),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.get_spec(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.get_spec(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.get_spec(),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 
     fn get_write_ram_command(&self) -> u8 {
         match self {
+            #[cfg(feature = "linux")]
             Self::EPD128x250(p) => p.get_write_ram_command(),
+            #[cfg(feature = "linux")]
             Self::EPD240x416(p) => p.get_write_ram_command(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.get_write_ram_command(),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.get_write_ram_command(),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
+        }
+    }
+
+    fn load_waveform(&mut self, sequence: CommandSequence) -> Result<(), DisplayError> {
+        match self {
+            #[cfg(feature = "linux")]
+            Self::EPD128x250(p) => p.load_waveform(sequence),
+            #[cfg(feature = "linux")]
+            Self::EPD240x416(p) => p.load_waveform(sequence),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.load_waveform(sequence),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.load_waveform(sequence),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
+        }
+    }
+
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed) {
+        match self {
+            #[cfg(feature = "linux")]
+            Self::EPD128x250(p) => p.set_refresh_speed(speed),
+            #[cfg(feature = "linux")]
+            Self::EPD240x416(p) => p.set_refresh_speed(speed),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD128x250Usb(p) => p.set_refresh_speed(speed),
+            #[cfg(feature = "usb-spi")]
+            Self::EPD240x416Usb(p) => p.set_refresh_speed(speed),
+            #[cfg(not(any(feature = "linux", feature = "usb-spi")))]
+            Self::Unreachable(never) => match *never {},
         }
     }
 }
 
 /// Default protocol type using configurable firmware
+///
+/// The non-USB variants (`EPD128x250`, `EPD240x416`) are built on
+/// `DefaultGpioController`/`DefaultSpiController`, which require the
+/// `linux` feature (see `hardware.rs`). Bare-metal targets that disable
+/// `linux` should build a `GenericEinkProtocol` directly over
+/// `embedded_hal_backend`'s adapters instead of going through this type.
 pub type DefaultProtocol = ConfigurableProtocol;
 
 /// Create a default protocol using the configured firmware type
@@ -292,25 +667,63 @@ pub type DefaultProtocol = ConfigurableProtocol;
 ///
 /// Returns `DisplayError` if hardware initialization fails
 pub fn create_default_protocol() -> Result<DefaultProtocol, DisplayError> {
-    let hardware = crate::hardware::DefaultHardwareInterface::new()?;
-
     // Get the configured firmware type and create appropriate protocol variant
     let firmware_type = crate::config::get_default_firmware().unwrap_or_else(|e| {
         log::warn!("Failed to get configured firmware: {e}, using EPD128x250");
         crate::config::FirmwareType::EPD128x250
     });
 
-    match firmware_type {
-        crate::config::FirmwareType::EPD128x250 => {
-            let firmware = crate::firmware::EPD128x250Firmware::new();
-            let protocol = GenericEinkProtocol::new(hardware, firmware);
-            Ok(ConfigurableProtocol::EPD128x250(Box::new(protocol)))
-        },
-        crate::config::FirmwareType::EPD240x416 => {
-            let firmware = crate::firmware::EPD240x416Firmware::new();
-            let protocol = GenericEinkProtocol::new(hardware, firmware);
-            Ok(ConfigurableProtocol::EPD240x416(Box::new(protocol)))
-        },
+    let backend = crate::config::get_hardware_backend().unwrap_or_else(|e| {
+        log::warn!("Failed to get configured hardware backend: {e}, using native");
+        crate::config::HardwareBackend::Native
+    });
+
+    #[cfg(feature = "usb-spi")]
+    if backend == crate::config::HardwareBackend::UsbCp2130 {
+        let hardware = crate::hardware::cp2130::Cp2130HardwareInterface::new()?;
+        return match firmware_type {
+            crate::config::FirmwareType::EPD128x250 => {
+                let firmware = crate::firmware::EPD128x250Firmware::new();
+                let protocol = GenericEinkProtocol::new(hardware, firmware);
+                Ok(ConfigurableProtocol::EPD128x250Usb(Box::new(protocol)))
+            },
+            crate::config::FirmwareType::EPD240x416 => {
+                let firmware = crate::firmware::EPD240x416Firmware::new();
+                let protocol = GenericEinkProtocol::new(hardware, firmware);
+                Ok(ConfigurableProtocol::EPD240x416Usb(Box::new(protocol)))
+            },
+        };
+    }
+
+    #[cfg(not(feature = "usb-spi"))]
+    if backend == crate::config::HardwareBackend::UsbCp2130 {
+        log::warn!("usb-cp2130 backend configured but the `usb-spi` feature is not enabled; falling back to native");
+    }
+
+    #[cfg(feature = "linux")]
+    {
+        let hardware = crate::hardware::DefaultHardwareInterface::new()?;
+
+        match firmware_type {
+            crate::config::FirmwareType::EPD128x250 => {
+                let firmware = crate::firmware::EPD128x250Firmware::new();
+                let protocol = GenericEinkProtocol::new(hardware, firmware);
+                Ok(ConfigurableProtocol::EPD128x250(Box::new(protocol)))
+            },
+            crate::config::FirmwareType::EPD240x416 => {
+                let firmware = crate::firmware::EPD240x416Firmware::new();
+                let protocol = GenericEinkProtocol::new(hardware, firmware);
+                Ok(ConfigurableProtocol::EPD240x416(Box::new(protocol)))
+            },
+        }
+    }
+
+    #[cfg(not(feature = "linux"))]
+    {
+        let _ = firmware_type;
+        Err(DisplayError::Config(
+            "no usable hardware backend: the `linux` feature is disabled and no `usb-spi` backend was configured".to_string(),
+        ))
     }
 }
 
@@ -319,6 +732,7 @@ pub fn create_default_protocol() -> Result<DefaultProtocol, DisplayError> {
 /// # Errors
 ///
 /// Returns `DisplayError` if hardware initialization fails
+#[cfg(feature = "linux")]
 pub fn create_protocol_with_firmware<F: DisplayFirmware>(
     firmware: F,
 ) -> Result<
@@ -332,3 +746,263 @@ pub fn create_protocol_with_firmware<F: DisplayFirmware>(
     let hardware = crate::hardware::DefaultHardwareInterface::new()?;
     Ok(GenericEinkProtocol::new(hardware, firmware))
 }
+
+/// One panel's protocol on a bus shared with other panels (see
+/// [`MultiPanelProtocol`]): the same firmware variants as
+/// [`ConfigurableProtocol`], but bound to
+/// [`crate::hardware::SharedSpiController`] instead of
+/// [`crate::hardware::DefaultSpiController`], so several of these can
+/// write through the same underlying `Spidev` handle.
+///
+/// Built entirely on native `spidev`/`gpiod` types, so the whole type is
+/// gated behind the `linux` feature — there's no USB-SPI equivalent.
+#[cfg(feature = "linux")]
+pub enum SharedBusProtocol {
+    /// 128x250 display protocol
+    EPD128x250(
+        Box<
+            GenericEinkProtocol<
+                crate::hardware::DefaultGpioController,
+                crate::hardware::SharedSpiController,
+                crate::firmware::EPD128x250Firmware,
+            >,
+        >,
+    ),
+    /// 240x416 display protocol
+    EPD240x416(
+        Box<
+            GenericEinkProtocol<
+                crate::hardware::DefaultGpioController,
+                crate::hardware::SharedSpiController,
+                crate::firmware::EPD240x416Firmware,
+            >,
+        >,
+    ),
+}
+
+#[cfg(feature = "linux")]
+impl EinkProtocol for SharedBusProtocol {
+    fn init_hardware(&mut self) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.init_hardware(),
+            Self::EPD240x416(p) => p.init_hardware(),
+        }
+    }
+
+    fn init_partial(&mut self) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.init_partial(),
+            Self::EPD240x416(p) => p.init_partial(),
+        }
+    }
+
+    fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.write_cmd(cmd),
+            Self::EPD240x416(p) => p.write_cmd(cmd),
+        }
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.write_data(data),
+            Self::EPD240x416(p) => p.write_data(data),
+        }
+    }
+
+    fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.write_image_data(data),
+            Self::EPD240x416(p) => p.write_image_data(data),
+        }
+    }
+
+    fn check_status(&mut self) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.check_status(),
+            Self::EPD240x416(p) => p.check_status(),
+        }
+    }
+
+    fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.update_display(mode),
+            Self::EPD240x416(p) => p.update_display(mode),
+        }
+    }
+
+    fn sleep(&mut self) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.sleep(),
+            Self::EPD240x416(p) => p.sleep(),
+        }
+    }
+
+    fn get_spec(&self) -> &crate::firmware::DisplaySpec {
+        match self {
+            Self::EPD128x250(p) => p.get_spec(),
+            Self::EPD240x416(p) => p.get_spec(),
+        }
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        match self {
+            Self::EPD128x250(p) => p.get_write_ram_command(),
+            Self::EPD240x416(p) => p.get_write_ram_command(),
+        }
+    }
+
+    fn load_waveform(&mut self, sequence: CommandSequence) -> Result<(), DisplayError> {
+        match self {
+            Self::EPD128x250(p) => p.load_waveform(sequence),
+            Self::EPD240x416(p) => p.load_waveform(sequence),
+        }
+    }
+
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed) {
+        match self {
+            Self::EPD128x250(p) => p.set_refresh_speed(speed),
+            Self::EPD240x416(p) => p.set_refresh_speed(speed),
+        }
+    }
+}
+
+/// Drives several e-ink panels that share one physical SPI bus, each
+/// selected via its own CS pin (bound through
+/// [`crate::hardware::DefaultGpioController::with_cs_pin`]) while a single
+/// [`crate::hardware::DefaultSpiController`] handles the shared data line —
+/// e.g. a main 240x416 panel plus a small 128x250 status panel.
+///
+/// Only one panel is "active" at a time: [`EinkProtocol`] calls on this
+/// type are forwarded to whichever panel [`Self::select`] last chose, with
+/// that panel's own CS asserted only around its own transactions (see
+/// [`GenericEinkProtocol::write_cmd`]/`write_data`/`write_image_data`), so
+/// the other panels on the bus never see spurious data.
+///
+/// Built on [`SharedBusProtocol`], so gated behind the `linux` feature too.
+#[cfg(feature = "linux")]
+pub struct MultiPanelProtocol {
+    panels: Vec<SharedBusProtocol>,
+    active: usize,
+}
+
+#[cfg(feature = "linux")]
+impl MultiPanelProtocol {
+    /// Build a multi-panel protocol from a list of `(firmware_type,
+    /// cs_pin)` pairs, one per panel sharing the bus, each given its own
+    /// [`crate::hardware::DefaultGpioController`] bound to that CS pin. The
+    /// panel at index 0 starts out active.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if `panels` is empty, or
+    /// `DisplayError::Gpio`/`DisplayError::Spi` if any panel's hardware
+    /// initialization fails.
+    pub fn new(panels: &[(crate::config::FirmwareType, u32)]) -> Result<Self, DisplayError> {
+        if panels.is_empty() {
+            return Err(DisplayError::Config(
+                "MultiPanelProtocol needs at least one panel".to_string(),
+            ));
+        }
+
+        let spi = crate::hardware::DefaultSpiController::new()?;
+        let shared_spi = crate::hardware::SharedSpiController::from_controller(spi);
+
+        let mut built = Vec::with_capacity(panels.len());
+        for &(firmware_type, cs_pin) in panels {
+            let gpio = crate::hardware::DefaultGpioController::with_cs_pin(cs_pin)?;
+            let hardware = crate::hardware::HardwareInterface::from_parts(gpio, shared_spi.clone());
+
+            let protocol = match firmware_type {
+                crate::config::FirmwareType::EPD128x250 => {
+                    let firmware = crate::firmware::EPD128x250Firmware::new();
+                    SharedBusProtocol::EPD128x250(Box::new(GenericEinkProtocol::new(hardware, firmware)))
+                },
+                crate::config::FirmwareType::EPD240x416 => {
+                    let firmware = crate::firmware::EPD240x416Firmware::new();
+                    SharedBusProtocol::EPD240x416(Box::new(GenericEinkProtocol::new(hardware, firmware)))
+                },
+            };
+            built.push(protocol);
+        }
+
+        Ok(Self {
+            panels: built,
+            active: 0,
+        })
+    }
+
+    /// Select which panel subsequent [`EinkProtocol`] calls drive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError::Config` if `index` is out of range.
+    pub fn select(&mut self, index: usize) -> Result<(), DisplayError> {
+        if index >= self.panels.len() {
+            return Err(DisplayError::Config(format!(
+                "panel index {index} out of range (have {})",
+                self.panels.len()
+            )));
+        }
+        self.active = index;
+        Ok(())
+    }
+
+    /// The currently selected panel's protocol, for variant-specific
+    /// operations like [`SharedBusProtocol::EPD240x416`]'s windowed
+    /// partial updates.
+    pub fn active_mut(&mut self) -> &mut SharedBusProtocol {
+        &mut self.panels[self.active]
+    }
+}
+
+#[cfg(feature = "linux")]
+impl EinkProtocol for MultiPanelProtocol {
+    fn init_hardware(&mut self) -> Result<(), DisplayError> {
+        self.active_mut().init_hardware()
+    }
+
+    fn init_partial(&mut self) -> Result<(), DisplayError> {
+        self.active_mut().init_partial()
+    }
+
+    fn write_cmd(&mut self, cmd: u8) -> Result<(), DisplayError> {
+        self.active_mut().write_cmd(cmd)
+    }
+
+    fn write_data(&mut self, data: u8) -> Result<(), DisplayError> {
+        self.active_mut().write_data(data)
+    }
+
+    fn write_image_data(&mut self, data: &[u8]) -> Result<(), DisplayError> {
+        self.active_mut().write_image_data(data)
+    }
+
+    fn check_status(&mut self) -> Result<(), DisplayError> {
+        self.active_mut().check_status()
+    }
+
+    fn update_display(&mut self, mode: DisplayMode) -> Result<(), DisplayError> {
+        self.active_mut().update_display(mode)
+    }
+
+    fn sleep(&mut self) -> Result<(), DisplayError> {
+        self.active_mut().sleep()
+    }
+
+    fn get_spec(&self) -> &crate::firmware::DisplaySpec {
+        self.panels[self.active].get_spec()
+    }
+
+    fn get_write_ram_command(&self) -> u8 {
+        self.panels[self.active].get_write_ram_command()
+    }
+
+    fn load_waveform(&mut self, sequence: CommandSequence) -> Result<(), DisplayError> {
+        self.active_mut().load_waveform(sequence)
+    }
+
+    fn set_refresh_speed(&mut self, speed: crate::firmware::RefreshSpeed) {
+        self.active_mut().set_refresh_speed(speed);
+    }
+}
@@ -0,0 +1,796 @@
+//! From-scratch QR Code (ISO/IEC 18004) symbol encoder.
+//!
+//! Supports versions 1-10, which is comfortably enough capacity for the
+//! pairing URLs and Wi-Fi credential strings this display is used for,
+//! without needing the full version 1-40 capacity table. Builds byte or
+//! alphanumeric symbols, generates Reed-Solomon error correction for the
+//! requested level, and picks the masking pattern with the lowest penalty
+//! score per the standard scoring rules.
+
+use crate::error::DisplayError;
+
+/// Error correction level (0=L, 1=M, 2=Q, 3=H in the public FFI surface).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EcLevel {
+    /// ~7% of codewords can be restored
+    L,
+    /// ~15% of codewords can be restored
+    M,
+    /// ~25% of codewords can be restored
+    Q,
+    /// ~30% of codewords can be restored
+    H,
+}
+
+impl EcLevel {
+    /// Parse the FFI `ec_level` integer (0=L, 1=M, 2=Q, 3=H).
+    #[must_use]
+    pub const fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(Self::L),
+            1 => Some(Self::M),
+            2 => Some(Self::Q),
+            3 => Some(Self::H),
+            _ => None,
+        }
+    }
+
+    /// The 2-bit level indicator used in the symbol's format information.
+    const fn format_bits(self) -> u32 {
+        match self {
+            Self::L => 0b01,
+            Self::M => 0b00,
+            Self::Q => 0b11,
+            Self::H => 0b10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Alphanumeric,
+    Byte,
+}
+
+const MIN_VERSION: u8 = 1;
+const MAX_VERSION: u8 = 10;
+
+const ALPHANUMERIC_CHARS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+const FORMAT_GENERATOR: u32 = 0b10100110111;
+const FORMAT_MASK: u32 = 0x5412;
+const VERSION_GENERATOR: u32 = 0b1_1111_0010_0101;
+
+/// A finished QR symbol: a square matrix of modules, `true` meaning "dark".
+pub struct QrMatrix {
+    pub size: usize,
+    pub dark: Vec<bool>,
+}
+
+impl QrMatrix {
+    #[must_use]
+    pub fn get(&self, col: usize, row: usize) -> bool {
+        self.dark[row * self.size + col]
+    }
+}
+
+/// Encode `text` into the smallest supported QR version (1-10) for `level`.
+///
+/// Returns `Ok(None)` if `text` does not fit within the largest supported
+/// version's capacity at the requested error-correction level.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if `text` contains characters that
+/// cannot be represented (this encoder supports alphanumeric and arbitrary
+/// byte/UTF-8 data, so this should not occur in practice).
+pub fn encode(text: &str, level: EcLevel) -> Result<Option<QrMatrix>, DisplayError> {
+    let mode = if is_alphanumeric(text) {
+        Mode::Alphanumeric
+    } else {
+        Mode::Byte
+    };
+
+    let Some(version) = (MIN_VERSION..=MAX_VERSION).find(|&v| fits(text, v, level, mode)) else {
+        return Ok(None);
+    };
+
+    let info = version_info(version, level);
+    let data_codewords = build_codewords(text, version, &info, mode);
+    let gf = Gf256::new();
+    let all_codewords = interleave_with_ec(&gf, &data_codewords, &info);
+    let bits = bytes_to_bits(&all_codewords);
+
+    let size = usize::from(version) * 4 + 17;
+    let mut symbol = Symbol::new(size);
+    symbol.draw_function_patterns(version);
+    symbol.place_data(&bits);
+
+    let mut best_mask = 0u8;
+    let mut best_penalty = u32::MAX;
+    let mut best_modules = symbol.modules.clone();
+    for mask in 0..8u8 {
+        let candidate = symbol.apply_mask(mask);
+        let score = penalty_score(&candidate, size);
+        if score < best_penalty {
+            best_penalty = score;
+            best_mask = mask;
+            best_modules = candidate;
+        }
+    }
+
+    draw_format_bits(&mut best_modules, size, level, best_mask);
+
+    Ok(Some(QrMatrix { size, dark: best_modules }))
+}
+
+fn is_alphanumeric(s: &str) -> bool {
+    s.chars().all(|c| ALPHANUMERIC_CHARS.contains(c))
+}
+
+fn alphanumeric_value(c: char) -> u32 {
+    ALPHANUMERIC_CHARS.find(c).expect("validated by is_alphanumeric") as u32
+}
+
+fn count_indicator_bits(version: u8, mode: Mode) -> u32 {
+    match mode {
+        Mode::Alphanumeric => {
+            if version <= 9 {
+                9
+            } else {
+                11
+            }
+        },
+        Mode::Byte => {
+            if version <= 9 {
+                8
+            } else {
+                16
+            }
+        },
+    }
+}
+
+fn data_bit_length(mode: Mode, text: &str) -> u32 {
+    match mode {
+        Mode::Alphanumeric => {
+            let n = text.chars().count() as u32;
+            (n / 2) * 11 + (n % 2) * 6
+        },
+        Mode::Byte => text.len() as u32 * 8,
+    }
+}
+
+fn fits(text: &str, version: u8, level: EcLevel, mode: Mode) -> bool {
+    let info = version_info(version, level);
+    let capacity_bits = info.total_data_codewords() * 8;
+    let required = 4 + count_indicator_bits(version, mode) + data_bit_length(mode, text);
+    (required as usize) <= capacity_bits
+}
+
+/// Per-version, per-level codeword layout: data is split across one or two
+/// groups of equally-sized blocks, each carrying its own Reed-Solomon EC
+/// codewords.
+struct VersionInfo {
+    ec_per_block: usize,
+    group1_blocks: usize,
+    group1_data_codewords: usize,
+    group2_blocks: usize,
+    group2_data_codewords: usize,
+}
+
+impl VersionInfo {
+    fn total_data_codewords(&self) -> usize {
+        self.group1_blocks * self.group1_data_codewords + self.group2_blocks * self.group2_data_codewords
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn version_info(version: u8, level: EcLevel) -> VersionInfo {
+    let (ec, g1n, g1d, g2n, g2d): (usize, usize, usize, usize, usize) = match (version, level) {
+        (1, EcLevel::L) => (7, 1, 19, 0, 0),
+        (1, EcLevel::M) => (10, 1, 16, 0, 0),
+        (1, EcLevel::Q) => (13, 1, 13, 0, 0),
+        (1, EcLevel::H) => (17, 1, 9, 0, 0),
+        (2, EcLevel::L) => (10, 1, 34, 0, 0),
+        (2, EcLevel::M) => (16, 1, 28, 0, 0),
+        (2, EcLevel::Q) => (22, 1, 22, 0, 0),
+        (2, EcLevel::H) => (28, 1, 16, 0, 0),
+        (3, EcLevel::L) => (15, 1, 55, 0, 0),
+        (3, EcLevel::M) => (26, 1, 44, 0, 0),
+        (3, EcLevel::Q) => (18, 2, 17, 0, 0),
+        (3, EcLevel::H) => (22, 2, 13, 0, 0),
+        (4, EcLevel::L) => (20, 1, 80, 0, 0),
+        (4, EcLevel::M) => (18, 2, 32, 0, 0),
+        (4, EcLevel::Q) => (26, 2, 24, 0, 0),
+        (4, EcLevel::H) => (16, 4, 9, 0, 0),
+        (5, EcLevel::L) => (26, 1, 108, 0, 0),
+        (5, EcLevel::M) => (24, 2, 43, 0, 0),
+        (5, EcLevel::Q) => (18, 2, 15, 2, 16),
+        (5, EcLevel::H) => (22, 2, 11, 2, 12),
+        (6, EcLevel::L) => (18, 2, 68, 0, 0),
+        (6, EcLevel::M) => (16, 4, 27, 0, 0),
+        (6, EcLevel::Q) => (24, 4, 19, 0, 0),
+        (6, EcLevel::H) => (28, 4, 15, 0, 0),
+        (7, EcLevel::L) => (20, 2, 78, 0, 0),
+        (7, EcLevel::M) => (18, 4, 31, 0, 0),
+        (7, EcLevel::Q) => (18, 2, 14, 4, 15),
+        (7, EcLevel::H) => (26, 4, 13, 1, 14),
+        (8, EcLevel::L) => (24, 2, 97, 0, 0),
+        (8, EcLevel::M) => (22, 2, 38, 2, 39),
+        (8, EcLevel::Q) => (22, 4, 18, 2, 19),
+        (8, EcLevel::H) => (26, 4, 14, 2, 15),
+        (9, EcLevel::L) => (30, 2, 116, 0, 0),
+        (9, EcLevel::M) => (22, 3, 36, 2, 37),
+        (9, EcLevel::Q) => (20, 4, 16, 4, 17),
+        (9, EcLevel::H) => (24, 4, 12, 4, 13),
+        (10, EcLevel::L) => (18, 2, 68, 2, 69),
+        (10, EcLevel::M) => (26, 4, 43, 1, 44),
+        (10, EcLevel::Q) => (24, 6, 19, 2, 20),
+        (10, EcLevel::H) => (28, 6, 15, 2, 16),
+        _ => unreachable!("QR version out of supported range 1..=10"),
+    };
+    VersionInfo {
+        ec_per_block: ec,
+        group1_blocks: g1n,
+        group1_data_codewords: g1d,
+        group2_blocks: g2n,
+        group2_data_codewords: g2d,
+    }
+}
+
+fn alignment_positions(version: u8) -> &'static [u32] {
+    match version {
+        2 => &[6, 18],
+        3 => &[6, 22],
+        4 => &[6, 26],
+        5 => &[6, 30],
+        6 => &[6, 34],
+        7 => &[6, 22, 38],
+        8 => &[6, 24, 42],
+        9 => &[6, 26, 46],
+        10 => &[6, 28, 50],
+        _ => &[],
+    }
+}
+
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self { bits: Vec::new() }
+    }
+
+    fn push_bits(&mut self, value: u32, len: u32) {
+        for i in (0..len).rev() {
+            self.bits.push((value >> i) & 1 == 1);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bits.len()
+    }
+}
+
+fn encode_alphanumeric(writer: &mut BitWriter, text: &str) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+    while i + 1 < chars.len() {
+        let value = alphanumeric_value(chars[i]) * 45 + alphanumeric_value(chars[i + 1]);
+        writer.push_bits(value, 11);
+        i += 2;
+    }
+    if i < chars.len() {
+        writer.push_bits(alphanumeric_value(chars[i]), 6);
+    }
+}
+
+fn encode_byte(writer: &mut BitWriter, text: &str) {
+    for &b in text.as_bytes() {
+        writer.push_bits(u32::from(b), 8);
+    }
+}
+
+fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            let mut byte = 0u8;
+            for (i, &b) in chunk.iter().enumerate() {
+                if b {
+                    byte |= 1 << (7 - i);
+                }
+            }
+            byte
+        })
+        .collect()
+}
+
+fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes.iter().flat_map(|&b| (0..8).map(move |i| (b >> (7 - i)) & 1 == 1)).collect()
+}
+
+fn build_codewords(text: &str, version: u8, info: &VersionInfo, mode: Mode) -> Vec<u8> {
+    let capacity_bits = info.total_data_codewords() * 8;
+    let mut writer = BitWriter::new();
+
+    match mode {
+        Mode::Alphanumeric => {
+            writer.push_bits(0b0010, 4);
+            writer.push_bits(text.chars().count() as u32, count_indicator_bits(version, mode));
+            encode_alphanumeric(&mut writer, text);
+        },
+        Mode::Byte => {
+            writer.push_bits(0b0100, 4);
+            writer.push_bits(text.len() as u32, count_indicator_bits(version, mode));
+            encode_byte(&mut writer, text);
+        },
+    }
+
+    let terminator_len = capacity_bits.saturating_sub(writer.len()).min(4);
+    writer.push_bits(0, terminator_len as u32);
+
+    while !writer.len().is_multiple_of(8) {
+        writer.push_bits(0, 1);
+    }
+
+    let mut codewords = bits_to_bytes(&writer.bits);
+
+    const PAD_BYTES: [u8; 2] = [0xEC, 0x11];
+    let mut i = 0;
+    while codewords.len() < info.total_data_codewords() {
+        codewords.push(PAD_BYTES[i % 2]);
+        i += 1;
+    }
+
+    codewords
+}
+
+/// GF(256) arithmetic over the QR standard's primitive polynomial
+/// `x^8 + x^4 + x^3 + x^2 + 1` (0x11D), generator element 2.
+struct Gf256 {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+impl Gf256 {
+    fn new() -> Self {
+        let mut exp = [0u8; 512];
+        let mut log = [0u8; 256];
+        let mut x: u32 = 1;
+        for (i, e) in exp.iter_mut().enumerate().take(255) {
+            *e = x as u8;
+            log[x as usize] = i as u8;
+            x <<= 1;
+            if x & 0x100 != 0 {
+                x ^= 0x11D;
+            }
+        }
+        for i in 255..512 {
+            exp[i] = exp[i - 255];
+        }
+        Self { exp, log }
+    }
+
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        let sum = usize::from(self.log[a as usize]) + usize::from(self.log[b as usize]);
+        self.exp[sum]
+    }
+}
+
+/// Build the monic generator polynomial of the given `degree`, as the
+/// product of `(x - alpha^i)` for `i` in `0..degree`. Coefficients are
+/// stored highest-degree first.
+fn rs_generator_poly(gf: &Gf256, degree: usize) -> Vec<u8> {
+    let mut coeffs = vec![1u8];
+    let mut root = 1u8;
+    for _ in 0..degree {
+        let mut new_coeffs = vec![0u8; coeffs.len() + 1];
+        for (i, &c) in coeffs.iter().enumerate() {
+            new_coeffs[i] ^= c;
+            new_coeffs[i + 1] ^= gf.mul(c, root);
+        }
+        coeffs = new_coeffs;
+        root = gf.mul(root, 2);
+    }
+    coeffs
+}
+
+/// Compute the `ec_len` Reed-Solomon error correction codewords for one
+/// data block, via polynomial long division of `data(x) * x^ec_len` by
+/// the generator polynomial.
+fn rs_encode(gf: &Gf256, data: &[u8], ec_len: usize) -> Vec<u8> {
+    let generator = rs_generator_poly(gf, ec_len);
+    let mut remainder = data.to_vec();
+    remainder.extend(std::iter::repeat_n(0u8, ec_len));
+
+    for i in 0..data.len() {
+        let coef = remainder[i];
+        if coef != 0 {
+            for (j, &g) in generator.iter().enumerate() {
+                remainder[i + j] ^= gf.mul(g, coef);
+            }
+        }
+    }
+
+    remainder[data.len()..].to_vec()
+}
+
+fn interleave_with_ec(gf: &Gf256, data: &[u8], info: &VersionInfo) -> Vec<u8> {
+    let mut blocks: Vec<&[u8]> = Vec::new();
+    let mut offset = 0;
+    for _ in 0..info.group1_blocks {
+        blocks.push(&data[offset..offset + info.group1_data_codewords]);
+        offset += info.group1_data_codewords;
+    }
+    for _ in 0..info.group2_blocks {
+        blocks.push(&data[offset..offset + info.group2_data_codewords]);
+        offset += info.group2_data_codewords;
+    }
+
+    let ec_blocks: Vec<Vec<u8>> = blocks.iter().map(|b| rs_encode(gf, b, info.ec_per_block)).collect();
+
+    let max_data_len = blocks.iter().map(|b| b.len()).max().unwrap_or(0);
+    let mut result = Vec::with_capacity(data.len() + info.ec_per_block * blocks.len());
+    for i in 0..max_data_len {
+        for b in &blocks {
+            if i < b.len() {
+                result.push(b[i]);
+            }
+        }
+    }
+    for i in 0..info.ec_per_block {
+        for b in &ec_blocks {
+            result.push(b[i]);
+        }
+    }
+
+    result
+}
+
+/// The in-progress module matrix: `modules` is the dark/light grid,
+/// `is_function` marks finder/timing/alignment/format/version cells that
+/// masking and data placement must not touch.
+struct Symbol {
+    size: usize,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl Symbol {
+    fn new(size: usize) -> Self {
+        Self { size, modules: vec![false; size * size], is_function: vec![false; size * size] }
+    }
+
+    fn idx(&self, col: usize, row: usize) -> usize {
+        row * self.size + col
+    }
+
+    fn is_function(&self, col: usize, row: usize) -> bool {
+        self.is_function[self.idx(col, row)]
+    }
+
+    fn set_function(&mut self, col: usize, row: usize, dark: bool) {
+        let i = self.idx(col, row);
+        self.modules[i] = dark;
+        self.is_function[i] = true;
+    }
+
+    fn set_data(&mut self, col: usize, row: usize, dark: bool) {
+        let i = self.idx(col, row);
+        self.modules[i] = dark;
+    }
+
+    fn draw_finder(&mut self, top: usize, left: usize) {
+        for dy in -1i32..=7 {
+            for dx in -1i32..=7 {
+                let r = top as i32 + dy;
+                let c = left as i32 + dx;
+                if r < 0 || c < 0 || r as usize >= self.size || c as usize >= self.size {
+                    continue;
+                }
+                let in_core = (0..7).contains(&dy) && (0..7).contains(&dx);
+                let dark = in_core
+                    && (dy == 0 || dy == 6 || dx == 0 || dx == 6 || ((2..=4).contains(&dy) && (2..=4).contains(&dx)));
+                self.set_function(c as usize, r as usize, dark);
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, center_row: u32, center_col: u32) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let r = (center_row as i32 + dy) as usize;
+                let c = (center_col as i32 + dx) as usize;
+                let dark = dx.abs() == 2 || dy.abs() == 2 || (dx == 0 && dy == 0);
+                self.set_function(c, r, dark);
+            }
+        }
+    }
+
+    fn draw_version_bits(&mut self, version: u8) {
+        let rem = bch_remainder(u32::from(version), 6, VERSION_GENERATOR, 12);
+        let bits = (u32::from(version) << 12) | rem;
+        let size = self.size;
+        for i in 0..18u32 {
+            let bit = (bits >> i) & 1 == 1;
+            let col_in_block = (i / 3) as usize;
+            let row_in_block = (i % 3) as usize;
+            self.set_function(col_in_block, size - 11 + row_in_block, bit);
+            self.set_function(size - 11 + row_in_block, col_in_block, bit);
+        }
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn draw_function_patterns(&mut self, version: u8) {
+        let size = self.size;
+
+        self.draw_finder(0, 0);
+        self.draw_finder(0, size - 7);
+        self.draw_finder(size - 7, 0);
+
+        for i in 8..size - 8 {
+            let dark = i % 2 == 0;
+            self.set_function(i, 6, dark);
+            self.set_function(6, i, dark);
+        }
+
+        let positions = alignment_positions(version);
+        if !positions.is_empty() {
+            let first = positions[0];
+            let last = positions[positions.len() - 1];
+            for &r in positions {
+                for &c in positions {
+                    // Skip the three corners that overlap the finder patterns
+                    // (top-left, top-right, bottom-left); bottom-right is a
+                    // real alignment pattern position.
+                    let skip_top_left = r == first && c == first;
+                    let skip_top_right = r == first && c == last;
+                    let skip_bottom_left = r == last && c == first;
+                    if skip_top_left || skip_top_right || skip_bottom_left {
+                        continue;
+                    }
+                    self.draw_alignment(r, c);
+                }
+            }
+        }
+
+        // Dark module, always set regardless of mask or level
+        self.set_function(8, 4 * usize::from(version) + 9, true);
+
+        // Reserve the two format-info strips; real bits filled in later
+        // once the mask pattern has been chosen.
+        for i in 0..9 {
+            if i != 6 {
+                self.set_function(i, 8, false);
+                self.set_function(8, i, false);
+            }
+        }
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, false);
+        }
+        for i in 0..7 {
+            self.set_function(8, size - 1 - i, false);
+        }
+
+        if version >= 7 {
+            self.draw_version_bits(version);
+        }
+    }
+
+    fn place_data(&mut self, bits: &[bool]) {
+        let size = self.size;
+        let mut bit_index = 0usize;
+        let mut upward = true;
+        let mut col = size as i32 - 1;
+        while col >= 1 {
+            if col == 6 {
+                col -= 1;
+            }
+            for i in 0..size {
+                let row = if upward { size - 1 - i } else { i };
+                for &c in &[col, col - 1] {
+                    if c < 0 {
+                        continue;
+                    }
+                    let c = c as usize;
+                    if self.is_function(c, row) {
+                        continue;
+                    }
+                    let bit = bits.get(bit_index).copied().unwrap_or(false);
+                    self.set_data(c, row, bit);
+                    bit_index += 1;
+                }
+            }
+            upward = !upward;
+            col -= 2;
+        }
+    }
+
+    fn apply_mask(&self, mask: u8) -> Vec<bool> {
+        let mut out = self.modules.clone();
+        for row in 0..self.size {
+            for col in 0..self.size {
+                let i = self.idx(col, row);
+                if !self.is_function[i] && mask_fn(mask, row, col) {
+                    out[i] = !out[i];
+                }
+            }
+        }
+        out
+    }
+}
+
+fn mask_fn(mask: u8, row: usize, col: usize) -> bool {
+    let (i, j) = (row as i64, col as i64);
+    match mask {
+        0 => (i + j) % 2 == 0,
+        1 => i % 2 == 0,
+        2 => j % 3 == 0,
+        3 => (i + j) % 3 == 0,
+        4 => (i / 2 + j / 3) % 2 == 0,
+        5 => (i * j) % 2 + (i * j) % 3 == 0,
+        6 => ((i * j) % 2 + (i * j) % 3) % 2 == 0,
+        _ => ((i + j) % 2 + (i * j) % 3) % 2 == 0,
+    }
+}
+
+/// Compute the BCH error-correction remainder for a `data_bits`-wide value
+/// against `generator`, producing `ec_bits` of parity.
+fn bch_remainder(data: u32, data_bits: u32, generator: u32, ec_bits: u32) -> u32 {
+    let mut value = data << ec_bits;
+    for i in (ec_bits..data_bits + ec_bits).rev() {
+        if (value >> i) & 1 == 1 {
+            value ^= generator << (i - ec_bits);
+        }
+    }
+    value
+}
+
+fn draw_format_bits(modules: &mut [bool], size: usize, level: EcLevel, mask: u8) {
+    let data = (level.format_bits() << 3) | u32::from(mask);
+    let rem = bch_remainder(data, 5, FORMAT_GENERATOR, 10);
+    let bits = ((data << 10) | rem) ^ FORMAT_MASK;
+    let get_bit = |i: u32| (bits >> i) & 1 == 1;
+    let set = |modules: &mut [bool], col: usize, row: usize, v: bool| modules[row * size + col] = v;
+
+    for i in 0..6 {
+        set(modules, 8, i, get_bit(i as u32));
+    }
+    set(modules, 8, 7, get_bit(6));
+    set(modules, 8, 8, get_bit(7));
+    set(modules, 7, 8, get_bit(8));
+    for i in 9..15 {
+        set(modules, 14 - i, 8, get_bit(i as u32));
+    }
+
+    for i in 0..8 {
+        set(modules, size - 1 - i, 8, get_bit(i as u32));
+    }
+    for i in 8..15 {
+        set(modules, 8, size - 15 + i, get_bit(i as u32));
+    }
+}
+
+fn run_penalty(iter: impl Iterator<Item = bool>) -> u32 {
+    let mut penalty = 0;
+    let mut last = None;
+    let mut run = 0u32;
+    for v in iter {
+        if Some(v) == last {
+            run += 1;
+        } else {
+            if run >= 5 {
+                penalty += 3 + (run - 5);
+            }
+            last = Some(v);
+            run = 1;
+        }
+    }
+    if run >= 5 {
+        penalty += 3 + (run - 5);
+    }
+    penalty
+}
+
+fn finder_pattern_penalty(bits: &[bool]) -> u32 {
+    const PATTERN: [bool; 7] = [true, false, true, true, true, false, true];
+    if bits.len() < 7 {
+        return 0;
+    }
+    let mut penalty = 0;
+    for i in 0..=bits.len() - 7 {
+        if bits[i..i + 7] == PATTERN {
+            let left_quiet = i >= 4 && bits[i - 4..i].iter().all(|&b| !b);
+            let right_quiet = i + 11 <= bits.len() && bits[i + 7..i + 11].iter().all(|&b| !b);
+            if left_quiet || right_quiet {
+                penalty += 40;
+            }
+        }
+    }
+    penalty
+}
+
+/// Standard ISO/IEC 18004 mask-pattern penalty scoring (approximating the
+/// four scoring rules closely enough to pick a good mask; since every mask
+/// produces a valid symbol, exactness here only affects aesthetic quality,
+/// not correctness).
+fn penalty_score(modules: &[bool], size: usize) -> u32 {
+    let get = |col: usize, row: usize| modules[row * size + col];
+    let mut penalty = 0;
+
+    for row in 0..size {
+        penalty += run_penalty((0..size).map(|c| get(c, row)));
+    }
+    for col in 0..size {
+        penalty += run_penalty((0..size).map(|r| get(col, r)));
+    }
+
+    for row in 0..size - 1 {
+        for col in 0..size - 1 {
+            let v = get(col, row);
+            if v == get(col + 1, row) && v == get(col, row + 1) && v == get(col + 1, row + 1) {
+                penalty += 3;
+            }
+        }
+    }
+
+    for row in 0..size {
+        let bits: Vec<bool> = (0..size).map(|c| get(c, row)).collect();
+        penalty += finder_pattern_penalty(&bits);
+    }
+    for col in 0..size {
+        let bits: Vec<bool> = (0..size).map(|r| get(col, r)).collect();
+        penalty += finder_pattern_penalty(&bits);
+    }
+
+    let dark = modules.iter().filter(|&&v| v).count();
+    let percent = dark * 100 / (size * size);
+    let deviation = percent.abs_diff(50);
+    penalty += (deviation as u32 / 5) * 10;
+
+    penalty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gf256_exp_log_are_inverse() {
+        let gf = Gf256::new();
+        for a in 1..=255u8 {
+            assert_eq!(gf.exp[gf.log[a as usize] as usize], a);
+        }
+    }
+
+    #[test]
+    fn short_alphanumeric_string_encodes_to_version_1() {
+        let matrix = encode("HELLO", EcLevel::M).unwrap().unwrap();
+        assert_eq!(matrix.size, 21);
+    }
+
+    #[test]
+    fn byte_mode_used_for_lowercase_text() {
+        let matrix = encode("hello world", EcLevel::L).unwrap().unwrap();
+        assert_eq!(matrix.size, 21);
+    }
+
+    #[test]
+    fn text_too_large_for_supported_versions_returns_none() {
+        let long_text = "a".repeat(2000);
+        assert!(encode(&long_text, EcLevel::H).unwrap().is_none());
+    }
+
+    #[test]
+    fn higher_ec_level_needs_a_larger_version_for_the_same_text() {
+        let text = "A".repeat(60);
+        let low = encode(&text, EcLevel::L).unwrap().unwrap();
+        let high = encode(&text, EcLevel::H).unwrap().unwrap();
+        assert!(high.size >= low.size);
+    }
+}
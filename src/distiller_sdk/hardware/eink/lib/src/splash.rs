@@ -0,0 +1,90 @@
+//! Boot-splash selection: find and display an image asset keyed to the
+//! active panel's resolution, so early userspace/boot scripts can paint a
+//! splash without knowing which firmware variant is wired up.
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    config, display,
+    error::DisplayError,
+    image_processing::{DitherMode, ScaleMode},
+    protocol::DisplayMode,
+};
+
+/// Basename used when no resolution-keyed asset is found.
+const DEFAULT_SPLASH_BASENAME: &str = "splash-default";
+
+/// Extensions probed for each candidate basename, in priority order. These
+/// are exactly the formats `image::open` (and therefore
+/// `display_image_file`) already handles.
+const SPLASH_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "gif"];
+
+/// Expected basename for the resolution-keyed splash asset, e.g.
+/// `splash-128x250` for a 128x250 panel.
+fn resolution_basename(width: u32, height: u32) -> String {
+    format!("splash-{width}x{height}")
+}
+
+/// Find the first existing `<dir>/<basename>.<ext>` for one of
+/// [`SPLASH_EXTENSIONS`].
+fn find_asset(dir: &Path, basename: &str) -> Option<PathBuf> {
+    SPLASH_EXTENSIONS.iter().find_map(|ext| {
+        let candidate = dir.join(format!("{basename}.{ext}"));
+        candidate.is_file().then_some(candidate)
+    })
+}
+
+fn not_found(dir: &str, basename: &str) -> DisplayError {
+    DisplayError::Io(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!(
+            "no splash asset named '{basename}.*' or '{DEFAULT_SPLASH_BASENAME}.*' found in {dir}"
+        ),
+    ))
+}
+
+/// Display the boot splash found in `dir`.
+///
+/// Scans `dir` for an asset named after the active panel's resolution (e.g.
+/// `splash-128x250.png`), falling back to `splash-default.<ext>` if no
+/// resolution-keyed asset exists. The asset is displayed as-is if its
+/// dimensions exactly match the panel, and through the existing auto
+/// scale/dither pipeline otherwise.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` if the active display spec cannot be
+/// resolved, or `DisplayError::Io` if `dir` contains neither a
+/// resolution-keyed nor a default splash asset.
+pub fn display_splash(dir: &str) -> Result<(), DisplayError> {
+    let spec = config::get_default_spec()?;
+    let dir_path = Path::new(dir);
+
+    let basename = resolution_basename(spec.width, spec.height);
+    let asset = find_asset(dir_path, &basename)
+        .or_else(|| find_asset(dir_path, DEFAULT_SPLASH_BASENAME))
+        .ok_or_else(|| not_found(dir, &basename))?;
+
+    let asset_str = asset.to_str().ok_or_else(|| {
+        DisplayError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "splash asset path is not valid UTF-8",
+        ))
+    })?;
+
+    let exact_match = image::image_dimensions(asset_str)
+        .is_ok_and(|(width, height)| width == spec.width && height == spec.height);
+
+    if exact_match {
+        display::display_image_file(asset_str, DisplayMode::Full)
+    } else {
+        display::display_image_auto(
+            asset_str,
+            DisplayMode::Full,
+            ScaleMode::Letterbox,
+            crate::image_processing::ResizeQuality::Lanczos3,
+            DitherMode::FloydSteinberg,
+            None,
+        )
+    }
+}
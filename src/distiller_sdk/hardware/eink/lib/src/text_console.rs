@@ -0,0 +1,258 @@
+//! Text-console wrapper over an [`EinkProtocol`], implementing
+//! `core::fmt::Write` so callers can `write!(console, "...")` much like the
+//! `lcd` HD44780 crate's `Display: core::fmt::Write`.
+//!
+//! [`TextConsole`] renders into a private 1-bit framebuffer using a small
+//! built-in 6x8 bitmap font, tracks a cursor, and wraps/scrolls on overflow.
+//! Writes only touch the in-memory buffer; nothing reaches the panel until
+//! [`TextConsole::flush`] is called, since e-ink has no auto-refresh.
+//!
+//! `flush` re-enters partial-update mode via `get_partial_init_sequence`
+//! (through [`EinkProtocol::init_partial`]) and drives the refresh with
+//! `DisplayMode::Partial`, so repeated short writes use the fast, low-flicker
+//! waveform instead of paying for a full refresh every time. [`EinkProtocol`]
+//! has no RAM-window method, so the whole framebuffer is still re-sent on
+//! every flush; only the refresh waveform is partial. The changed character
+//! rows are tracked privately and only used to skip a flush when nothing has
+//! changed since the last one.
+
+use core::fmt;
+
+use crate::error::DisplayError;
+use crate::protocol::{DisplayMode, EinkProtocol};
+
+/// Glyph width in pixels.
+pub const FONT_WIDTH: u32 = 6;
+/// Glyph height in pixels.
+pub const FONT_HEIGHT: u32 = 8;
+
+/// Text console rendered onto a 1-bit e-ink framebuffer, writable with
+/// `write!`/`writeln!` via its `core::fmt::Write` implementation.
+pub struct TextConsole<P: EinkProtocol> {
+    protocol: P,
+    width: u32,
+    height: u32,
+    rows: u32,
+    cols: u32,
+    buffer: Vec<u8>,
+    cursor_row: u32,
+    cursor_col: u32,
+    dirty_rows: Option<(u32, u32)>,
+}
+
+impl<P: EinkProtocol> TextConsole<P> {
+    /// Build a console whose character grid fills `protocol`'s active
+    /// display spec with the built-in 6x8 font.
+    #[must_use]
+    pub fn new(protocol: P) -> Self {
+        let (width, height) = {
+            let spec = protocol.get_spec();
+            (spec.width, spec.height)
+        };
+        let row_bytes = (width as usize).div_ceil(8);
+
+        Self {
+            protocol,
+            width,
+            height,
+            rows: height / FONT_HEIGHT,
+            cols: width / FONT_WIDTH,
+            buffer: vec![0xFFu8; row_bytes * height as usize],
+            cursor_row: 0,
+            cursor_col: 0,
+            dirty_rows: None,
+        }
+    }
+
+    /// Number of character rows in the grid.
+    #[must_use]
+    pub const fn rows(&self) -> u32 {
+        self.rows
+    }
+
+    /// Number of character columns in the grid.
+    #[must_use]
+    pub const fn cols(&self) -> u32 {
+        self.cols
+    }
+
+    /// Move the cursor to `(row, col)`, clamped to the grid bounds.
+    pub fn set_cursor(&mut self, row: u32, col: u32) {
+        self.cursor_row = row.min(self.rows.saturating_sub(1));
+        self.cursor_col = col.min(self.cols.saturating_sub(1));
+    }
+
+    fn mark_dirty(&mut self, row: u32) {
+        self.dirty_rows = Some(match self.dirty_rows {
+            Some((min_row, max_row)) => (min_row.min(row), max_row.max(row)),
+            None => (row, row),
+        });
+    }
+
+    fn row_bytes(&self) -> usize {
+        (self.width as usize).div_ceil(8)
+    }
+
+    fn newline(&mut self) {
+        self.cursor_col = 0;
+        if self.cursor_row + 1 >= self.rows {
+            self.scroll();
+        } else {
+            self.cursor_row += 1;
+        }
+    }
+
+    /// Scroll the framebuffer up by one character row, discarding row 0 and
+    /// blanking the new last row. Marks the whole grid dirty since every
+    /// pixel row moves.
+    pub fn scroll(&mut self) {
+        let row_bytes = self.row_bytes();
+        let shift = row_bytes * FONT_HEIGHT as usize;
+        self.buffer.drain(0..shift);
+        self.buffer.extend(std::iter::repeat_n(0xFFu8, shift));
+        self.mark_dirty(0);
+        self.mark_dirty(self.rows.saturating_sub(1));
+    }
+
+    fn draw_char(&mut self, ch: char) {
+        let glyph = glyph_bitmap(ch);
+        let px0 = self.cursor_col * FONT_WIDTH;
+        let py0 = self.cursor_row * FONT_HEIGHT;
+        let row_bytes = self.row_bytes();
+
+        for gy in 0..FONT_HEIGHT {
+            let row_bits = glyph[gy as usize];
+            for gx in 0..FONT_WIDTH {
+                let on = (row_bits >> (FONT_WIDTH - 1 - gx)) & 1 == 1;
+                let px = px0 + gx;
+                let py = py0 + gy;
+                if px >= self.width || py >= self.height {
+                    continue;
+                }
+
+                let byte_idx = py as usize * row_bytes + (px / 8) as usize;
+                let bit_idx = px % 8;
+                if on {
+                    self.buffer[byte_idx] &= !(1 << (7 - bit_idx));
+                } else {
+                    self.buffer[byte_idx] |= 1 << (7 - bit_idx);
+                }
+            }
+        }
+
+        self.mark_dirty(self.cursor_row);
+    }
+
+    /// Blank the framebuffer, return the cursor to the top-left corner, and
+    /// mark the whole grid dirty.
+    pub fn clear(&mut self) {
+        for byte in &mut self.buffer {
+            *byte = 0xFF;
+        }
+        self.cursor_row = 0;
+        self.cursor_col = 0;
+        self.mark_dirty(0);
+        self.mark_dirty(self.rows.saturating_sub(1));
+    }
+
+    /// Push the framebuffer to the panel as a partial refresh if anything
+    /// has changed since the last flush, then clear the dirty-row tracker.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DisplayError` if entering partial mode, writing the
+    /// framebuffer, or triggering the update fails.
+    pub fn flush(&mut self) -> Result<(), DisplayError> {
+        if self.dirty_rows.is_none() {
+            return Ok(());
+        }
+
+        self.protocol.init_partial()?;
+        let write_ram_cmd = self.protocol.get_write_ram_command();
+        self.protocol.write_cmd(write_ram_cmd)?;
+        self.protocol.write_image_data(&self.buffer)?;
+        self.protocol.update_display(DisplayMode::Partial)?;
+
+        self.dirty_rows = None;
+        Ok(())
+    }
+}
+
+impl<P: EinkProtocol> fmt::Write for TextConsole<P> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for ch in s.chars() {
+            if ch == '\n' {
+                self.newline();
+                continue;
+            }
+
+            if self.cursor_col >= self.cols {
+                self.newline();
+            }
+
+            self.draw_char(ch);
+            self.cursor_col += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Look up the 8-row bitmap for a glyph; each row's bottom `FONT_WIDTH` bits
+/// are the pixels for that row, MSB first (leftmost column). Characters
+/// outside the built-in set render as a hollow box.
+#[rustfmt::skip]
+fn glyph_bitmap(ch: char) -> [u8; FONT_HEIGHT as usize] {
+    match ch.to_ascii_uppercase() {
+        ' ' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000],
+        '0' => [0b011110, 0b100011, 0b100101, 0b101001, 0b110001, 0b100001, 0b100001, 0b011110],
+        '1' => [0b001100, 0b011100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b111111],
+        '2' => [0b011110, 0b100001, 0b000001, 0b000010, 0b000100, 0b001000, 0b010000, 0b111111],
+        '3' => [0b111110, 0b000001, 0b000001, 0b011110, 0b000001, 0b000001, 0b100001, 0b011110],
+        '4' => [0b000010, 0b000110, 0b001010, 0b010010, 0b100010, 0b111111, 0b000010, 0b000010],
+        '5' => [0b111111, 0b100000, 0b111110, 0b000001, 0b000001, 0b000001, 0b100001, 0b011110],
+        '6' => [0b011110, 0b100000, 0b100000, 0b111110, 0b100001, 0b100001, 0b100001, 0b011110],
+        '7' => [0b111111, 0b000001, 0b000010, 0b000100, 0b001000, 0b010000, 0b010000, 0b010000],
+        '8' => [0b011110, 0b100001, 0b100001, 0b011110, 0b100001, 0b100001, 0b100001, 0b011110],
+        '9' => [0b011110, 0b100001, 0b100001, 0b100001, 0b011111, 0b000001, 0b000001, 0b011110],
+        'A' => [0b001100, 0b010010, 0b100001, 0b100001, 0b111111, 0b100001, 0b100001, 0b100001],
+        'B' => [0b111110, 0b100001, 0b100001, 0b111110, 0b100001, 0b100001, 0b100001, 0b111110],
+        'C' => [0b011110, 0b100001, 0b100000, 0b100000, 0b100000, 0b100000, 0b100001, 0b011110],
+        'D' => [0b111100, 0b100010, 0b100001, 0b100001, 0b100001, 0b100001, 0b100010, 0b111100],
+        'E' => [0b111111, 0b100000, 0b100000, 0b111110, 0b100000, 0b100000, 0b100000, 0b111111],
+        'F' => [0b111111, 0b100000, 0b100000, 0b111110, 0b100000, 0b100000, 0b100000, 0b100000],
+        'G' => [0b011110, 0b100001, 0b100000, 0b100000, 0b100111, 0b100001, 0b100001, 0b011110],
+        'H' => [0b100001, 0b100001, 0b100001, 0b111111, 0b100001, 0b100001, 0b100001, 0b100001],
+        'I' => [0b011110, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b011110],
+        'J' => [0b000111, 0b000010, 0b000010, 0b000010, 0b000010, 0b100010, 0b100010, 0b011100],
+        'K' => [0b100001, 0b100010, 0b100100, 0b111000, 0b100100, 0b100010, 0b100001, 0b100001],
+        'L' => [0b100000, 0b100000, 0b100000, 0b100000, 0b100000, 0b100000, 0b100000, 0b111111],
+        'M' => [0b100001, 0b110011, 0b101101, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001],
+        'N' => [0b100001, 0b110001, 0b101001, 0b100101, 0b100011, 0b100001, 0b100001, 0b100001],
+        'O' => [0b011110, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b011110],
+        'P' => [0b111110, 0b100001, 0b100001, 0b111110, 0b100000, 0b100000, 0b100000, 0b100000],
+        'Q' => [0b011110, 0b100001, 0b100001, 0b100001, 0b100101, 0b100010, 0b100001, 0b011101],
+        'R' => [0b111110, 0b100001, 0b100001, 0b111110, 0b100100, 0b100010, 0b100001, 0b100001],
+        'S' => [0b011111, 0b100000, 0b100000, 0b011110, 0b000001, 0b000001, 0b000001, 0b111110],
+        'T' => [0b111111, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100],
+        'U' => [0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b011110],
+        'V' => [0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b010010, 0b010010, 0b001100],
+        'W' => [0b100001, 0b100001, 0b100001, 0b100001, 0b101101, 0b110011, 0b100001, 0b100001],
+        'X' => [0b100001, 0b100001, 0b010010, 0b001100, 0b001100, 0b010010, 0b100001, 0b100001],
+        'Y' => [0b100001, 0b100001, 0b010010, 0b001100, 0b001100, 0b001100, 0b001100, 0b001100],
+        'Z' => [0b111111, 0b000010, 0b000100, 0b001000, 0b010000, 0b100000, 0b100000, 0b111111],
+        '.' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b001100, 0b001100],
+        ',' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b001100, 0b001100, 0b011000],
+        ':' => [0b000000, 0b001100, 0b001100, 0b000000, 0b000000, 0b001100, 0b001100, 0b000000],
+        ';' => [0b000000, 0b001100, 0b001100, 0b000000, 0b000000, 0b001100, 0b001100, 0b011000],
+        '-' => [0b000000, 0b000000, 0b000000, 0b111111, 0b000000, 0b000000, 0b000000, 0b000000],
+        '+' => [0b000000, 0b001100, 0b001100, 0b111111, 0b001100, 0b001100, 0b000000, 0b000000],
+        '=' => [0b000000, 0b000000, 0b111111, 0b000000, 0b111111, 0b000000, 0b000000, 0b000000],
+        '!' => [0b001100, 0b001100, 0b001100, 0b001100, 0b001100, 0b000000, 0b001100, 0b001100],
+        '?' => [0b011110, 0b100001, 0b000010, 0b000100, 0b001000, 0b000000, 0b001000, 0b001000],
+        '/' => [0b000001, 0b000010, 0b000100, 0b001000, 0b010000, 0b100000, 0b000000, 0b000000],
+        '_' => [0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b000000, 0b111111],
+        // Missing glyph: hollow box, matching how real fonts signal "tofu".
+        _ => [0b111111, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b100001, 0b111111],
+    }
+}
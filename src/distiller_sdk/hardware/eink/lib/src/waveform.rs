@@ -0,0 +1,311 @@
+//! Runtime-loadable waveform LUT files, analogous to `request_firmware` in
+//! the kernel display drivers: an operator drops a panel- and
+//! temperature-specific waveform blob under
+//! [`crate::config::DisplayConfig::waveform_dir`] (e.g.
+//! `/opt/distiller-sdk/waveforms/`) and [`load_from_file`] turns it into a
+//! [`crate::firmware::CommandSequence`] that
+//! [`crate::display::DisplayDriver::load_waveform`] installs into the
+//! protocol before the next `update_display`, without recompiling.
+//!
+//! # Binary format
+//!
+//! ```text
+//! magic:       4 bytes, b"EWAV"
+//! version:     u8
+//! panel_len:   u8
+//! panel:       [u8; panel_len], UTF-8 panel name (see `get_spec().name`)
+//! records:     zero or more Record, see below, followed by a `type == 1`
+//!              (EOF) record with `len == 0`
+//! checksum:    u16 (LE), the wrapping sum of every preceding byte in the
+//!              file (header + records + EOF record)
+//! ```
+//!
+//! Each `Record` is `{ record_type: u16 (LE), addr: u32 (LE), len: u16 (LE),
+//! data: [u8; len] }`. `record_type` is one of:
+//!
+//! - `0` LUT data — write `data` to the panel via the command register
+//!   `addr as u8` (e.g. `0x32` for the `EPD128x250`'s "Write LUT register",
+//!   or one of `0x20..=0x24` for the `EPD240x416`'s per-LUT commands)
+//! - `3` entry/start offset — issue command `addr as u8` with no data,
+//!   before any LUT data records, to select the waveform bank or entry
+//!   point the following LUT records apply to; `len` must be `0`
+//! - `1` EOF — terminates the record stream; `len` must be `0`
+//!
+//! A bad magic, unsupported version, or checksum mismatch is rejected
+//! outright, the same way a malformed header string is rejected by the
+//! Softing CAN firmware loader.
+
+use crate::{error::DisplayError, firmware::CommandSequence};
+
+/// Magic bytes identifying a waveform file.
+const MAGIC: &[u8; 4] = b"EWAV";
+/// Only format version currently understood by [`parse`].
+const FORMAT_VERSION: u8 = 1;
+
+const RECORD_TYPE_LUT: u16 = 0;
+const RECORD_TYPE_EOF: u16 = 1;
+const RECORD_TYPE_ENTRY: u16 = 3;
+
+/// One decoded waveform record, ready to be replayed into a
+/// [`CommandSequence`].
+#[derive(Debug, Clone)]
+enum WaveformOp {
+    /// Select command register `addr`, then write `data` to it.
+    Lut { register: u8, data: Vec<u8> },
+    /// Select command register `addr`, issuing no data (a bank/entry-point
+    /// selector).
+    Entry { register: u8 },
+}
+
+/// A waveform parsed from the binary format documented in the module
+/// doc-comment.
+#[derive(Debug, Clone)]
+pub struct Waveform {
+    panel: String,
+    ops: Vec<WaveformOp>,
+}
+
+impl Waveform {
+    /// The panel name this waveform was authored for (the file's header
+    /// field, not validated against the active firmware's
+    /// [`crate::firmware::DisplaySpec::name`] — callers that care should
+    /// compare it themselves).
+    #[must_use]
+    pub fn panel(&self) -> &str {
+        &self.panel
+    }
+
+    /// Build the [`CommandSequence`] that installs this waveform: one
+    /// `cmd` per record, with LUT records followed by their `data` bytes.
+    #[must_use]
+    pub fn into_command_sequence(self) -> CommandSequence {
+        self.ops.into_iter().fold(CommandSequence::new(), |seq, op| match op {
+            WaveformOp::Entry { register } => seq.cmd(register),
+            WaveformOp::Lut { register, data } => {
+                let seq = seq.cmd(register);
+                data.into_iter().fold(seq, CommandSequence::data)
+            },
+        })
+    }
+}
+
+/// Parse a waveform from `bytes`.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Config` with a descriptive message if `bytes` is
+/// too short, the magic or version don't match, a record is malformed, the
+/// trailing checksum doesn't match, or the terminator record is missing.
+pub fn parse(bytes: &[u8]) -> Result<Waveform, DisplayError> {
+    const HEADER_LEN: usize = 4 + 1 + 1;
+    if bytes.len() < HEADER_LEN {
+        return Err(DisplayError::Config(
+            "waveform file is too short for its header".to_string(),
+        ));
+    }
+
+    if &bytes[0..4] != MAGIC {
+        return Err(DisplayError::Config(format!(
+            "bad waveform magic: expected {MAGIC:?}, found {:?}",
+            &bytes[0..4]
+        )));
+    }
+
+    let version = bytes[4];
+    if version != FORMAT_VERSION {
+        return Err(DisplayError::Config(format!(
+            "unsupported waveform version {version} (expected {FORMAT_VERSION})"
+        )));
+    }
+
+    let panel_len = bytes[5] as usize;
+    let mut offset = 6;
+    if bytes.len() < offset + panel_len {
+        return Err(DisplayError::Config(
+            "waveform file is truncated in its panel name field".to_string(),
+        ));
+    }
+    let panel = std::str::from_utf8(&bytes[offset..offset + panel_len])
+        .map_err(|e| DisplayError::Config(format!("waveform panel name is not valid UTF-8: {e}")))?
+        .to_string();
+    offset += panel_len;
+
+    if bytes.len() < offset + 2 {
+        return Err(DisplayError::Config(
+            "waveform file is missing its trailing checksum".to_string(),
+        ));
+    }
+    let checksum_offset = bytes.len() - 2;
+    let expected_checksum = u16::from_le_bytes([bytes[checksum_offset], bytes[checksum_offset + 1]]);
+    let actual_checksum = bytes[..checksum_offset]
+        .iter()
+        .fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+    if actual_checksum != expected_checksum {
+        return Err(DisplayError::Config(format!(
+            "waveform checksum mismatch: expected {expected_checksum:#06x}, computed {actual_checksum:#06x}"
+        )));
+    }
+
+    let mut ops = Vec::new();
+    let mut terminated = false;
+
+    while offset < checksum_offset {
+        if offset + 8 > checksum_offset {
+            return Err(DisplayError::Config(
+                "waveform file is truncated in a record header".to_string(),
+            ));
+        }
+        let record_type = u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        let addr = u32::from_le_bytes([
+            bytes[offset + 2],
+            bytes[offset + 3],
+            bytes[offset + 4],
+            bytes[offset + 5],
+        ]);
+        let len = u16::from_le_bytes([bytes[offset + 6], bytes[offset + 7]]) as usize;
+        offset += 8;
+
+        if offset + len > checksum_offset {
+            return Err(DisplayError::Config(
+                "waveform record payload runs past the end of the file".to_string(),
+            ));
+        }
+        let data = &bytes[offset..offset + len];
+        offset += len;
+
+        let register = u8::try_from(addr).map_err(|_| {
+            DisplayError::Config(format!("waveform record register {addr:#x} does not fit in a u8"))
+        })?;
+
+        match record_type {
+            RECORD_TYPE_EOF => {
+                if len != 0 {
+                    return Err(DisplayError::Config(
+                        "waveform EOF record must carry no payload".to_string(),
+                    ));
+                }
+                terminated = true;
+                break;
+            },
+            RECORD_TYPE_ENTRY => {
+                if len != 0 {
+                    return Err(DisplayError::Config(
+                        "waveform entry-offset record must carry no payload".to_string(),
+                    ));
+                }
+                ops.push(WaveformOp::Entry { register });
+            },
+            RECORD_TYPE_LUT => {
+                ops.push(WaveformOp::Lut {
+                    register,
+                    data: data.to_vec(),
+                });
+            },
+            other => {
+                return Err(DisplayError::Config(format!(
+                    "waveform file references unknown record type {other}"
+                )));
+            },
+        }
+    }
+
+    if !terminated {
+        return Err(DisplayError::Config(
+            "waveform file is missing its EOF record".to_string(),
+        ));
+    }
+
+    Ok(Waveform { panel, ops })
+}
+
+/// Read and parse a waveform file from `path`.
+///
+/// # Errors
+///
+/// Returns `DisplayError::Io` if `path` cannot be read, or
+/// `DisplayError::Config` if its contents fail to parse (see [`parse`]).
+pub fn load_from_file(path: &str) -> Result<Waveform, DisplayError> {
+    let bytes = std::fs::read(path)?;
+    parse(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assemble a minimal but valid waveform: one entry-offset record
+    /// selecting register `0x32`, one LUT record writing two bytes to
+    /// the same register, and a correct checksum.
+    fn minimal_waveform_bytes(panel: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.push(u8::try_from(panel.len()).unwrap());
+        bytes.extend_from_slice(panel.as_bytes());
+
+        // Entry-offset record: type=3, addr=0x32, len=0.
+        bytes.extend_from_slice(&3u16.to_le_bytes());
+        bytes.extend_from_slice(&0x32u32.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+
+        // LUT record: type=0, addr=0x32, len=2, data=[0xAA, 0xBB].
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&0x32u32.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&[0xAA, 0xBB]);
+
+        // EOF record: type=1, addr=0, len=0.
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+
+        let checksum = bytes.iter().fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parses_a_well_formed_waveform() {
+        let bytes = minimal_waveform_bytes("EPD128x250");
+        let waveform = parse(&bytes).unwrap();
+        assert_eq!(waveform.panel(), "EPD128x250");
+        assert_eq!(waveform.ops.len(), 2);
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let mut bytes = minimal_waveform_bytes("EPD128x250");
+        bytes[0] = b'X';
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_corrupted_checksum() {
+        let mut bytes = minimal_waveform_bytes("EPD128x250");
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_missing_eof_record() {
+        // Truncate right before the EOF record and re-checksum, so the
+        // loop runs off the end of the file without ever seeing type 1.
+        let mut bytes = minimal_waveform_bytes("EPD128x250");
+        let eof_and_checksum_len = 8 + 2;
+        let cut = bytes.len() - eof_and_checksum_len;
+        bytes.truncate(cut);
+        let checksum = bytes.iter().fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn builds_expected_command_sequence() {
+        let bytes = minimal_waveform_bytes("EPD128x250");
+        let waveform = parse(&bytes).unwrap();
+        let sequence = waveform.into_command_sequence();
+        // cmd(0x32) [entry] + cmd(0x32) + data(0xAA) + data(0xBB) = 4 ops.
+        assert_eq!(sequence.commands.len(), 4);
+    }
+}